@@ -0,0 +1,179 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+use heapless::FnvIndexMap;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// A fixed-capacity collection of [stopwatches](StopwatchImpl) identified by
+/// integer IDs.
+///
+/// This is like [`StopwatchGroup`](crate::StopwatchGroup), but needs no
+/// allocator, so it's suitable for `no_std` targets. It holds at most `N`
+/// stopwatches, where `N` must be a power of two greater than one.
+///
+/// Stopwatches are created on demand when first started. Reading or stopping
+/// an ID which has never been started behaves as if it were a stopped
+/// stopwatch with zero elapsed time.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{ArrayStopwatchGroup, Error};
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// const PARSE: u16 = 0;
+/// const RENDER: u16 = 1;
+///
+/// let start = Instant::now();
+/// let mut group = ArrayStopwatchGroup::<Instant, 2>::new();
+/// group.start_at(PARSE, start)?;
+/// group.stop_at(PARSE, start + Duration::from_secs(2))?;
+/// assert_eq!(group.elapsed(PARSE), Duration::from_secs(2));
+///
+/// group.start(RENDER)?;
+/// assert_eq!(group.start(2), Err(Error::GroupFull));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArrayStopwatchGroup<I: Instant, const N: usize> {
+    stopwatches: FnvIndexMap<u16, StopwatchImpl<I>, N>,
+}
+
+impl<I: Instant, const N: usize> ArrayStopwatchGroup<I, N> {
+    /// Returns an empty group.
+    ///
+    /// # Notes
+    ///
+    /// Fails to compile if `N` isn't a power of two greater than one.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stopwatches: FnvIndexMap::new(),
+        }
+    }
+
+    /// Returns a reference to the stopwatch with the given `id`, if it exists.
+    #[must_use]
+    pub fn get(&self, id: u16) -> Option<&StopwatchImpl<I>> {
+        self.stopwatches.get(&id)
+    }
+
+    /// Returns the number of stopwatches in the group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stopwatches.len()
+    }
+
+    /// Returns `true` if the group contains no stopwatches.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stopwatches.is_empty()
+    }
+
+    /// Returns the maximum number of stopwatches the group can hold, `N`.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the total time elapsed of the stopwatch with the given `id`, or
+    /// zero if it doesn't exist.
+    #[must_use]
+    pub fn elapsed(&self, id: u16) -> Duration {
+        self.elapsed_at(id, I::now())
+    }
+
+    /// Returns the total time elapsed of the stopwatch with the given `id`,
+    /// measured as if the current time were `anchor`, or zero if it doesn't
+    /// exist.
+    #[must_use]
+    pub fn elapsed_at(&self, id: u16, anchor: I) -> Duration {
+        self.get(id)
+            .map_or(Duration::ZERO, |sw| sw.elapsed_at(anchor))
+    }
+
+    /// Starts the stopwatch with the given `id`, creating it if it doesn't
+    /// exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running, or
+    /// [`GroupFull`](Error::GroupFull) if it doesn't exist and the group is
+    /// full.
+    pub fn start(&mut self, id: u16) -> crate::Result<()> {
+        self.start_at(id, I::now())
+    }
+
+    /// Starts the stopwatch with the given `id` as if the current time were
+    /// `anchor`, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running, or
+    /// [`GroupFull`](Error::GroupFull) if it doesn't exist and the group is
+    /// full.
+    pub fn start_at(&mut self, id: u16, anchor: I) -> crate::Result<()> {
+        if let Some(sw) = self.stopwatches.get_mut(&id) {
+            return sw.start_at(anchor);
+        }
+        self.stopwatches
+            .insert(id, StopwatchImpl::new_started_at(anchor))
+            .map_err(|_| Error::GroupFull)?;
+        Ok(())
+    }
+
+    /// Stops the stopwatch with the given `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped
+    /// or doesn't exist.
+    pub fn stop(&mut self, id: u16) -> crate::Result<()> {
+        self.stop_at(id, I::now())
+    }
+
+    /// Stops the stopwatch with the given `id` as if the current time were
+    /// `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped
+    /// or doesn't exist.
+    pub fn stop_at(&mut self, id: u16, anchor: I) -> crate::Result<()> {
+        self.stopwatches
+            .get_mut(&id)
+            .ok_or(Error::SwStop)?
+            .stop_at(anchor)
+    }
+
+    /// Removes the stopwatch with the given `id`, returning it if it existed.
+    pub fn remove(&mut self, id: u16) -> Option<StopwatchImpl<I>> {
+        self.stopwatches.remove(&id)
+    }
+
+    /// Removes all stopwatches from the group.
+    pub fn clear(&mut self) {
+        self.stopwatches.clear();
+    }
+
+    /// Returns an iterator over the IDs and stopwatches in the group.
+    ///
+    /// Stopwatches are visited in the order they were created, until one is
+    /// [removed](Self::remove). Removing a stopwatch moves the last one into
+    /// its place, so the order is unspecified after any removal.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &StopwatchImpl<I>)> {
+        self.stopwatches.iter().map(|(id, sw)| (*id, sw))
+    }
+}
+
+impl<I: Instant, const N: usize> Default for ArrayStopwatchGroup<I, N> {
+    /// Returns an empty group. Same as calling [`ArrayStopwatchGroup::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,217 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::{Error, StopwatchImpl};
+
+/// Set if the stopwatch is running.
+const RUNNING: u64 = 1 << 63;
+
+/// Offset applied to origins, so that origins before the base instant can be
+/// represented.
+const BIAS: u64 = 1 << 62;
+
+/// Largest encodable value, excluding the running flag.
+const MASK: u64 = RUNNING - 1;
+
+/// A lock-free stopwatch, which can be shared and controlled across threads.
+///
+/// `AtomicStopwatch` measures time with [`std::time::Instant`], and has the
+/// same semantics as [`StopwatchImpl`] for it. Its whole state is stored in a
+/// single atomic integer, so [`elapsed`](Self::elapsed) is always consistent,
+/// even while other threads start and stop the stopwatch.
+///
+/// # Notes
+///
+/// - Time is stored in nanoseconds relative to when the stopwatch was
+///   created. The elapsed time saturates at about 146 years, rather than
+///   [`Duration::MAX`].
+///
+/// - Each operation reads the clock and the state separately, so a thread
+///   racing with [`stop`](Self::stop) may briefly observe slightly more
+///   elapsed time than is recorded once the stop completes.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::AtomicStopwatch;
+/// # use core::time::Duration;
+/// # use std::{sync::Arc, thread};
+/// # fn main() -> libsw::Result<()> {
+/// let sw = Arc::new(AtomicStopwatch::new());
+/// sw.start()?;
+///
+/// let worker = {
+///     let sw = Arc::clone(&sw);
+///     thread::spawn(move || {
+///         thread::sleep(Duration::from_millis(100));
+///         sw.stop()
+///     })
+/// };
+/// worker.join().unwrap()?;
+///
+/// assert!(sw.is_stopped());
+/// assert!(sw.elapsed() >= Duration::from_millis(100));
+/// # Ok(())
+/// # }
+/// ```
+pub struct AtomicStopwatch {
+    base: Instant,
+    // if running: RUNNING | (origin + BIAS), where origin is the start instant
+    // less the elapsed time before it, relative to base.
+    // if stopped: the elapsed time.
+    state: AtomicU64,
+}
+
+impl AtomicStopwatch {
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            state: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self {
+            base: Instant::now(),
+            state: AtomicU64::new(RUNNING | BIAS),
+        }
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.state.load(Ordering::Acquire) & RUNNING != 0
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        !self.is_running()
+    }
+
+    /// Returns the total time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        let state = self.state.load(Ordering::Acquire);
+        Duration::from_nanos(Self::decode_elapsed(state, self.now()))
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start(&self) -> crate::Result<()> {
+        self.update(|state| {
+            if state & RUNNING != 0 {
+                Err(Error::SwStart)
+            } else {
+                // origin = now - elapsed
+                let now = self.now();
+                Ok(RUNNING | (now.saturating_add(BIAS).saturating_sub(state) & MASK))
+            }
+        })
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    pub fn stop(&self) -> crate::Result<()> {
+        self.update(|state| {
+            if state & RUNNING == 0 {
+                Err(Error::SwStop)
+            } else {
+                Ok(Self::decode_elapsed(state, self.now()))
+            }
+        })
+    }
+
+    /// Stops and resets the elapsed time to zero.
+    pub fn reset(&self) {
+        self.state.store(0, Ordering::Release);
+    }
+
+    /// Returns a [`StopwatchImpl`] with the same state as this stopwatch.
+    #[must_use]
+    pub fn to_stopwatch(&self) -> StopwatchImpl<Instant> {
+        let state = self.state.load(Ordering::Acquire);
+        if state & RUNNING == 0 {
+            StopwatchImpl::with_elapsed(Duration::from_nanos(state))
+        } else {
+            let now = Instant::now();
+            let elapsed = Duration::from_nanos(Self::decode_elapsed(state, self.since_base(now)));
+            StopwatchImpl::from_raw(elapsed, Some(now))
+        }
+    }
+
+    /// Returns the nanoseconds elapsed since the stopwatch was created.
+    fn now(&self) -> u64 {
+        self.since_base(Instant::now())
+    }
+
+    fn since_base(&self, instant: Instant) -> u64 {
+        let nanos = instant.saturating_duration_since(self.base).as_nanos();
+        u64::try_from(nanos).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the elapsed time in nanoseconds encoded by `state`, measured
+    /// at `now` nanoseconds since the stopwatch was created.
+    fn decode_elapsed(state: u64, now: u64) -> u64 {
+        if state & RUNNING == 0 {
+            state
+        } else {
+            // elapsed = now - origin
+            now.saturating_add(BIAS)
+                .saturating_sub(state & MASK)
+                .min(MASK)
+        }
+    }
+
+    /// Atomically replaces the state with the result of `f`, retrying if
+    /// another thread changed it first.
+    ///
+    /// `f` must read the clock itself, after being passed the state, so that
+    /// a retry never pairs a newer state with an older reading of the clock.
+    fn update(&self, mut f: impl FnMut(u64) -> crate::Result<u64>) -> crate::Result<()> {
+        let mut state = self.state.load(Ordering::Acquire);
+        loop {
+            let new = f(state)?;
+            match self
+                .state
+                .compare_exchange_weak(state, new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return Ok(()),
+                Err(actual) => state = actual,
+            }
+        }
+    }
+}
+
+impl Default for AtomicStopwatch {
+    /// Returns the default stopwatch. Same as calling
+    /// [`AtomicStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for AtomicStopwatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AtomicStopwatch")
+            .field("elapsed", &self.elapsed())
+            .field("running", &self.is_running())
+            .finish_non_exhaustive()
+    }
+}
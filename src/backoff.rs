@@ -0,0 +1,184 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// An exponential backoff schedule, with a [stopwatch](StopwatchImpl)
+/// measuring the current wait.
+///
+/// Each failed attempt is recorded with [`retry`](Self::retry), which begins a
+/// wait. The delay of wait `n`, counting from zero, is `base * factor^n`,
+/// capped at `cap`. The timer is [ready](Self::ready) once the time elapsed in
+/// the current wait reaches its delay, at which point the caller may make
+/// another attempt.
+///
+/// Before the first retry, and after a [reset](Self::reset), the stopwatch is
+/// stopped and the timer isn't waiting, so it's always ready.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::BackoffTimer;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let start = Instant::now();
+/// let mut timer = BackoffTimer::<Instant>::new(Duration::from_secs(1), 2, Duration::from_secs(5));
+/// assert!(timer.ready_at(start));
+///
+/// // first attempt failed
+/// timer.retry_at(start);
+/// assert_eq!(timer.next_delay(), Duration::from_secs(1));
+/// assert!(!timer.ready_at(start));
+/// assert!(timer.ready_at(start + Duration::from_secs(1)));
+///
+/// // second attempt failed
+/// timer.retry_at(start);
+/// assert_eq!(timer.next_delay(), Duration::from_secs(2));
+///
+/// timer.retry_at(start);
+/// timer.retry_at(start);
+/// // capped
+/// assert_eq!(timer.next_delay(), Duration::from_secs(5));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct BackoffTimer<I: Instant> {
+    inner: StopwatchImpl<I>,
+    base: Duration,
+    factor: u32,
+    cap: Duration,
+    attempt: u32,
+}
+
+impl<I: Instant> BackoffTimer<I> {
+    /// Returns a backoff timer which isn't waiting, whose first wait will have
+    /// a delay of `base`.
+    #[must_use]
+    pub const fn new(base: Duration, factor: u32, cap: Duration) -> Self {
+        Self {
+            inner: StopwatchImpl::new(),
+            base,
+            factor,
+            cap,
+            attempt: 0,
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`], which measures the
+    /// current wait.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the index of the current wait, counting from zero.
+    #[inline]
+    #[must_use]
+    pub const fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the delay of the current wait, `base * factor^attempt`, capped
+    /// at `cap`.
+    ///
+    /// # Notes
+    ///
+    /// If the delay overflows, returns `cap`.
+    #[must_use]
+    pub fn next_delay(&self) -> Duration {
+        self.factor
+            .checked_pow(self.attempt)
+            .and_then(|scale| self.base.checked_mul(scale))
+            .map_or(self.cap, |delay| delay.min(self.cap))
+    }
+
+    /// Returns the time elapsed in the current wait.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    /// Returns the time elapsed in the current wait, measured as if the
+    /// current time were `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        self.inner.elapsed_at(anchor)
+    }
+
+    /// Returns `true` if the timer is waiting, ie. a failed attempt has been
+    /// recorded since the timer was created or reset.
+    #[inline]
+    #[must_use]
+    pub const fn is_waiting(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the timer isn't waiting, or the time elapsed in the
+    /// current wait has reached its [delay](Self::next_delay).
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        self.ready_at(I::now())
+    }
+
+    /// Returns `true` if the timer isn't waiting, or the time elapsed in the
+    /// current wait has reached its [delay](Self::next_delay), measured as if
+    /// the current time were `anchor`.
+    #[must_use]
+    pub fn ready_at(&self, anchor: I) -> bool {
+        self.remaining_at(anchor) == Duration::ZERO
+    }
+
+    /// Returns the time left in the current wait, or zero if the timer is
+    /// [ready](Self::ready).
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(I::now())
+    }
+
+    /// Returns the time left in the current wait, measured as if the current
+    /// time were `anchor`, or zero if the timer is [ready](Self::ready_at).
+    #[must_use]
+    pub fn remaining_at(&self, anchor: I) -> Duration {
+        if self.is_waiting() {
+            self.next_delay().saturating_sub(self.elapsed_at(anchor))
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Records a failed attempt, and begins the next wait. If the timer was
+    /// already waiting, the next wait has a longer delay.
+    pub fn retry(&mut self) {
+        self.retry_at(I::now());
+    }
+
+    /// Records a failed attempt, and begins the next wait as if the current
+    /// time were `anchor`. If the timer was already waiting, the next wait has
+    /// a longer delay.
+    ///
+    /// # Notes
+    ///
+    /// The wait index saturates at [`u32::MAX`].
+    pub fn retry_at(&mut self, anchor: I) {
+        if self.is_waiting() {
+            self.attempt = self.attempt.saturating_add(1);
+        }
+        self.inner = StopwatchImpl::from_raw(Duration::ZERO, Some(anchor));
+    }
+
+    /// Resets the wait index to zero, and stops the timer from waiting.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+        self.inner.reset();
+    }
+}
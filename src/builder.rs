@@ -0,0 +1,107 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A builder for a [stopwatch](StopwatchImpl), which collects its initial
+/// state before constructing it.
+///
+/// By default, the builder produces a stopped stopwatch with zero elapsed
+/// time, the same as [`StopwatchImpl::new`].
+///
+/// `StopwatchBuilder`s are returned by [`StopwatchImpl::builder`].
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let start = Instant::now();
+/// let sw = Sw::builder()
+///     .elapsed(Duration::from_secs(1))
+///     .started_at(start)
+///     .build();
+/// assert_eq!(sw, Sw::from_raw(Duration::from_secs(1), Some(start)));
+///
+/// let sw = Sw::builder().elapsed(Duration::from_secs(1)).build();
+/// assert_eq!(sw, Sw::with_elapsed(Duration::from_secs(1)));
+/// ```
+#[must_use = "builders do nothing unless built"]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct StopwatchBuilder<I: Instant> {
+    elapsed: Duration,
+    started: bool,
+    // if started, the instant to start at, or `None` for the time of building
+    start: Option<I>,
+}
+
+impl<I: Instant> StopwatchBuilder<I> {
+    /// Returns a builder for a stopped stopwatch with zero elapsed time.
+    pub const fn new() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            started: false,
+            start: None,
+        }
+    }
+
+    /// Sets the elapsed time accumulated before the stopwatch was last
+    /// started.
+    pub const fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// Sets whether the stopwatch is running. A running stopwatch is started
+    /// at the time it's [built](Self::build), unless an instant is set with
+    /// [`started_at`](Self::started_at).
+    pub const fn started(mut self, started: bool) -> Self {
+        self.started = started;
+        if !started {
+            self.start = None;
+        }
+        self
+    }
+
+    /// Sets the stopwatch to be running, started at `start`.
+    pub const fn started_at(mut self, start: I) -> Self {
+        self.started = true;
+        self.start = Some(start);
+        self
+    }
+
+    /// Returns the stopwatch described by the builder.
+    #[must_use]
+    pub fn build(self) -> StopwatchImpl<I> {
+        let start = if self.started {
+            Some(self.start.unwrap_or_else(I::now))
+        } else {
+            None
+        };
+        StopwatchImpl::from_raw(self.elapsed, start)
+    }
+}
+
+impl<I: Instant> Default for StopwatchBuilder<I> {
+    /// Returns a builder for a stopped stopwatch with zero elapsed time. Same
+    /// as calling [`StopwatchBuilder::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> StopwatchImpl<I> {
+    /// Returns a [`StopwatchBuilder`] for a stopped stopwatch with zero
+    /// elapsed time.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`StopwatchBuilder`] for a related example.
+    pub const fn builder() -> StopwatchBuilder<I> {
+        StopwatchBuilder::new()
+    }
+}
@@ -0,0 +1,354 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::cell::Cell;
+use core::fmt;
+use core::time::Duration;
+
+use crate::monotonic::clamp_to_last;
+use crate::{Error, Instant, StopwatchImpl};
+
+/// How a [`ConfiguredStopwatch`] handles its elapsed time overflowing when
+/// stopped.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Saturate the elapsed time to [`Duration::MAX`], like
+    /// [`StopwatchImpl::stop`].
+    Saturate,
+
+    /// Return [`Overflow`](Error::Overflow) without mutating the stopwatch,
+    /// like [`StopwatchImpl::checked_stop`].
+    Error,
+}
+
+impl Default for OverflowPolicy {
+    /// Returns [`OverflowPolicy::Saturate`].
+    fn default() -> Self {
+        Self::Saturate
+    }
+}
+
+/// Optional behaviors of a [`ConfiguredStopwatch`], collected in one place.
+///
+/// The default configuration behaves the same as a plain [`StopwatchImpl`].
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::StopwatchConfig;
+/// # use core::time::Duration;
+/// let config = StopwatchConfig {
+///     monotonic: true,
+///     cap: Some(Duration::from_secs(60)),
+///     ..StopwatchConfig::new()
+/// };
+/// assert_eq!(config.offset, Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct StopwatchConfig {
+    /// How overflow of the elapsed time is handled when stopping.
+    ///
+    /// Defaults to [`OverflowPolicy::Saturate`].
+    pub overflow: OverflowPolicy,
+
+    /// Whether reads of the current time are clamped to be no earlier than
+    /// the latest instant read, as in
+    /// [`MonotonicStopwatch`](crate::MonotonicStopwatch).
+    ///
+    /// Defaults to `false`.
+    pub monotonic: bool,
+
+    /// An offset added to the reported elapsed time, without affecting the
+    /// time accumulated by the inner stopwatch.
+    ///
+    /// Defaults to [`Duration::ZERO`].
+    pub offset: Duration,
+
+    /// The maximum reported elapsed time, applied after `offset`.
+    ///
+    /// Defaults to [`None`], meaning no cap.
+    pub cap: Option<Duration>,
+
+    /// The maximum time a single running segment contributes to the elapsed
+    /// time.
+    ///
+    /// A segment is the time between starting and stopping the stopwatch. On
+    /// a wall clock such as `SystemTime`, a forward
+    /// jump of the clock inflates the running segment. Capping it bounds the
+    /// damage of an implausible jump, both while running and when stopping.
+    ///
+    /// Defaults to [`None`], meaning no cap.
+    pub max_segment: Option<Duration>,
+}
+
+impl StopwatchConfig {
+    /// Returns the default configuration.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            overflow: OverflowPolicy::Saturate,
+            monotonic: false,
+            offset: Duration::ZERO,
+            cap: None,
+            max_segment: None,
+        }
+    }
+}
+
+impl Default for StopwatchConfig {
+    /// Returns the default configuration. Same as calling
+    /// [`StopwatchConfig::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [stopwatch](StopwatchImpl) whose optional behaviors are set by a
+/// [`StopwatchConfig`].
+///
+/// `ConfiguredStopwatch`es are returned by
+/// [`StopwatchImpl::with_config`]. Plain `StopwatchImpl`s don't carry a
+/// configuration, so they pay nothing for these behaviors.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{StopwatchConfig, Sw};
+/// # use core::time::Duration;
+/// let config = StopwatchConfig {
+///     offset: Duration::from_secs(5),
+///     cap: Some(Duration::from_secs(8)),
+///     ..StopwatchConfig::new()
+/// };
+/// let sw = Sw::with_elapsed(Duration::from_secs(2)).with_config(config);
+/// assert_eq!(sw.elapsed(), Duration::from_secs(7));
+/// assert_eq!(sw.inner().elapsed(), Duration::from_secs(2));
+/// ```
+pub struct ConfiguredStopwatch<I: Instant> {
+    inner: StopwatchImpl<I>,
+    config: StopwatchConfig,
+    // latest instant read, if monotonic
+    last: Cell<Option<I>>,
+}
+
+impl<I: Instant> ConfiguredStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time, configured by
+    /// `config`.
+    #[must_use]
+    pub const fn new(config: StopwatchConfig) -> Self {
+        Self::from_inner(StopwatchImpl::new(), config)
+    }
+
+    /// Returns a stopwatch wrapping `inner`, configured by `config`.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>, config: StopwatchConfig) -> Self {
+        Self {
+            inner,
+            config,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Returns the configuration of the stopwatch.
+    #[inline]
+    #[must_use]
+    pub const fn config(&self) -> &StopwatchConfig {
+        &self.config
+    }
+
+    /// Replaces the configuration of the stopwatch.
+    pub fn configure(&mut self, config: StopwatchConfig) {
+        if !config.monotonic {
+            self.last.set(None);
+        }
+        self.config = config;
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the current time, clamped if the stopwatch is
+    /// [monotonic](StopwatchConfig::monotonic).
+    #[must_use]
+    pub fn now(&self) -> I {
+        let now = I::now();
+        if self.config.monotonic {
+            clamp_to_last(&self.last, now)
+        } else {
+            now
+        }
+    }
+
+    /// Returns `anchor`, clamped to the end of the longest allowed
+    /// [segment](StopwatchConfig::max_segment) if the stopwatch is running.
+    fn segment_end(&self, anchor: I) -> I {
+        match (self.config.max_segment, self.inner.inner.start) {
+            (Some(max), Some(start)) if anchor.saturating_duration_since(start) > max => {
+                start.checked_add(max).unwrap_or(anchor)
+            }
+            _ => anchor,
+        }
+    }
+
+    /// Returns the reported elapsed time, after applying the
+    /// [segment cap](StopwatchConfig::max_segment),
+    /// [offset](StopwatchConfig::offset) and [cap](StopwatchConfig::cap).
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(self.now())
+    }
+
+    /// Returns the reported elapsed time, measured as if the current time
+    /// were `anchor`, after applying the
+    /// [segment cap](StopwatchConfig::max_segment),
+    /// [offset](StopwatchConfig::offset) and [cap](StopwatchConfig::cap).
+    ///
+    /// # Notes
+    ///
+    /// `anchor` isn't clamped, even if the stopwatch is
+    /// [monotonic](StopwatchConfig::monotonic). See
+    /// [`StopwatchImpl::elapsed_at`] for details about `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        let elapsed = self
+            .inner
+            .elapsed_at(self.segment_end(anchor))
+            .saturating_add(self.config.offset);
+        match self.config.cap {
+            Some(cap) => elapsed.min(cap),
+            None => elapsed,
+        }
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        let now = self.now();
+        self.start_at(now)
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` isn't clamped, even if the stopwatch is
+    /// [monotonic](StopwatchConfig::monotonic).
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed since the last start, clamping the
+    /// segment to the [segment cap](StopwatchConfig::max_segment).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// If the [overflow policy](StopwatchConfig::overflow) is
+    /// [`Error`](OverflowPolicy::Error), returns [`Overflow`](Error::Overflow)
+    /// without mutating the stopwatch if the new elapsed time overflows.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        let now = self.now();
+        self.stop_at(now)
+    }
+
+    /// Stops measuring the time elapsed since the last start as if the current
+    /// time were `anchor`, clamping the segment to the
+    /// [segment cap](StopwatchConfig::max_segment).
+    ///
+    /// # Errors
+    ///
+    /// See [`stop`](Self::stop).
+    ///
+    /// # Notes
+    ///
+    /// `anchor` isn't clamped, even if the stopwatch is
+    /// [monotonic](StopwatchConfig::monotonic). See
+    /// [`StopwatchImpl::stop_at`] for details about `anchor`.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        let end = self.segment_end(anchor);
+        match self.config.overflow {
+            OverflowPolicy::Saturate => self.inner.stop_at(end),
+            OverflowPolicy::Error => self.inner.checked_stop_at(end)?.ok_or(Error::Overflow),
+        }
+    }
+
+    /// Stops and resets the elapsed time to zero, keeping the configuration.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<I: Instant> StopwatchImpl<I> {
+    /// Returns a [`ConfiguredStopwatch`] wrapping the stopwatch, configured by
+    /// `config`.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`ConfiguredStopwatch`] for a related
+    /// example.
+    #[must_use]
+    pub const fn with_config(self, config: StopwatchConfig) -> ConfiguredStopwatch<I> {
+        ConfiguredStopwatch::from_inner(self, config)
+    }
+}
+
+impl<I: Instant> Clone for ConfiguredStopwatch<I> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner,
+            config: self.config,
+            last: Cell::new(self.last.get()),
+        }
+    }
+}
+
+impl<I: Instant> fmt::Debug for ConfiguredStopwatch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConfiguredStopwatch")
+            .field("inner", &self.inner)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Instant> Default for ConfiguredStopwatch<I> {
+    /// Returns a stopwatch with the default configuration. Same as calling
+    /// [`ConfiguredStopwatch::new`] with [`StopwatchConfig::new`].
+    fn default() -> Self {
+        Self::new(StopwatchConfig::new())
+    }
+}
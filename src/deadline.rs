@@ -0,0 +1,194 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A budget of time measured by a [stopwatch](StopwatchImpl).
+///
+/// A `Deadline` expires once the elapsed time of its stopwatch reaches the
+/// budget. While the stopwatch is running, the deadline also corresponds to an
+/// absolute [instant](Self::deadline_instant), which is useful for APIs which
+/// take one, such as timers.
+///
+/// A deadline can also be used as a countdown timer. Starting and stopping it
+/// resumes and pauses the countdown, and a stopped deadline reports the time
+/// remaining as of when it was stopped.
+///
+/// # Notes
+///
+/// The elapsed time of the stopwatch saturates to [`Duration::MAX`], so a
+/// budget of [`Duration::MAX`] only expires once the elapsed time overflows.
+/// Likewise, [`deadline_instant`](Self::deadline_instant) returns [`None`] if
+/// the budget reaches past the instants representable by `I`.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{Deadline, Sw};
+/// # use core::time::Duration;
+/// # use std::thread;
+/// let deadline = Deadline::new(Sw::new_started(), Duration::from_millis(100));
+/// assert!(!deadline.expired());
+/// assert!(deadline.deadline_instant().is_some());
+///
+/// thread::sleep(Duration::from_millis(100));
+/// assert!(deadline.expired());
+/// assert_eq!(deadline.remaining(), Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Deadline<I: Instant> {
+    inner: StopwatchImpl<I>,
+    budget: Duration,
+}
+
+impl<I: Instant> Deadline<I> {
+    /// Returns a deadline which expires once the elapsed time of `sw`
+    /// reaches `budget`.
+    #[must_use]
+    pub const fn new(sw: StopwatchImpl<I>, budget: Duration) -> Self {
+        Self { inner: sw, budget }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner [`StopwatchImpl`].
+    ///
+    /// Starting and stopping the inner stopwatch pauses and resumes the
+    /// deadline.
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut StopwatchImpl<I> {
+        &mut self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the budget of time.
+    #[inline]
+    #[must_use]
+    pub const fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Returns `true` if the stopwatch is running, and so the deadline is
+    /// approaching.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped, and so the deadline is
+    /// paused.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Resumes the countdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.start_at(I::now())
+    }
+
+    /// Resumes the countdown as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Pauses the countdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.stop_at(I::now())
+    }
+
+    /// Pauses the countdown as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Stops the countdown and restores the full budget.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Returns `true` if the elapsed time has reached the budget.
+    #[must_use]
+    pub fn expired(&self) -> bool {
+        self.expired_at(I::now())
+    }
+
+    /// Returns `true` if the elapsed time, measured as if the current time
+    /// were `anchor`, has reached the budget.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn expired_at(&self, anchor: I) -> bool {
+        self.inner.elapsed_at(anchor) >= self.budget
+    }
+
+    /// Returns the time remaining until the deadline expires, or
+    /// [`Duration::ZERO`] if it has expired.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(I::now())
+    }
+
+    /// Returns the time remaining until the deadline expires, measured as if
+    /// the current time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn remaining_at(&self, anchor: I) -> Duration {
+        self.budget.saturating_sub(self.inner.elapsed_at(anchor))
+    }
+
+    /// Returns the instant at which the deadline expires.
+    ///
+    /// Returns [`None`] if the stopwatch is stopped, since the deadline isn't
+    /// approaching, or if the instant can't be represented by `I`.
+    ///
+    /// # Notes
+    ///
+    /// If the deadline expired before the stopwatch was last started, returns
+    /// the instant it was last started.
+    #[must_use]
+    pub fn deadline_instant(&self) -> Option<I> {
+        let start = self.inner.inner.start?;
+        start.checked_add(self.budget.saturating_sub(self.inner.inner.elapsed))
+    }
+}
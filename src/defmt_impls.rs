@@ -0,0 +1,29 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use defmt::{Format, Formatter};
+
+use crate::{Error, Instant, StopwatchImpl};
+
+impl<I: Instant + Format> Format for StopwatchImpl<I> {
+    /// Formats the total time elapsed, whether the stopwatch is running, and
+    /// the instant it was last started.
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(
+            f,
+            "StopwatchImpl {{ elapsed: {}, running: {=bool}, start: {} }}",
+            self.elapsed(),
+            self.is_running(),
+            self.inner.start,
+        );
+    }
+}
+
+impl Format for Error {
+    /// Formats the error the same way as its [`Display`](core::fmt::Display)
+    /// implementation.
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.message());
+    }
+}
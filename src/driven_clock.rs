@@ -0,0 +1,151 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::hint;
+use ::core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+// a sequence lock over the seconds and nanoseconds of the driven clock. the
+// sequence is odd while a write is in progress, and readers retry if it
+// changed while they read.
+static SEQ: AtomicU64 = AtomicU64::new(0);
+static SECS: AtomicU64 = AtomicU64::new(0);
+static NANOS: AtomicU32 = AtomicU32::new(0);
+
+/// A handle to the global clock read by [`DrivenInstant`].
+///
+/// The driven clock starts at zero and only moves when an external source,
+/// such as a loop replaying recorded timestamps, calls [`set`](Self::set).
+/// Every stopwatch using [`DrivenInstant`] reads the same clock, so they all
+/// agree on the current time, from any thread.
+///
+/// # Notes
+///
+/// The driven clock is separate from the clock read by `MockInstant`, so
+/// driving it doesn't move the time seen by tests. A `DrivenClock` holds no
+/// time of its own, though. There is only one driven clock per process, and
+/// every handle sets the same clock, so two replays can't run independently in
+/// the same process. Each thread can drive its own clock with `ManualInstant`
+/// instead, behind the `manual_clock` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{DrivenClock, DrivenSw};
+/// # use core::time::Duration;
+/// # fn main() -> libsw::Result<()> {
+/// let clock = DrivenClock::new();
+/// let timestamps = [1, 2, 3, 5, 8].map(Duration::from_secs);
+///
+/// clock.set(timestamps[0]);
+/// let mut sw = DrivenSw::new_started();
+/// for timestamp in timestamps {
+///     clock.set(timestamp);
+/// }
+/// sw.stop()?;
+/// assert_eq!(sw.elapsed(), Duration::from_secs(7));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DrivenClock(());
+
+impl DrivenClock {
+    /// Returns a handle to the driven clock.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(())
+    }
+
+    /// Sets the driven clock to `now`, the time since its epoch.
+    ///
+    /// The clock may be set backwards, which stopwatches handle the same way
+    /// as any clock which isn't monotonic.
+    #[allow(clippy::unused_self)]
+    pub fn set(&self, now: Duration) {
+        loop {
+            let seq = SEQ.load(Ordering::SeqCst);
+            if seq % 2 == 0
+                && SEQ
+                    .compare_exchange(seq, seq.wrapping_add(1), Ordering::SeqCst, Ordering::SeqCst)
+                    .is_ok()
+            {
+                SECS.store(now.as_secs(), Ordering::SeqCst);
+                NANOS.store(now.subsec_nanos(), Ordering::SeqCst);
+                SEQ.store(seq.wrapping_add(2), Ordering::SeqCst);
+                return;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    /// Returns the current instant according to the driven clock.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn now(&self) -> DrivenInstant {
+        DrivenInstant::now()
+    }
+}
+
+/// An instant read from the global clock driven by [`DrivenClock`].
+///
+/// A `DrivenInstant` is the time since the clock's epoch.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DrivenInstant {
+    since_epoch: Duration,
+}
+
+impl DrivenInstant {
+    /// Returns an instant `since_epoch` after the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn from_duration(since_epoch: Duration) -> Self {
+        Self { since_epoch }
+    }
+
+    /// Returns the time since the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn as_duration(&self) -> Duration {
+        self.since_epoch
+    }
+}
+
+impl Instant for DrivenInstant {
+    /// Returns the current instant according to the driven clock.
+    fn now() -> Self {
+        loop {
+            let seq = SEQ.load(Ordering::SeqCst);
+            if seq % 2 == 0 {
+                let secs = SECS.load(Ordering::SeqCst);
+                let nanos = NANOS.load(Ordering::SeqCst);
+                if SEQ.load(Ordering::SeqCst) == seq {
+                    return Self::from_duration(Duration::new(secs, nanos));
+                }
+            }
+            hint::spin_loop();
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.since_epoch
+            .checked_add(duration)
+            .map(Self::from_duration)
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.since_epoch
+            .checked_sub(duration)
+            .map(Self::from_duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.since_epoch.saturating_sub(earlier.since_epoch)
+    }
+}
+
+impl Resolution for DrivenInstant {}
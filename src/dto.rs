@@ -0,0 +1,97 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Instant, StopwatchImpl};
+
+/// A plain representation of a [stopwatch](StopwatchImpl), suitable for
+/// crossing API boundaries.
+///
+/// Unlike `StopwatchImpl`, `StopwatchDto` does not depend on the [`Instant`]
+/// type or on the internal representation of the stopwatch. Its shape is
+/// stable.
+///
+/// # Conversions
+///
+/// Converting a `StopwatchImpl` into a `StopwatchDto` measures the elapsed time
+/// at the time of conversion, truncated to whole milliseconds. If the elapsed
+/// time exceeds [`u64::MAX`] milliseconds, it saturates.
+///
+/// Converting a `StopwatchDto` into a `StopwatchImpl` produces a stopwatch
+/// with the given elapsed time. If `running` is `true`, the stopwatch is
+/// started at [`I::now()`](Instant::now).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{StopwatchDto, Sw};
+/// # use core::time::Duration;
+/// let sw = Sw::with_elapsed(Duration::from_millis(1500));
+/// let dto = StopwatchDto::from(sw);
+/// assert_eq!(dto, StopwatchDto { elapsed_ms: 1500, running: false });
+/// assert_eq!(Sw::from(dto), sw);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StopwatchDto {
+    /// The total elapsed time, in whole milliseconds.
+    pub elapsed_ms: u64,
+
+    /// Whether the stopwatch is running.
+    pub running: bool,
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for StopwatchDto {
+    fn from(sw: StopwatchImpl<I>) -> Self {
+        let elapsed_ms = u64::try_from(sw.elapsed().as_millis()).unwrap_or(u64::MAX);
+        Self {
+            elapsed_ms,
+            running: sw.is_running(),
+        }
+    }
+}
+
+impl<I: Instant> From<StopwatchDto> for StopwatchImpl<I> {
+    fn from(dto: StopwatchDto) -> Self {
+        let elapsed = Duration::from_millis(dto.elapsed_ms);
+        if dto.running {
+            Self::with_elapsed_started(elapsed)
+        } else {
+            Self::with_elapsed(elapsed)
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde_json")))]
+impl<I: Instant> StopwatchImpl<I> {
+    /// Returns the state of the stopwatch as a JSON object, in the shape of
+    /// [`StopwatchDto`].
+    ///
+    /// The object has an `elapsed_ms` number and a `running` boolean. The
+    /// elapsed time is measured and truncated as described in the
+    /// [`StopwatchDto` documentation](StopwatchDto#conversions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_millis(1500));
+    /// let json = sw.to_json_value();
+    /// assert_eq!(json["elapsed_ms"], 1500);
+    /// assert_eq!(json["running"], false);
+    /// ```
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let dto = StopwatchDto::from(*self);
+        serde_json::json!({
+            "elapsed_ms": dto.elapsed_ms,
+            "running": dto.running,
+        })
+    }
+}
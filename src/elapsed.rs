@@ -0,0 +1,78 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::ops::Deref;
+use core::time::Duration;
+
+/// The elapsed time of a [stopwatch](crate::StopwatchImpl), along with whether
+/// it was saturated.
+///
+/// `Elapsed` dereferences to its [`Duration`] value, so it can be used in
+/// place of one. Check [`saturated`](Elapsed::saturated) to tell a genuine
+/// [`Duration::MAX`] apart from an overflow. It also converts into a
+/// `(Duration, bool)` tuple of the elapsed time and whether it was saturated.
+///
+/// `Elapsed`s are returned by the `StopwatchImpl` methods
+/// [`elapsed_full`](crate::StopwatchImpl::elapsed_full) and
+/// [`elapsed_full_at`](crate::StopwatchImpl::elapsed_full_at).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// let sw = Sw::with_elapsed(Duration::from_secs(1));
+/// let elapsed = sw.elapsed_full();
+/// assert!(!elapsed.saturated);
+/// assert_eq!(elapsed.as_secs(), 1);
+///
+/// let (elapsed, saturated) = sw.elapsed_full().into();
+/// assert_eq!((elapsed, saturated), (Duration::from_secs(1), false));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Elapsed {
+    /// The elapsed time. If [`saturated`](Elapsed::saturated) is `true`, this
+    /// is [`Duration::MAX`].
+    pub value: Duration,
+
+    /// Whether the elapsed time overflowed and was saturated to
+    /// [`Duration::MAX`].
+    pub saturated: bool,
+}
+
+impl Elapsed {
+    pub(crate) const fn from_checked(checked: Option<Duration>) -> Self {
+        match checked {
+            Some(value) => Self {
+                value,
+                saturated: false,
+            },
+            None => Self {
+                value: Duration::MAX,
+                saturated: true,
+            },
+        }
+    }
+}
+
+impl Deref for Elapsed {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.value
+    }
+}
+
+impl From<Elapsed> for Duration {
+    fn from(elapsed: Elapsed) -> Self {
+        elapsed.value
+    }
+}
+
+impl From<Elapsed> for (Duration, bool) {
+    /// Returns the elapsed time and whether it was saturated, in that order.
+    fn from(elapsed: Elapsed) -> Self {
+        (elapsed.value, elapsed.saturated)
+    }
+}
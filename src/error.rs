@@ -65,6 +65,24 @@ pub enum Error {
 
     /// Returned by [`Guard::new`](crate::Guard::new). Expects that it's running.
     GuardNew,
+
+    /// Returned by methods that [lap](crate::LapStopwatch::lap) the stopwatch.
+    /// Expects that it's running.
+    SwLap,
+
+    /// Returned by methods which would overflow the elapsed time of the
+    /// stopwatch, such as
+    /// [`checked_start_at_past`](crate::StopwatchImpl::checked_start_at_past).
+    Overflow,
+
+    /// Returned by methods which read a fallible clock, such as
+    /// [`try_start`](crate::StopwatchImpl::try_start), when reading the
+    /// current time fails. See [`ClockError`](crate::ClockError).
+    Clock,
+
+    /// Returned by methods which would add a stopwatch to a full
+    /// fixed-capacity group, such as `ArrayStopwatchGroup::start`.
+    GroupFull,
 }
 
 impl Error {
@@ -77,13 +95,14 @@ impl Error {
     /// # use libsw::Error;
     /// assert!(Error::SwStop.expects_running());
     /// assert!(Error::GuardNew.expects_running());
+    /// assert!(!Error::Overflow.expects_running());
     /// ```
     #[inline]
     #[must_use]
     pub const fn expects_running(&self) -> bool {
         match self {
-            Self::SwStop | Self::GuardNew => true,
-            Self::SwStart | Self::SwGuard => false,
+            Self::SwStop | Self::GuardNew | Self::SwLap => true,
+            Self::SwStart | Self::SwGuard | Self::Overflow | Self::Clock | Self::GroupFull => false,
         }
     }
 
@@ -96,36 +115,52 @@ impl Error {
     /// # use libsw::Error;
     /// assert!(Error::SwStart.expects_stopped());
     /// assert!(Error::SwGuard.expects_stopped());
+    /// assert!(!Error::Overflow.expects_stopped());
     /// ```
     #[inline]
     #[must_use]
     pub const fn expects_stopped(&self) -> bool {
-        !self.expects_running()
+        match self {
+            Self::SwStart | Self::SwGuard => true,
+            Self::SwStop
+            | Self::GuardNew
+            | Self::SwLap
+            | Self::Overflow
+            | Self::Clock
+            | Self::GroupFull => false,
+        }
     }
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[inline]
-        const fn state_to_str(running: bool) -> &'static str {
-            if running {
-                "running"
-            } else {
-                "stopped"
-            }
+impl Error {
+    /// Returns the full message for the error, as written by its
+    /// [`Display`](fmt::Display) implementation.
+    pub(crate) const fn message(self) -> &'static str {
+        match self {
+            Self::SwStart => "started stopwatch while running, but expected stopped",
+            Self::SwStop => "stopped stopwatch while stopped, but expected running",
+            Self::SwGuard => "guarded stopwatch while running, but expected stopped",
+            Self::GuardNew => "created stopwatch guard while stopped, but expected running",
+            Self::SwLap => "lapped stopwatch while stopped, but expected running",
+            Self::Overflow => "stopwatch elapsed time overflowed",
+            Self::Clock => "failed to read the current time",
+            Self::GroupFull => "stopwatch group is full",
         }
+    }
+}
 
-        let verb = match self {
-            Self::SwStart => "started stopwatch",
-            Self::SwStop => "stopped stopwatch",
-            Self::SwGuard => "guarded stopwatch",
-            Self::GuardNew => "created stopwatch guard",
-        };
-
-        let state = state_to_str(!self.expects_running());
-        let expected_state = state_to_str(self.expects_running());
+#[inline]
+pub(crate) const fn state_to_str(running: bool) -> &'static str {
+    if running {
+        "running"
+    } else {
+        "stopped"
+    }
+}
 
-        write!(f, "{verb} while {state}, but expected {expected_state}")
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
     }
 }
 
@@ -0,0 +1,180 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) whose reported elapsed time is quantized to
+/// `DECIMALS` decimal places of a second.
+///
+/// The elapsed time is rounded down to a multiple of `10^-DECIMALS` seconds,
+/// so reads within the same quantum are identical regardless of the resolution
+/// of the underlying clock. This is useful for producing deterministic
+/// serialized values across machines.
+///
+/// If `DECIMALS` is 9 or more, the elapsed time is not quantized, since
+/// [`Duration`] has nanosecond precision.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{FixedStopwatch, Sw};
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let sw = FixedStopwatch::<Instant, 3>::from_inner(Sw::with_elapsed(Duration::from_micros(1_234_567)));
+/// assert_eq!(sw.elapsed(), Duration::from_millis(1234));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FixedStopwatch<I: Instant, const DECIMALS: u32> {
+    inner: StopwatchImpl<I>,
+}
+
+impl<I: Instant, const DECIMALS: u32> FixedStopwatch<I, DECIMALS> {
+    /// The quantum of reported elapsed time, in nanoseconds.
+    const QUANTUM_NANOS: u128 = 10_u128.pow(9_u32.saturating_sub(DECIMALS));
+
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self::from_inner(StopwatchImpl::new_started())
+    }
+
+    /// Returns a quantized stopwatch wrapping `inner`.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut StopwatchImpl<I> {
+        &mut self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed, rounded down to `DECIMALS` decimal
+    /// places of a second.
+    ///
+    /// # Notes
+    ///
+    /// See [`StopwatchImpl::elapsed`] for details about saturation.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(I::now())
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`, and rounded down to `DECIMALS` decimal places of a second.
+    ///
+    /// # Notes
+    ///
+    /// See [`StopwatchImpl::elapsed_at`] for details about `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        let nanos = self.inner.elapsed_at(anchor).as_nanos();
+        let quantized = nanos - nanos % Self::QUANTUM_NANOS;
+        // quantized <= nanos, so this is representable
+        #[allow(clippy::cast_possible_truncation)]
+        let subsec_nanos = (quantized % 1_000_000_000) as u32;
+        let secs = u64::try_from(quantized / 1_000_000_000).unwrap_or(u64::MAX);
+        Duration::new(secs, subsec_nanos)
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.inner.start()
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.inner.stop()
+    }
+
+    /// Stops measuring the time elapsed since the last start as if the current
+    /// time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    ///
+    /// # Notes
+    ///
+    /// See [`StopwatchImpl::stop_at`] for details about `anchor`.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Stops and resets the elapsed time to zero.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<I: Instant, const DECIMALS: u32> Default for FixedStopwatch<I, DECIMALS> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`FixedStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant, const DECIMALS: u32> From<StopwatchImpl<I>> for FixedStopwatch<I, DECIMALS> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
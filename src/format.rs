@@ -0,0 +1,342 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+use core::time::Duration;
+
+use crate::error::state_to_str;
+use crate::{Instant, StopwatchImpl};
+
+impl<I: Instant> StopwatchImpl<I> {
+    /// Formats the total time elapsed in seconds, using `decimal` as the
+    /// decimal separator and `unit_sep` between the number and the unit.
+    ///
+    /// The elapsed time is truncated to whole milliseconds, and always has
+    /// three fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_millis(1500));
+    /// assert_eq!(sw.format_localized(',', " "), "1,500 s");
+    /// assert_eq!(sw.format_localized('.', "\u{a0}"), "1.500\u{a0}s");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn format_localized(&self, decimal: char, unit_sep: &str) -> String {
+        let elapsed = self.elapsed();
+        alloc::format!(
+            "{}{decimal}{:03}{unit_sep}s",
+            elapsed.as_secs(),
+            elapsed.subsec_millis(),
+        )
+    }
+
+    /// Formats the total time elapsed as progress towards `budget`, such as
+    /// `"00:45 / 01:00 (75%)"`.
+    ///
+    /// Times are formatted as `MM:SS`, or as `HH:MM:SS` if either time is at
+    /// least an hour, truncated to whole seconds. The percentage is truncated
+    /// to a whole number.
+    ///
+    /// # Notes
+    ///
+    /// - If the elapsed time exceeds `budget`, the percentage exceeds 100%.
+    ///
+    /// - If `budget` is zero, the percentage is 100%.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(45));
+    /// let budget = Duration::from_secs(60);
+    /// assert_eq!(sw.format_progress(budget), "00:45 / 01:00 (75%)");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn format_progress(&self, budget: Duration) -> String {
+        let elapsed = self.elapsed();
+        let percent = (elapsed.as_nanos() * 100)
+            .checked_div(budget.as_nanos())
+            .unwrap_or(100);
+        let hours = elapsed.max(budget) >= Duration::from_secs(3600);
+        alloc::format!(
+            "{} / {} ({percent}%)",
+            Hms(elapsed, hours),
+            Hms(budget, hours),
+        )
+    }
+
+    /// Returns a single line describing the state of the stopwatch, intended
+    /// for grep-friendly logging.
+    ///
+    /// The line has the form `sw[running=true elapsed_ns=123456]`. Unlike the
+    /// [`Debug`](fmt::Debug) implementation, it doesn't depend on the
+    /// [`Instant`] type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_micros(1500));
+    /// assert_eq!(sw.debug_line(), "sw[running=false elapsed_ns=1500000]");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn debug_line(&self) -> String {
+        let mut line = String::new();
+        _ = self.write_debug_line(&mut line);
+        line
+    }
+
+    /// Returns a value which formats the total time elapsed in `unit`, with
+    /// `precision` fractional digits, such as `1.23s` or `1230ms`.
+    ///
+    /// The elapsed time is truncated rather than rounded. Formatting doesn't
+    /// allocate, so this works without an allocator. The formatter's width,
+    /// fill, and alignment are respected.
+    ///
+    /// # Notes
+    ///
+    /// `precision` is clamped to 9 digits, the most a nanosecond resolution
+    /// can fill. Digits finer than a nanosecond are written as zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Sw, TimeUnit};
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_micros(1_234_567));
+    /// assert_eq!(sw.format_elapsed(TimeUnit::Secs, 2).to_string(), "1.23s");
+    /// assert_eq!(sw.format_elapsed(TimeUnit::Millis, 0).to_string(), "1234ms");
+    /// assert_eq!(sw.format_elapsed(TimeUnit::Micros, 1).to_string(), "1234567.0µs");
+    /// assert_eq!(format!("{:>8}", sw.format_elapsed(TimeUnit::Secs, 2)), "   1.23s");
+    /// ```
+    #[must_use]
+    pub fn format_elapsed(&self, unit: TimeUnit, precision: usize) -> FormattedElapsed {
+        self.format_elapsed_at(unit, precision, I::now())
+    }
+
+    /// Returns a value which formats the total time elapsed in `unit`, with
+    /// `precision` fractional digits, measured as if the current time were
+    /// `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn format_elapsed_at(
+        &self,
+        unit: TimeUnit,
+        precision: usize,
+        anchor: I,
+    ) -> FormattedElapsed {
+        FormattedElapsed {
+            elapsed: self.elapsed_at(anchor),
+            unit,
+            precision: precision.min(MAX_PRECISION),
+        }
+    }
+
+    /// Writes a single line describing the state of the stopwatch to `w`.
+    ///
+    /// This is the `no_std` counterpart to [`debug_line`](Self::debug_line),
+    /// which describes the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_nanos(42));
+    /// let mut line = String::new();
+    /// sw.write_debug_line(&mut line)?;
+    /// assert_eq!(line, "sw[running=false elapsed_ns=42]");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn write_debug_line<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(
+            w,
+            "sw[running={} elapsed_ns={}]",
+            self.is_running(),
+            self.elapsed().as_nanos(),
+        )
+    }
+}
+
+impl<I: Instant> fmt::Display for StopwatchImpl<I> {
+    /// Formats the total time elapsed as `HH:MM:SS.mmm`, followed by whether
+    /// the stopwatch is running.
+    ///
+    /// Hours keep counting past a day, and the elapsed time is truncated to
+    /// whole milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_millis(3_723_045));
+    /// assert_eq!(sw.to_string(), "01:02:03.045 (stopped)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elapsed = self.elapsed();
+        let secs = elapsed.as_secs();
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:03} ({})",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            elapsed.subsec_millis(),
+            state_to_str(self.is_running()),
+        )
+    }
+}
+
+/// The most fractional digits a [`FormattedElapsed`] writes, which is enough
+/// to show nanoseconds in seconds.
+const MAX_PRECISION: usize = 9;
+
+/// A unit of time for [`FormattedElapsed`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Nanoseconds, suffixed with `ns`.
+    Nanos,
+
+    /// Microseconds, suffixed with `µs`.
+    Micros,
+
+    /// Milliseconds, suffixed with `ms`.
+    Millis,
+
+    /// Seconds, suffixed with `s`.
+    Secs,
+}
+
+impl TimeUnit {
+    const fn nanos(self) -> u128 {
+        match self {
+            Self::Nanos => 1,
+            Self::Micros => 1_000,
+            Self::Millis => 1_000_000,
+            Self::Secs => 1_000_000_000,
+        }
+    }
+
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Nanos => "ns",
+            Self::Micros => "µs",
+            Self::Millis => "ms",
+            Self::Secs => "s",
+        }
+    }
+}
+
+/// An elapsed time formatted in a chosen [unit](TimeUnit) and precision.
+///
+/// `FormattedElapsed`s are returned by the `StopwatchImpl` methods
+/// [`format_elapsed`](StopwatchImpl::format_elapsed) and
+/// [`format_elapsed_at`](StopwatchImpl::format_elapsed_at).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FormattedElapsed {
+    elapsed: Duration,
+    unit: TimeUnit,
+    precision: usize,
+}
+
+impl fmt::Display for FormattedElapsed {
+    /// Writes the elapsed time followed by the unit suffix, truncated to the
+    /// precision, and padded according to the formatter's width.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write as _;
+
+        let mut buf = StackStr::new();
+        let unit = self.unit.nanos();
+        let nanos = self.elapsed.as_nanos();
+        write!(buf, "{}", nanos / unit)?;
+        if self.precision > 0 {
+            buf.write_char('.')?;
+            let mut rest = nanos % unit;
+            for _ in 0..self.precision {
+                rest *= 10;
+                write!(buf, "{}", rest / unit)?;
+                rest %= unit;
+            }
+        }
+        buf.write_str(self.unit.suffix())?;
+        f.pad(buf.as_str())
+    }
+}
+
+/// A string on the stack, long enough for any [`FormattedElapsed`].
+struct StackStr {
+    // 39 integer digits of a `u128`, a decimal point, 9 fractional digits, and
+    // a suffix of at most 3 bytes
+    bytes: [u8; 52],
+    len: usize,
+}
+
+impl StackStr {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; 52],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // only whole `str`s are ever written
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for StackStr {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Formats a duration as `MM:SS`, or `HH:MM:SS` if the flag is set.
+#[cfg(feature = "alloc")]
+struct Hms(Duration, bool);
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Hms {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.0.as_secs();
+        if self.1 {
+            write!(
+                f,
+                "{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            )
+        } else {
+            write!(f, "{:02}:{:02}", secs / 60, secs % 60)
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+use ::once_cell::race::OnceRef;
+
+use crate::{Instant, Resolution};
+
+static GLOBAL_CLOCK: OnceRef<'static, fn() -> u64> = OnceRef::new();
+
+/// Registers the global nanosecond counter read by [`GlobalInstant::now`].
+///
+/// The global clock may only be registered **once**. This should be done at
+/// startup, before any stopwatch reads the current time.
+///
+/// # Errors
+///
+/// If a global clock has already been registered, the existing clock is kept
+/// and `now` is returned back.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::GlobalSw;
+/// fn read_timer() -> u64 {
+///     // read from a hardware timer, for example
+///     42
+/// }
+///
+/// libsw::set_global_clock(&(read_timer as fn() -> u64)).unwrap();
+/// assert!(GlobalSw::new_started().is_running());
+/// ```
+pub fn set_global_clock(now: &'static fn() -> u64) -> Result<(), &'static fn() -> u64> {
+    GLOBAL_CLOCK.set(now).map_err(|()| now)
+}
+
+/// Returns `true` if a global clock has been registered with
+/// [`set_global_clock`].
+#[must_use]
+pub fn has_global_clock() -> bool {
+    GLOBAL_CLOCK.get().is_some()
+}
+
+/// An instant read from a runtime-registered global clock.
+///
+/// This is intended for `no_std` targets without a built-in clock, such as
+/// bare-metal targets with a hardware timer counting nanoseconds. Register the
+/// function reading the counter once with [`set_global_clock`], after which
+/// methods like
+/// [`StopwatchImpl::new_started`](crate::StopwatchImpl::new_started) work
+/// without an explicit anchor. No allocator is needed.
+///
+/// A `GlobalInstant` counts nanoseconds since an arbitrary epoch chosen by the
+/// registered counter. The counter should be monotonic. Arithmetic maps
+/// directly onto the `u64` count of nanoseconds, and returns [`None`] on
+/// overflow.
+///
+/// # Wiring up a nanosecond counter
+///
+/// Registration takes a `'static` reference to a function pointer. Casting the
+/// function which reads the counter to `fn() -> u64` and borrowing it gives
+/// such a reference, since the constant is promoted to a static.
+///
+/// ```
+/// # use libsw::GlobalSw;
+/// # use core::sync::atomic::{AtomicU64, Ordering};
+/// # static TIMER: AtomicU64 = AtomicU64::new(0);
+/// // provided by the board support crate, for example
+/// fn read_timer_nanos() -> u64 {
+///     TIMER.fetch_add(1_000, Ordering::Relaxed)
+/// }
+///
+/// libsw::set_global_clock(&(read_timer_nanos as fn() -> u64)).unwrap();
+/// let mut sw = GlobalSw::new_started();
+/// sw.stop()?;
+/// assert_eq!(u64::try_from(sw.elapsed().as_nanos()), Ok(1_000));
+/// # Ok::<(), libsw::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GlobalInstant {
+    nanos: u64,
+}
+
+impl GlobalInstant {
+    /// Returns an instant `nanos` nanoseconds after the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self { nanos }
+    }
+
+    /// Returns the number of nanoseconds since the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos(&self) -> u64 {
+        self.nanos
+    }
+}
+
+impl From<u64> for GlobalInstant {
+    /// Returns an instant `nanos` nanoseconds after the clock's epoch. Same as
+    /// calling [`GlobalInstant::from_nanos`].
+    fn from(nanos: u64) -> Self {
+        Self::from_nanos(nanos)
+    }
+}
+
+impl From<GlobalInstant> for u64 {
+    /// Returns the number of nanoseconds since the clock's epoch. Same as
+    /// calling [`GlobalInstant::as_nanos`].
+    fn from(instant: GlobalInstant) -> Self {
+        instant.as_nanos()
+    }
+}
+
+impl Instant for GlobalInstant {
+    /// Returns the current instant according to the global clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no global clock has been registered with
+    /// [`set_global_clock`].
+    #[track_caller]
+    fn now() -> Self {
+        let now = GLOBAL_CLOCK
+            .get()
+            .expect("global clock must be registered before use");
+        Self::from_nanos(now())
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        self.nanos.checked_add(nanos).map(Self::from_nanos)
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        self.nanos.checked_sub(nanos).map(Self::from_nanos)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.nanos.saturating_sub(earlier.nanos))
+    }
+}
+
+impl Resolution for GlobalInstant {}
@@ -0,0 +1,155 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+use std::collections::HashMap;
+use std::string::String;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// A collection of [stopwatches](StopwatchImpl) identified by name.
+///
+/// Named stopwatches are created on demand when first started. Reading or
+/// stopping a name which has never been started behaves as if it were a
+/// stopped stopwatch with zero elapsed time.
+///
+/// See [`thread_local`](crate::thread_local) for a group which is available
+/// anywhere on the current thread.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::StopwatchGroup;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut group = StopwatchGroup::<Instant>::new();
+/// group.start_at("parse", start)?;
+/// group.stop_at("parse", start + Duration::from_secs(2))?;
+/// assert_eq!(group.elapsed("parse"), Duration::from_secs(2));
+/// assert_eq!(group.elapsed("render"), Duration::ZERO);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StopwatchGroup<I: Instant> {
+    stopwatches: HashMap<String, StopwatchImpl<I>>,
+}
+
+impl<I: Instant> StopwatchGroup<I> {
+    /// Returns an empty group.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            stopwatches: HashMap::new(),
+        }
+    }
+
+    /// Returns a reference to the stopwatch called `name`, if it exists.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&StopwatchImpl<I>> {
+        self.stopwatches.get(name)
+    }
+
+    /// Returns a mutable reference to the stopwatch called `name`, creating a
+    /// stopped stopwatch if it doesn't exist.
+    pub fn get_or_insert(&mut self, name: &str) -> &mut StopwatchImpl<I> {
+        self.stopwatches.entry(String::from(name)).or_default()
+    }
+
+    /// Returns the number of named stopwatches in the group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stopwatches.len()
+    }
+
+    /// Returns `true` if the group contains no stopwatches.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stopwatches.is_empty()
+    }
+
+    /// Returns the total time elapsed of the stopwatch called `name`, or zero
+    /// if it doesn't exist.
+    #[must_use]
+    pub fn elapsed(&self, name: &str) -> Duration {
+        self.elapsed_at(name, I::now())
+    }
+
+    /// Returns the total time elapsed of the stopwatch called `name`, measured
+    /// as if the current time were `anchor`, or zero if it doesn't exist.
+    #[must_use]
+    pub fn elapsed_at(&self, name: &str, anchor: I) -> Duration {
+        self.get(name)
+            .map_or(Duration::ZERO, |sw| sw.elapsed_at(anchor))
+    }
+
+    /// Starts the stopwatch called `name`, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self, name: &str) -> crate::Result<()> {
+        self.start_at(name, I::now())
+    }
+
+    /// Starts the stopwatch called `name` as if the current time were
+    /// `anchor`, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, name: &str, anchor: I) -> crate::Result<()> {
+        self.get_or_insert(name).start_at(anchor)
+    }
+
+    /// Stops the stopwatch called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped
+    /// or doesn't exist.
+    pub fn stop(&mut self, name: &str) -> crate::Result<()> {
+        self.stop_at(name, I::now())
+    }
+
+    /// Stops the stopwatch called `name` as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped
+    /// or doesn't exist.
+    pub fn stop_at(&mut self, name: &str, anchor: I) -> crate::Result<()> {
+        self.stopwatches
+            .get_mut(name)
+            .ok_or(Error::SwStop)?
+            .stop_at(anchor)
+    }
+
+    /// Removes the stopwatch called `name`, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<StopwatchImpl<I>> {
+        self.stopwatches.remove(name)
+    }
+
+    /// Removes all stopwatches from the group.
+    pub fn clear(&mut self) {
+        self.stopwatches.clear();
+    }
+
+    /// Returns an iterator over the names and stopwatches in the group, in
+    /// arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &StopwatchImpl<I>)> {
+        self.stopwatches
+            .iter()
+            .map(|(name, sw)| (name.as_str(), sw))
+    }
+}
+
+impl<I: Instant> Default for StopwatchGroup<I> {
+    /// Returns an empty group. Same as calling [`StopwatchGroup::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -14,15 +14,13 @@ guard3:                 ^ created
                          ^ dropped
 ```
 
-## you could give it a closure to run on the stopwatch when dropped
-struct GuardFn { inner: &mut Stopwatch, callback: FnOnce(&mut Stopwatch) }
-impl Drop for GuardFn {
-    fn drop(&mut self) {
-        (self.callback)(self.inner);
-    }
-}
+overlapping guards which don't stop the stopwatch until the last one is
+dropped are provided by `SharedStopwatch`, but masking is still missing.
 */
 
+use core::fmt;
+use core::time::Duration;
+
 use crate::{Error, Instant, StopwatchImpl};
 
 /// A running, guarded, [stopwatch](StopwatchImpl). When [dropped](Guard::drop),
@@ -31,6 +29,14 @@ use crate::{Error, Instant, StopwatchImpl};
 /// `Guard`s are returned by the `StopwatchImpl` methods
 /// [`guard`](StopwatchImpl::guard) and [`guard_at`](StopwatchImpl::guard_at).
 ///
+/// # Notes
+///
+/// A `Guard` doesn't track any state of its own, so any mutation of the
+/// guarded stopwatch through [`inner_mut`](Self::inner_mut) is allowed.
+/// Stopping it is the same as [pausing](Self::pause) the guard, and starting
+/// it again is the same as [resuming](Self::resume) it. When dropped, the
+/// guard only stops the stopwatch if it's running.
+///
 /// # Examples
 ///
 /// ```
@@ -53,7 +59,6 @@ use crate::{Error, Instant, StopwatchImpl};
 #[must_use = "if unused, the inner stopwatch will immediately stop again"]
 #[derive(Debug, Hash)]
 pub struct Guard<'sw, I: Instant> {
-    // invariant: sw must be running
     inner: &'sw mut StopwatchImpl<I>,
 }
 
@@ -87,6 +92,13 @@ impl<'sw, I: Instant> Guard<'sw, I> {
         }
     }
 
+    /// Returns a `Guard` to a [stopwatch](StopwatchImpl) which the caller has
+    /// just started.
+    pub(crate) fn new_started(sw: &'sw mut StopwatchImpl<I>) -> Self {
+        debug_assert!(sw.is_running());
+        Self { inner: sw }
+    }
+
     /// Returns a reference to the inner [`StopwatchImpl`].
     ///
     /// # Examples
@@ -108,15 +120,115 @@ impl<'sw, I: Instant> Guard<'sw, I> {
     pub const fn inner(&self) -> &StopwatchImpl<I> {
         self.inner
     }
+
+    /// Returns a mutable reference to the inner [`StopwatchImpl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut sw = Sw::new();
+    /// {
+    ///     let mut guard = sw.guard()?;
+    ///     *guard.inner_mut() += Duration::from_secs(1);
+    ///     assert!(guard.inner().is_running());
+    /// }
+    /// assert!(sw.is_stopped());
+    /// assert!(sw.elapsed() >= Duration::from_secs(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns `true` if the guard is [paused](Self::pause), and so the guarded
+    /// stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Pauses timing by stopping the guarded [stopwatch](StopwatchImpl),
+    /// while keeping the guard alive. Dropping a paused guard doesn't stop the
+    /// stopwatch again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the guard is already paused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut sw = Sw::new();
+    /// {
+    ///     let mut guard = sw.guard()?;
+    ///     guard.pause()?;
+    ///     // awaiting user input isn't timed
+    ///     thread::sleep(Duration::from_millis(100));
+    ///     guard.resume()?;
+    /// }
+    /// assert!(sw.is_stopped());
+    /// assert!(sw.elapsed() < Duration::from_millis(100));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pause(&mut self) -> crate::Result<()> {
+        self.pause_at(I::now())
+    }
+
+    /// Pauses timing by stopping the guarded [stopwatch](StopwatchImpl) as if
+    /// the current time were `anchor`, while keeping the guard alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the guard is already paused.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    pub fn pause_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Resumes timing by starting the guarded [stopwatch](StopwatchImpl)
+    /// after it was [paused](Self::pause).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the guard isn't paused.
+    pub fn resume(&mut self) -> crate::Result<()> {
+        self.resume_at(I::now())
+    }
+
+    /// Resumes timing by starting the guarded [stopwatch](StopwatchImpl) as if
+    /// the current time were `anchor`, after it was [paused](Self::pause).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the guard isn't paused.
+    pub fn resume_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
 }
 
 impl<I: Instant> Drop for Guard<'_, I> {
     /// Releases the guard, calling [`stop`](StopwatchImpl::stop) on the guarded
-    /// [stopwatch](StopwatchImpl).
+    /// [stopwatch](StopwatchImpl) unless the guard is paused.
     #[inline]
     fn drop(&mut self) {
-        debug_assert!(self.inner.is_running());
-        _ = self.inner.stop();
+        if self.inner.is_running() {
+            _ = self.inner.stop();
+        }
     }
 }
 
@@ -127,3 +239,304 @@ impl<I: Instant> PartialEq for Guard<'_, I> {
 }
 
 impl<I: Instant> Eq for Guard<'_, I> {}
+
+/// A running, guarded, [stopwatch](StopwatchImpl) which accumulates into a
+/// [`Duration`]. When [dropped](GuardInto::drop), the stopwatch will
+/// automatically stop, and the time elapsed while guarded is added to the
+/// accumulator.
+///
+/// This is useful for totalling the time spent in a repeatedly entered scope
+/// without keeping a second stopwatch around for the sum.
+///
+/// `GuardInto`s are returned by the `StopwatchImpl` methods
+/// [`guard_into`](StopwatchImpl::guard_into) and
+/// [`guard_into_at`](StopwatchImpl::guard_into_at).
+///
+/// # Notes
+///
+/// The accumulator saturates to [`Duration::MAX`] on overflow.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::thread;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = Sw::new();
+/// let mut total = Duration::ZERO;
+/// for _ in 0..3 {
+///     let _guard = sw.guard_into(&mut total)?;
+///     thread::sleep(Duration::from_millis(10));
+/// }
+/// assert!(total >= Duration::from_millis(30));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "if unused, the inner stopwatch will immediately stop again"]
+#[derive(Debug, Hash)]
+pub struct GuardInto<'sw, 'acc, I: Instant> {
+    // invariant: sw must be running
+    inner: &'sw mut StopwatchImpl<I>,
+    acc: &'acc mut Duration,
+}
+
+impl<'sw, 'acc, I: Instant> GuardInto<'sw, 'acc, I> {
+    /// Returns a `GuardInto` to a running [stopwatch](StopwatchImpl), which
+    /// will add the time elapsed while guarded to `acc`.
+    ///
+    /// # Errors
+    ///
+    /// If the stopwatch is stopped, returns [`GuardNew`](Error::GuardNew).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Error, GuardInto, Sw};
+    /// # use core::time::Duration;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut total = Duration::ZERO;
+    /// let mut sw = Sw::new();
+    /// assert_eq!(GuardInto::new(&mut sw, &mut total), Err(Error::GuardNew));
+    ///
+    /// sw.start()?;
+    /// assert!(GuardInto::new(&mut sw, &mut total).is_ok());
+    /// assert!(sw.is_stopped());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(sw: &'sw mut StopwatchImpl<I>, acc: &'acc mut Duration) -> crate::Result<Self> {
+        if sw.is_running() {
+            Ok(Self { inner: sw, acc })
+        } else {
+            Err(Error::GuardNew)
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the current value of the accumulator, not including the time
+    /// elapsed while guarded.
+    #[inline]
+    #[must_use]
+    pub const fn accumulated(&self) -> Duration {
+        *self.acc
+    }
+}
+
+impl<I: Instant> Drop for GuardInto<'_, '_, I> {
+    /// Releases the guard, stopping the guarded [stopwatch](StopwatchImpl) and
+    /// adding the time elapsed since it was last started to the accumulator.
+    fn drop(&mut self) {
+        debug_assert!(self.inner.is_running());
+        let now = I::now();
+        if let Some(start) = self.inner.inner.start {
+            let segment = now.saturating_duration_since(start);
+            *self.acc = self.acc.saturating_add(segment);
+        }
+        _ = self.inner.stop_at(now);
+    }
+}
+
+impl<I: Instant> PartialEq for GuardInto<'_, '_, I> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.inner() == rhs.inner() && self.accumulated() == rhs.accumulated()
+    }
+}
+
+impl<I: Instant> Eq for GuardInto<'_, '_, I> {}
+
+/// A running, guarded, [stopwatch](StopwatchImpl) which checks the time elapsed
+/// while guarded against a threshold, such as a service level agreement. When
+/// [dropped](SlaGuard::drop), the stopwatch will automatically stop, and if
+/// the time elapsed while guarded exceeds the threshold, the breach callback
+/// is invoked with it.
+///
+/// `SlaGuard`s are returned by the `StopwatchImpl` methods
+/// [`sla_guard`](StopwatchImpl::sla_guard) and
+/// [`sla_guard_at`](StopwatchImpl::sla_guard_at).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::thread;
+/// # fn main() -> libsw::Result<()> {
+/// fn on_breach(elapsed: Duration) {
+///     eprintln!("request took {elapsed:?}");
+/// }
+///
+/// let mut sw = Sw::new();
+/// {
+///     let _guard = sw.sla_guard(Duration::from_millis(50), on_breach)?;
+///     thread::sleep(Duration::from_millis(100));
+///     // guard dropped, breach reported
+/// }
+/// assert!(sw.is_stopped());
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "if unused, the inner stopwatch will immediately stop again"]
+#[derive(Debug, Hash)]
+pub struct SlaGuard<'sw, I: Instant> {
+    // invariant: sw must be running
+    inner: &'sw mut StopwatchImpl<I>,
+    threshold: Duration,
+    on_breach: fn(Duration),
+}
+
+impl<'sw, I: Instant> SlaGuard<'sw, I> {
+    /// Returns an `SlaGuard` to a running [stopwatch](StopwatchImpl), which
+    /// will call `on_breach` if the time elapsed while guarded exceeds
+    /// `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// If the stopwatch is stopped, returns [`GuardNew`](Error::GuardNew).
+    pub fn new(
+        sw: &'sw mut StopwatchImpl<I>,
+        threshold: Duration,
+        on_breach: fn(Duration),
+    ) -> crate::Result<Self> {
+        if sw.is_running() {
+            Ok(Self {
+                inner: sw,
+                threshold,
+                on_breach,
+            })
+        } else {
+            Err(Error::GuardNew)
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the threshold which the time elapsed while guarded must not
+    /// exceed.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> Duration {
+        self.threshold
+    }
+}
+
+impl<I: Instant> Drop for SlaGuard<'_, I> {
+    /// Releases the guard, stopping the guarded [stopwatch](StopwatchImpl). If
+    /// the time elapsed since it was last started exceeds the threshold, the
+    /// breach callback is invoked with it.
+    fn drop(&mut self) {
+        debug_assert!(self.inner.is_running());
+        let now = I::now();
+        let segment = self
+            .inner
+            .inner
+            .start
+            .map_or(Duration::ZERO, |start| now.saturating_duration_since(start));
+        _ = self.inner.stop_at(now);
+        if segment > self.threshold {
+            (self.on_breach)(segment);
+        }
+    }
+}
+
+impl<I: Instant> PartialEq for SlaGuard<'_, I> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.inner() == rhs.inner() && self.threshold() == rhs.threshold()
+    }
+}
+
+impl<I: Instant> Eq for SlaGuard<'_, I> {}
+
+/// A running, guarded, [stopwatch](StopwatchImpl) which runs a closure when
+/// [dropped](GuardFn::drop). The stopwatch is stopped before the closure is
+/// called, so the closure sees its final elapsed time.
+///
+/// This is useful for cleanup on scope exit, such as logging the time taken.
+///
+/// `GuardFn`s are returned by the `StopwatchImpl` methods
+/// [`guard_with`](StopwatchImpl::guard_with) and
+/// [`guard_with_at`](StopwatchImpl::guard_with_at).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::thread;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = Sw::new();
+/// let mut logged = Duration::ZERO;
+/// {
+///     let _guard = sw.guard_with(|sw| logged = sw.elapsed())?;
+///     thread::sleep(Duration::from_millis(100));
+///     // guard dropped, stopwatch stopped, then closure called
+/// }
+/// assert!(sw.is_stopped());
+/// assert_eq!(logged, sw.elapsed());
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "if unused, the inner stopwatch will immediately stop again"]
+pub struct GuardFn<'sw, I: Instant, F: FnOnce(&mut StopwatchImpl<I>)> {
+    // invariant: sw must be running
+    inner: &'sw mut StopwatchImpl<I>,
+    // invariant: Some until dropped
+    callback: Option<F>,
+}
+
+impl<'sw, I: Instant, F: FnOnce(&mut StopwatchImpl<I>)> GuardFn<'sw, I, F> {
+    /// Returns a `GuardFn` to a running [stopwatch](StopwatchImpl), which will
+    /// call `f` with the stopped stopwatch when dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the stopwatch is stopped, returns [`GuardNew`](Error::GuardNew).
+    pub fn new(sw: &'sw mut StopwatchImpl<I>, f: F) -> crate::Result<Self> {
+        if sw.is_running() {
+            Ok(Self {
+                inner: sw,
+                callback: Some(f),
+            })
+        } else {
+            Err(Error::GuardNew)
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        self.inner
+    }
+}
+
+impl<I: Instant, F: FnOnce(&mut StopwatchImpl<I>)> Drop for GuardFn<'_, I, F> {
+    /// Releases the guard, stopping the guarded [stopwatch](StopwatchImpl) and
+    /// then calling the closure with it.
+    fn drop(&mut self) {
+        debug_assert!(self.inner.is_running());
+        _ = self.inner.stop();
+        if let Some(callback) = self.callback.take() {
+            callback(self.inner);
+        }
+    }
+}
+
+impl<I: Instant, F: FnOnce(&mut StopwatchImpl<I>)> fmt::Debug for GuardFn<'_, I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GuardFn")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,69 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+#[cfg(feature = "cpu_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "cpu_time")))]
+mod cpu_time;
+
+#[cfg(feature = "boottime")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "boottime")))]
+mod boottime;
+
+#[cfg(feature = "web_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "web_time")))]
+mod web_time;
+
+#[cfg(feature = "minstant")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "minstant")))]
+mod minstant;
+
+#[cfg(all(feature = "std_instant", feature = "std_systemtime"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(feature = "std_instant", feature = "std_systemtime")))
+)]
+mod monotonic_system;
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+mod chrono;
+
+#[cfg(feature = "quanta")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "quanta")))]
+mod quanta;
+
+#[cfg(feature = "embedded_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "embedded_time")))]
+mod embedded_time;
+
+#[cfg(feature = "fugit")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fugit")))]
+mod fugit;
+
+#[cfg(feature = "cpu_time")]
+pub use self::cpu_time::ProcessCpuInstant;
+
+#[cfg(feature = "boottime")]
+pub use self::boottime::BoottimeInstant;
+
+#[cfg(feature = "web_time")]
+pub use self::web_time::WebInstant;
+
+#[cfg(feature = "minstant")]
+pub use self::minstant::MinstantInstant;
+
+#[cfg(all(feature = "std_instant", feature = "std_systemtime"))]
+pub use self::monotonic_system::MonotonicSystemInstant;
+
+#[cfg(feature = "chrono")]
+pub use self::chrono::ChronoInstant;
+
+#[cfg(feature = "quanta")]
+pub use self::quanta::QuantaClockInstant;
+
+#[cfg(feature = "embedded_time")]
+pub use self::embedded_time::EmbeddedInstant;
+
+#[cfg(feature = "fugit")]
+pub use self::fugit::{FugitClock, FugitInstant};
@@ -0,0 +1,73 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+type Inner = Duration;
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+type Inner = ::std::time::Instant;
+
+/// A monotonic instant which keeps counting while the system is suspended.
+///
+/// Whether [`std::time::Instant`] includes time spent suspended (for example
+/// while a laptop is asleep) depends on the platform. `BoottimeInstant` is
+/// intended to consistently include it.
+///
+/// # Platform support
+///
+/// | Platform          | Clock                                   | Includes suspend |
+/// |-------------------|-----------------------------------------|------------------|
+/// | Linux, Android    | `CLOCK_BOOTTIME`                        | Yes              |
+/// | Everything else   | Falls back to [`std::time::Instant`]    | Platform-defined |
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BoottimeInstant {
+    inner: Inner,
+}
+
+impl Instant for BoottimeInstant {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn now() -> Self {
+        use rustix::time::{clock_gettime, ClockId};
+
+        let ts = clock_gettime(ClockId::Boottime);
+        let secs = u64::try_from(ts.tv_sec).unwrap_or(0);
+        let nanos = u32::try_from(ts.tv_nsec).unwrap_or(0);
+        Self {
+            inner: Duration::new(secs, nanos),
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn now() -> Self {
+        Self {
+            inner: ::std::time::Instant::now(),
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_add(duration)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_sub(duration)?;
+        Some(Self { inner })
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner.saturating_sub(earlier.inner)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner.saturating_duration_since(earlier.inner)
+    }
+}
+
+impl Resolution for BoottimeInstant {}
@@ -0,0 +1,76 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{Instant, Resolution};
+
+/// An instant read from the `chrono` crate's UTC wall clock.
+///
+/// This is backed by [`DateTime<Utc>`](DateTime), so stopwatches can share a
+/// clock source with event times stored as `chrono` timestamps.
+///
+/// # Notes
+///
+/// This is a wall clock, not a monotonic one. Like
+/// [`SystemTime`](std::time::SystemTime), it can jump forwards or backwards,
+/// for example when the system time is adjusted. Durations measured across a
+/// backwards jump saturate to zero.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChronoInstant {
+    inner: DateTime<Utc>,
+}
+
+impl ChronoInstant {
+    /// Returns the inner [`DateTime<Utc>`](DateTime).
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> DateTime<Utc> {
+        self.inner
+    }
+}
+
+impl From<DateTime<Utc>> for ChronoInstant {
+    fn from(inner: DateTime<Utc>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<ChronoInstant> for DateTime<Utc> {
+    fn from(instant: ChronoInstant) -> Self {
+        instant.inner
+    }
+}
+
+impl Instant for ChronoInstant {
+    fn now() -> Self {
+        Self { inner: Utc::now() }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let delta = TimeDelta::from_std(duration).ok()?;
+        let inner = self.inner.checked_add_signed(delta)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let delta = TimeDelta::from_std(duration).ok()?;
+        let inner = self.inner.checked_sub_signed(delta)?;
+        Some(Self { inner })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner
+            .signed_duration_since(earlier.inner)
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+impl Resolution for ChronoInstant {
+    fn resolution() -> Duration {
+        crate::resolution::OS_RESOLUTION
+    }
+}
@@ -0,0 +1,61 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+/// An instant measured in CPU time consumed by the current process.
+///
+/// Unlike wall clock instants, CPU time does not advance while the process is
+/// sleeping or blocked on IO. This is useful for timing CPU-bound work.
+///
+/// This is backed by the `cpu_time` crate's
+/// [`ProcessTime`](cpu_time::ProcessTime).
+///
+/// # Panics
+///
+/// [`ProcessCpuInstant::now`](Instant::now) panics if the CPU time could not be
+/// read from the operating system.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProcessCpuInstant {
+    cpu_time: Duration,
+}
+
+impl ProcessCpuInstant {
+    /// Returns the CPU time consumed by the process up to this instant.
+    #[inline]
+    #[must_use]
+    pub const fn as_duration(&self) -> Duration {
+        self.cpu_time
+    }
+}
+
+impl Instant for ProcessCpuInstant {
+    fn now() -> Self {
+        Self {
+            cpu_time: cpu_time::ProcessTime::now().as_duration(),
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let cpu_time = self.cpu_time.checked_add(duration)?;
+        Some(Self { cpu_time })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let cpu_time = self.cpu_time.checked_sub(duration)?;
+        Some(Self { cpu_time })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.cpu_time.saturating_sub(earlier.cpu_time)
+    }
+}
+
+impl Resolution for ProcessCpuInstant {
+    fn resolution() -> Duration {
+        crate::resolution::OS_RESOLUTION
+    }
+}
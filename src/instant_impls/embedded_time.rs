@@ -0,0 +1,136 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::convert::TryFrom;
+use ::core::fmt::Debug;
+use ::core::ops::Div;
+use ::core::time::Duration;
+use embedded_time::duration::Nanoseconds;
+use embedded_time::Clock;
+
+use crate::{ClockError, Instant, Resolution, TryInstant};
+
+/// An instant read from an `embedded-time` [`Clock`].
+///
+/// `embedded-time` clocks are read through an instance, with
+/// [`Clock::try_now`]. This creates the clock with [`Default::default`]
+/// whenever the current time is needed, so it suits clocks which are
+/// zero-sized handles to a hardware timer.
+///
+/// Reading the clock is fallible, so prefer the [`TryInstant`] methods of
+/// [`StopwatchImpl`](crate::StopwatchImpl), such as
+/// [`try_start`](crate::StopwatchImpl::try_start), which never panic.
+///
+/// # Notes
+///
+/// The clock's ticks wrap around, so durations are only meaningful across
+/// less than half the range of [`Clock::T`].
+pub struct EmbeddedInstant<C: Clock> {
+    inner: embedded_time::Instant<C>,
+}
+
+impl<C: Clock> EmbeddedInstant<C> {
+    /// Returns the inner [`embedded_time::Instant`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> embedded_time::Instant<C> {
+        self.inner
+    }
+}
+
+impl<C: Clock> Clone for EmbeddedInstant<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Clock> Copy for EmbeddedInstant<C> {}
+
+impl<C: Clock + Debug> Debug for EmbeddedInstant<C> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("EmbeddedInstant")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<C: Clock> PartialEq for EmbeddedInstant<C> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.inner == rhs.inner
+    }
+}
+
+impl<C: Clock> Eq for EmbeddedInstant<C> {}
+
+impl<C: Clock> From<embedded_time::Instant<C>> for EmbeddedInstant<C> {
+    fn from(inner: embedded_time::Instant<C>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<C: Clock> Instant for EmbeddedInstant<C>
+where
+    C: Default + Debug,
+    C::T: TryFrom<u64> + Div<Output = C::T>,
+    u64: TryFrom<C::T>,
+{
+    /// Returns the current instant in time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reading the clock fails. Use [`TryInstant::try_now`] to
+    /// handle this instead.
+    fn now() -> Self {
+        Self::try_now().expect("failed to read embedded-time clock")
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let nanos = Nanoseconds::<u64>::try_from(duration).ok()?;
+        let inner = self.inner.checked_add(nanos)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let nanos = Nanoseconds::<u64>::try_from(duration).ok()?;
+        let inner = self.inner.checked_sub(nanos)?;
+        Some(Self { inner })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner
+            .checked_duration_since(&earlier.inner)
+            .map_or(Duration::ZERO, |since| {
+                Nanoseconds::<u64>::try_from(since)
+                    .ok()
+                    .and_then(|nanos| Duration::try_from(nanos).ok())
+                    .unwrap_or(Duration::MAX)
+            })
+    }
+}
+
+impl<C: Clock> TryInstant for EmbeddedInstant<C>
+where
+    C: Default + Debug,
+    C::T: TryFrom<u64> + Div<Output = C::T>,
+    u64: TryFrom<C::T>,
+{
+    fn try_now() -> Result<Self, ClockError> {
+        let inner = C::default().try_now().map_err(|_| ClockError)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<C: Clock> Resolution for EmbeddedInstant<C>
+where
+    C: Default + Debug,
+    C::T: TryFrom<u64> + Div<Output = C::T>,
+    u64: TryFrom<C::T>,
+{
+    /// Returns the duration of one tick of the clock.
+    fn resolution() -> Duration {
+        let nanos = u128::from(*C::SCALING_FACTOR.numerator()) * 1_000_000_000
+            / u128::from(*C::SCALING_FACTOR.denominator());
+        <u64 as TryFrom<u128>>::try_from(nanos).map_or(Duration::MAX, Duration::from_nanos)
+    }
+}
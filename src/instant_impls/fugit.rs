@@ -0,0 +1,178 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::convert::TryFrom;
+use ::core::fmt::Debug;
+use ::core::marker::PhantomData;
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// A monotonic source of [`fugit::Instant`]s, with a tick period of
+/// `NOM / DENOM` seconds.
+///
+/// `fugit` has no global notion of the current time, so this pairs its
+/// instants with the timer which produces them, such as an RTIC monotonic.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{FugitClock, FugitInstant, StopwatchImpl};
+/// /// Token for a 1 MHz hardware timer.
+/// struct Mono;
+///
+/// impl FugitClock<1, 1_000_000> for Mono {
+///     fn now() -> fugit::Instant<u64, 1, 1_000_000> {
+///         # let read_timer = || 0;
+///         fugit::Instant::<u64, 1, 1_000_000>::from_ticks(read_timer())
+///     }
+/// }
+///
+/// type MonoSw = StopwatchImpl<FugitInstant<Mono, 1, 1_000_000>>;
+/// let sw = MonoSw::new_started();
+/// ```
+pub trait FugitClock<const NOM: u32, const DENOM: u32> {
+    /// Returns the current instant in time.
+    fn now() -> fugit::Instant<u64, NOM, DENOM>;
+}
+
+/// An instant read from a [`FugitClock`], such as an RTIC monotonic.
+///
+/// `fugit` instants are const-generic over their tick period of `NOM / DENOM`
+/// seconds, and have no global `now`, so each is paired with the clock `C`
+/// which produces it.
+///
+/// # Notes
+///
+/// The clock's ticks wrap around, so durations are only meaningful across
+/// less than half the range of [`u64`] ticks. Durations added to or subtracted
+/// from an instant are truncated to whole ticks.
+pub struct FugitInstant<C, const NOM: u32, const DENOM: u32> {
+    inner: fugit::Instant<u64, NOM, DENOM>,
+    clock: PhantomData<fn() -> C>,
+}
+
+impl<C, const NOM: u32, const DENOM: u32> FugitInstant<C, NOM, DENOM> {
+    /// Returns the inner [`fugit::Instant`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> fugit::Instant<u64, NOM, DENOM> {
+        self.inner
+    }
+
+    const fn from_inner(inner: fugit::Instant<u64, NOM, DENOM>) -> Self {
+        Self {
+            inner,
+            clock: PhantomData,
+        }
+    }
+}
+
+impl<C, const NOM: u32, const DENOM: u32> Clone for FugitInstant<C, NOM, DENOM> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, const NOM: u32, const DENOM: u32> Copy for FugitInstant<C, NOM, DENOM> {}
+
+impl<C, const NOM: u32, const DENOM: u32> Debug for FugitInstant<C, NOM, DENOM> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FugitInstant")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<C, const NOM: u32, const DENOM: u32> PartialEq for FugitInstant<C, NOM, DENOM> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.inner == rhs.inner
+    }
+}
+
+impl<C, const NOM: u32, const DENOM: u32> Eq for FugitInstant<C, NOM, DENOM> {}
+
+impl<C, const NOM: u32, const DENOM: u32> From<fugit::Instant<u64, NOM, DENOM>>
+    for FugitInstant<C, NOM, DENOM>
+{
+    fn from(inner: fugit::Instant<u64, NOM, DENOM>) -> Self {
+        Self::from_inner(inner)
+    }
+}
+
+impl<C, const NOM: u32, const DENOM: u32> From<FugitInstant<C, NOM, DENOM>>
+    for fugit::Instant<u64, NOM, DENOM>
+{
+    fn from(instant: FugitInstant<C, NOM, DENOM>) -> Self {
+        instant.inner
+    }
+}
+
+impl<C: FugitClock<NOM, DENOM>, const NOM: u32, const DENOM: u32> Instant
+    for FugitInstant<C, NOM, DENOM>
+{
+    fn now() -> Self {
+        Self::from_inner(C::now())
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let ticks = to_ticks::<NOM, DENOM>(duration)?;
+        let inner = self.inner.checked_add_duration(ticks)?;
+        Some(Self::from_inner(inner))
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let ticks = to_ticks::<NOM, DENOM>(duration)?;
+        let inner = self.inner.checked_sub_duration(ticks)?;
+        Some(Self::from_inner(inner))
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner
+            .checked_duration_since(earlier.inner)
+            .map_or(Duration::ZERO, from_ticks::<NOM, DENOM>)
+    }
+}
+
+impl<C: FugitClock<NOM, DENOM>, const NOM: u32, const DENOM: u32> Resolution
+    for FugitInstant<C, NOM, DENOM>
+{
+    /// Returns the duration of one tick of the clock.
+    fn resolution() -> Duration {
+        from_ticks(fugit::Duration::<u64, NOM, DENOM>::from_ticks(1))
+    }
+}
+
+/// Converts `duration` to whole ticks, or returns [`None`] if they don't fit
+/// in a [`u64`].
+fn to_ticks<const NOM: u32, const DENOM: u32>(
+    duration: Duration,
+) -> Option<fugit::Duration<u64, NOM, DENOM>> {
+    let per_tick = u128::from(NOM).checked_mul(NANOS_PER_SEC)?;
+    let ticks = duration
+        .as_nanos()
+        .checked_mul(u128::from(DENOM))?
+        .checked_div(per_tick)?;
+    u64::try_from(ticks)
+        .ok()
+        .map(fugit::Duration::<u64, NOM, DENOM>::from_ticks)
+}
+
+/// Converts `ticks` to a [`Duration`], saturating to [`Duration::MAX`].
+fn from_ticks<const NOM: u32, const DENOM: u32>(
+    ticks: fugit::Duration<u64, NOM, DENOM>,
+) -> Duration {
+    let nanos = u128::from(ticks.ticks())
+        .checked_mul(u128::from(NOM) * NANOS_PER_SEC)
+        .and_then(|nanos| nanos.checked_div(u128::from(DENOM)));
+    nanos
+        .and_then(|nanos| {
+            let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+            #[allow(clippy::cast_possible_truncation)]
+            Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+        })
+        .unwrap_or(Duration::MAX)
+}
@@ -0,0 +1,63 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+/// An instant read cheaply from the CPU's timestamp counter.
+///
+/// This is backed by the `minstant` crate's [`Instant`](minstant::Instant),
+/// which reads the TSC where it's reliable and falls back to
+/// [`std::time::Instant`] everywhere else. It's useful for timing hot loops,
+/// where the cost of reading the system clock shows up in profiles.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MinstantInstant {
+    inner: minstant::Instant,
+}
+
+impl MinstantInstant {
+    /// Returns the inner [`minstant::Instant`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> minstant::Instant {
+        self.inner
+    }
+}
+
+impl From<minstant::Instant> for MinstantInstant {
+    fn from(inner: minstant::Instant) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<MinstantInstant> for minstant::Instant {
+    fn from(instant: MinstantInstant) -> Self {
+        instant.inner
+    }
+}
+
+impl Instant for MinstantInstant {
+    fn now() -> Self {
+        Self {
+            inner: minstant::Instant::now(),
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_add(duration)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_sub(duration)?;
+        Some(Self { inner })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner.saturating_duration_since(earlier.inner)
+    }
+}
+
+impl Resolution for MinstantInstant {}
@@ -0,0 +1,94 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+use std::time::SystemTime;
+
+use crate::{Instant, Resolution};
+
+/// A wall clock instant whose durations are measured by a monotonic clock.
+///
+/// [`SystemTime`] is useful for timestamping, but it can jump forwards or
+/// backwards, such as when the system clock is adjusted by NTP, which corrupts
+/// the elapsed time of a stopwatch measuring across the jump. This pairs a
+/// `SystemTime` reading with a [`std::time::Instant`] reading taken at the
+/// same time. Durations between instants are measured with the monotonic
+/// readings, while the [wall clock reading](Self::system_time) remains
+/// available for logging.
+///
+/// # Notes
+///
+/// Durations never fall back to the wall clock readings, since every instant
+/// has both readings. As a result, the difference between the wall clock
+/// readings of two instants may disagree with the duration between them.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{Instant, MonotonicSystemInstant, MonotonicSystemSw};
+/// # use core::time::Duration;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = MonotonicSystemSw::new();
+/// let start = MonotonicSystemInstant::now();
+/// sw.start_at(start)?;
+/// sw.stop_at(start.checked_add(Duration::from_secs(1)).unwrap())?;
+/// assert_eq!(sw.elapsed(), Duration::from_secs(1));
+/// println!("started at {:?}", start.system_time());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct MonotonicSystemInstant {
+    system: SystemTime,
+    monotonic: std::time::Instant,
+}
+
+impl MonotonicSystemInstant {
+    /// Returns the wall clock reading of the instant.
+    #[inline]
+    #[must_use]
+    pub const fn system_time(&self) -> SystemTime {
+        self.system
+    }
+
+    /// Returns the monotonic reading of the instant.
+    #[inline]
+    #[must_use]
+    pub const fn monotonic(&self) -> std::time::Instant {
+        self.monotonic
+    }
+}
+
+impl Instant for MonotonicSystemInstant {
+    fn now() -> Self {
+        Self {
+            system: SystemTime::now(),
+            monotonic: std::time::Instant::now(),
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Some(Self {
+            system: self.system.checked_add(duration)?,
+            monotonic: self.monotonic.checked_add(duration)?,
+        })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        Some(Self {
+            system: self.system.checked_sub(duration)?,
+            monotonic: self.monotonic.checked_sub(duration)?,
+        })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.monotonic.saturating_duration_since(earlier.monotonic)
+    }
+}
+
+impl Resolution for MonotonicSystemInstant {
+    fn resolution() -> Duration {
+        crate::resolution::OS_RESOLUTION
+    }
+}
@@ -0,0 +1,98 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::cell::RefCell;
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+std::thread_local! {
+    static CLOCK: RefCell<Option<quanta::Clock>> = const { RefCell::new(None) };
+}
+
+/// An instant read from a [`quanta::Clock`] set for the current thread.
+///
+/// [`quanta::Instant::now`] reads a global clock, so stopwatches using it
+/// can't be driven by a mock clock. This reads the clock set with
+/// [`set_clock`](Self::set_clock) instead, such as one created by
+/// [`quanta::Clock::mock`], which makes tests using `quanta` deterministic. If
+/// no clock is set for the current thread, the global clock is read.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{QuantaClockInstant, StopwatchImpl};
+/// # use core::time::Duration;
+/// # fn main() -> libsw::Result<()> {
+/// let (clock, mock) = quanta::Clock::mock();
+/// QuantaClockInstant::set_clock(Some(clock));
+///
+/// let mut sw = StopwatchImpl::<QuantaClockInstant>::new_started();
+/// mock.increment(Duration::from_secs(1));
+/// sw.stop()?;
+/// assert_eq!(sw.elapsed(), Duration::from_secs(1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QuantaClockInstant {
+    inner: quanta::Instant,
+}
+
+impl QuantaClockInstant {
+    /// Sets the clock read by [`now`](Instant::now) on the current thread, or
+    /// the global clock if `clock` is [`None`]. Returns the previous clock.
+    #[allow(clippy::must_use_candidate)]
+    pub fn set_clock(clock: Option<quanta::Clock>) -> Option<quanta::Clock> {
+        CLOCK.with(|cell| cell.replace(clock))
+    }
+
+    /// Returns the inner [`quanta::Instant`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> quanta::Instant {
+        self.inner
+    }
+}
+
+impl From<quanta::Instant> for QuantaClockInstant {
+    fn from(inner: quanta::Instant) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<QuantaClockInstant> for quanta::Instant {
+    fn from(instant: QuantaClockInstant) -> Self {
+        instant.inner
+    }
+}
+
+impl Instant for QuantaClockInstant {
+    /// Returns the current instant according to the clock set for the current
+    /// thread, or the global clock if none is set.
+    fn now() -> Self {
+        let inner = CLOCK.with(|cell| {
+            cell.borrow()
+                .as_ref()
+                .map_or_else(quanta::Instant::now, quanta::Clock::now)
+        });
+        Self { inner }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_add(duration)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_sub(duration)?;
+        Some(Self { inner })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner.saturating_duration_since(earlier.inner)
+    }
+}
+
+impl Resolution for QuantaClockInstant {}
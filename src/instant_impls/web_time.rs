@@ -0,0 +1,74 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+/// An instant which works in the browser.
+///
+/// [`std::time::Instant::now`] panics on `wasm32-unknown-unknown`. This is
+/// backed by the `web-time` crate's [`Instant`](web_time::Instant), which
+/// reads `performance.now()` on the web and falls back to
+/// [`std::time::Instant`] everywhere else.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WebInstant {
+    inner: web_time::Instant,
+}
+
+impl WebInstant {
+    /// Returns the inner [`web_time::Instant`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> web_time::Instant {
+        self.inner
+    }
+}
+
+impl From<web_time::Instant> for WebInstant {
+    fn from(inner: web_time::Instant) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<WebInstant> for web_time::Instant {
+    fn from(instant: WebInstant) -> Self {
+        instant.inner
+    }
+}
+
+impl Instant for WebInstant {
+    fn now() -> Self {
+        Self {
+            inner: web_time::Instant::now(),
+        }
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_add(duration)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let inner = self.inner.checked_sub(duration)?;
+        Some(Self { inner })
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.inner.saturating_duration_since(earlier.inner)
+    }
+}
+
+impl Resolution for WebInstant {
+    /// Returns the resolution of `performance.now()` in the browser, which is
+    /// coarsened to as much as 100 microseconds, or of [`std::time::Instant`]
+    /// elsewhere.
+    fn resolution() -> Duration {
+        if cfg!(all(target_family = "wasm", target_os = "unknown")) {
+            Duration::from_micros(100)
+        } else {
+            crate::resolution::OS_RESOLUTION
+        }
+    }
+}
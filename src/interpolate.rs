@@ -0,0 +1,181 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::cell::Cell;
+use core::fmt;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) driven by a coarse clock `C`, which smooths its
+/// reported elapsed time using a finer clock `F`.
+///
+/// Coarse clocks are cheap to read, but consecutive reads may return the same
+/// instant, which makes the elapsed time of a running stopwatch appear to
+/// stutter. While running, `InterpolatedSw` reads `F` only when `C` hasn't
+/// advanced since the previous read of [`elapsed`](Self::elapsed), and adds the
+/// time elapsed on `F` since then.
+///
+/// # Notes
+///
+/// - The interpolated elapsed time is **approximate**. It may briefly run
+///   ahead of the coarse clock, but never goes backwards while the stopwatch
+///   keeps running.
+///
+/// - Interpolation only affects what is reported by
+///   [`elapsed`](Self::elapsed). The inner stopwatch accumulates time according
+///   to `C` alone.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::InterpolatedSw;
+/// # use std::time::{Instant, SystemTime};
+/// let sw = InterpolatedSw::<SystemTime, Instant>::new_started();
+/// let first = sw.elapsed();
+/// let second = sw.elapsed();
+/// assert!(second >= first);
+/// ```
+pub struct InterpolatedSw<C: Instant, F: Instant> {
+    inner: StopwatchImpl<C>,
+    // (last coarse elapsed, fine instant when it was first repeated, last reported)
+    last: Cell<Option<(Duration, Option<F>, Duration)>>,
+}
+
+impl<C: Instant, F: Instant> InterpolatedSw<C, F> {
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self::from_inner(StopwatchImpl::new_started())
+    }
+
+    /// Returns an interpolating stopwatch wrapping `inner`.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<C>) -> Self {
+        Self {
+            inner,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<C> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<C> {
+        self.inner
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed, interpolated using the fine clock `F`
+    /// while running.
+    ///
+    /// See the [struct documentation](Self) for details.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        let coarse = self.inner.elapsed();
+        if self.inner.is_stopped() {
+            return coarse;
+        }
+
+        let (fine_start, reported) = match self.last.get() {
+            Some((last_coarse, fine_start, reported)) if last_coarse == coarse => {
+                // coarse clock hasn't advanced, so read the fine clock
+                let fine_now = F::now();
+                let fine_start = fine_start.unwrap_or(fine_now);
+                let interpolated =
+                    coarse.saturating_add(fine_now.saturating_duration_since(fine_start));
+                (Some(fine_start), interpolated.max(reported))
+            }
+            Some((_, _, reported)) => (None, coarse.max(reported)),
+            None => (None, coarse),
+        };
+
+        self.last.set(Some((coarse, fine_start, reported)));
+        reported
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.last.set(None);
+        self.inner.start()
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.last.set(None);
+        self.inner.stop()
+    }
+
+    /// Stops and resets the elapsed time to zero.
+    pub fn reset(&mut self) {
+        self.last.set(None);
+        self.inner.reset();
+    }
+}
+
+impl<C: Instant, F: Instant> Clone for InterpolatedSw<C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner,
+            last: Cell::new(self.last.get()),
+        }
+    }
+}
+
+impl<C: Instant, F: Instant> fmt::Debug for InterpolatedSw<C, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InterpolatedSw")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Instant, F: Instant> Default for InterpolatedSw<C, F> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`InterpolatedSw::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Instant, F: Instant> From<StopwatchImpl<C>> for InterpolatedSw<C, F> {
+    fn from(inner: StopwatchImpl<C>) -> Self {
+        Self::from_inner(inner)
+    }
+}
@@ -0,0 +1,66 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::iter::FusedIterator;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// Extension methods for timing [iterators](Iterator) with a
+/// [stopwatch](StopwatchImpl).
+///
+/// This trait is implemented for all iterators.
+pub trait TimedIteratorExt: Iterator + Sized {
+    /// Returns an iterator which pairs each item with the total time elapsed
+    /// on `sw` at the moment the item was produced.
+    ///
+    /// If `sw` is running, the elapsed times are non-decreasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Sw, TimedIteratorExt};
+    /// let sw = Sw::new_started();
+    /// let timed: Vec<_> = (0..3).with_cumulative_elapsed(&sw).collect();
+    /// assert_eq!(timed.len(), 3);
+    /// assert!(timed.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    /// ```
+    fn with_cumulative_elapsed<I: Instant>(
+        self,
+        sw: &StopwatchImpl<I>,
+    ) -> CumulativeElapsed<'_, Self, I> {
+        CumulativeElapsed { iter: self, sw }
+    }
+}
+
+impl<T: Iterator> TimedIteratorExt for T {}
+
+/// An iterator which pairs each item with the elapsed time of a
+/// [stopwatch](StopwatchImpl).
+///
+/// `CumulativeElapsed` is returned by
+/// [`TimedIteratorExt::with_cumulative_elapsed`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct CumulativeElapsed<'sw, T, I: Instant> {
+    iter: T,
+    sw: &'sw StopwatchImpl<I>,
+}
+
+impl<T: Iterator, I: Instant> Iterator for CumulativeElapsed<'_, T, I> {
+    type Item = (T::Item, Duration);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some((item, self.sw.elapsed()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: ExactSizeIterator, I: Instant> ExactSizeIterator for CumulativeElapsed<'_, T, I> {}
+
+impl<T: FusedIterator, I: Instant> FusedIterator for CumulativeElapsed<'_, T, I> {}
@@ -0,0 +1,354 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) which records laps, or splits, without
+/// stopping.
+///
+/// Each [lap](Self::lap) returns the time elapsed since the previous lap,
+/// while the total elapsed time keeps accumulating. The first lap returns the
+/// time elapsed since the stopwatch was last started.
+///
+/// # Notes
+///
+/// Laps are measured in elapsed time, so time spent stopped isn't counted
+/// towards the current lap. Stopping and starting the stopwatch pauses and
+/// resumes the current lap.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::LapStopwatch;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut sw = LapStopwatch::<Instant>::new();
+/// sw.start_at(start)?;
+/// assert_eq!(sw.lap_at(start + Duration::from_secs(3))?, Duration::from_secs(3));
+/// assert_eq!(sw.lap_at(start + Duration::from_secs(5))?, Duration::from_secs(2));
+/// assert_eq!(sw.elapsed_at(start + Duration::from_secs(5)), Duration::from_secs(5));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct LapStopwatch<I: Instant> {
+    inner: StopwatchImpl<I>,
+    // total elapsed time at the previous lap
+    last_lap: Duration,
+}
+
+impl<I: Instant> LapStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self::from_inner(StopwatchImpl::new_started())
+    }
+
+    /// Returns a lap stopwatch wrapping `inner`.
+    ///
+    /// The first lap begins when `inner` was last started, or when it's next
+    /// started if it's stopped.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self {
+            last_lap: inner.inner.elapsed,
+            inner,
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        self.inner.elapsed_at(anchor)
+    }
+
+    /// Records a lap, returning the time elapsed since the previous lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwLap`](Error::SwLap) if the stopwatch is stopped.
+    pub fn lap(&mut self) -> crate::Result<Duration> {
+        self.lap_at(I::now())
+    }
+
+    /// Records a lap as if the current time were `anchor`, returning the time
+    /// elapsed since the previous lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwLap`](Error::SwLap) if the stopwatch is stopped.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started. If
+    /// `anchor` is earlier than the previous lap, the lap is zero.
+    pub fn lap_at(&mut self, anchor: I) -> crate::Result<Duration> {
+        if self.is_stopped() {
+            return Err(Error::SwLap);
+        }
+        let elapsed = self.inner.elapsed_at(anchor);
+        let lap = elapsed.saturating_sub(self.last_lap);
+        self.last_lap = self.last_lap.max(elapsed);
+        Ok(lap)
+    }
+
+    /// Starts measuring the time elapsed, resuming the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.inner.start()
+    }
+
+    /// Starts measuring the time elapsed as if the current time were
+    /// `anchor`, resuming the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed, pausing the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.inner.stop()
+    }
+
+    /// Stops measuring the time elapsed as if the current time were `anchor`,
+    /// pausing the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Stops and resets the elapsed time and the current lap to zero.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<I: Instant> Default for LapStopwatch<I> {
+    /// Returns the default stopwatch. Same as calling [`LapStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for LapStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
+
+/// Summary statistics of a stream of durations, such as
+/// [laps](LapStopwatch::lap), computed without storing the durations.
+///
+/// `RunningStats` tracks the count, minimum, maximum, most recent, and mean
+/// duration recorded, in constant memory. The mean is updated incrementally
+/// with Welford's method, which avoids the overflow and precision loss of
+/// summing every duration.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{LapStopwatch, RunningStats};
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut sw = LapStopwatch::<Instant>::new();
+/// let mut stats = RunningStats::new();
+/// sw.start_at(start)?;
+/// for secs in [1, 3, 6] {
+///     stats.record(sw.lap_at(start + Duration::from_secs(secs))?);
+/// }
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!(stats.min(), Some(Duration::from_secs(1)));
+/// assert_eq!(stats.max(), Some(Duration::from_secs(3)));
+/// assert_eq!(stats.mean(), Some(Duration::from_secs(2)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    min: Duration,
+    max: Duration,
+    last: Duration,
+    // mean in seconds
+    mean: f64,
+}
+
+impl RunningStats {
+    /// Returns statistics with no durations recorded.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            last: Duration::ZERO,
+            mean: 0.0,
+        }
+    }
+
+    /// Records `dur`, updating the statistics.
+    ///
+    /// # Notes
+    ///
+    /// The count saturates at [`u64::MAX`].
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record(&mut self, dur: Duration) {
+        self.count = self.count.saturating_add(1);
+        self.min = self.min.min(dur);
+        self.max = self.max.max(dur);
+        self.last = dur;
+        self.mean += (dur.as_secs_f64() - self.mean) / self.count as f64;
+    }
+
+    /// Returns the number of durations recorded.
+    #[inline]
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the shortest duration recorded, or [`None`] if none have been
+    /// recorded.
+    #[must_use]
+    pub const fn min(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// Returns the longest duration recorded, or [`None`] if none have been
+    /// recorded.
+    #[must_use]
+    pub const fn max(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// Returns the most recent duration recorded, or [`None`] if none have
+    /// been recorded.
+    #[must_use]
+    pub const fn last(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.last)
+        }
+    }
+
+    /// Returns the mean of the durations recorded, in seconds, or [`None`] if
+    /// none have been recorded.
+    #[must_use]
+    pub fn mean_secs_f64(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Returns the mean of the durations recorded, or [`None`] if none have
+    /// been recorded.
+    ///
+    /// # Notes
+    ///
+    /// The mean is tracked as an [`f64`], so it's approximate. It's clamped to
+    /// the range of the durations recorded.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        let mean = self.mean_secs_f64()?;
+        if mean <= self.min.as_secs_f64() {
+            Some(self.min)
+        } else if mean >= self.max.as_secs_f64() {
+            Some(self.max)
+        } else {
+            Some(Duration::from_secs_f64(mean))
+        }
+    }
+
+    /// Clears all recorded durations.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for RunningStats {
+    /// Returns statistics with no durations recorded. Same as calling
+    /// [`RunningStats::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<Duration> for RunningStats {
+    fn extend<T: IntoIterator<Item = Duration>>(&mut self, iter: T) {
+        for dur in iter {
+            self.record(dur);
+        }
+    }
+}
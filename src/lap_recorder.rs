@@ -0,0 +1,209 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::{Instant, LapStopwatch};
+
+/// A [lap stopwatch](LapStopwatch) which records the history of its laps.
+///
+/// Laps are appended to a buffer, which can be
+/// [preallocated](Self::with_capacity) for a known number of laps, such as
+/// per-frame timings, and [cleared](Self::clear_laps) to be reused without
+/// reallocating.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::LapRecorder;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut sw = LapRecorder::<Instant>::with_capacity(3);
+/// sw.start_at(start)?;
+/// for secs in [1, 3, 6] {
+///     sw.lap_at(start + Duration::from_secs(secs))?;
+/// }
+/// assert_eq!(sw.laps(), [1, 2, 3].map(Duration::from_secs));
+///
+/// sw.clear_laps();
+/// assert!(sw.laps().is_empty());
+/// assert!(sw.is_running());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LapRecorder<I: Instant> {
+    inner: LapStopwatch<I>,
+    laps: Vec<Duration>,
+}
+
+impl<I: Instant> LapRecorder<I> {
+    /// Returns a stopped stopwatch with zero elapsed time and no laps.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(LapStopwatch::new())
+    }
+
+    /// Returns a stopped stopwatch with zero elapsed time and room for
+    /// `capacity` laps before reallocating.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: LapStopwatch::new(),
+            laps: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns a lap recorder wrapping `inner`, with no laps recorded.
+    #[must_use]
+    pub const fn from_inner(inner: LapStopwatch<I>) -> Self {
+        Self {
+            inner,
+            laps: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the inner [`LapStopwatch`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &LapStopwatch<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`LapStopwatch`], discarding the recorded laps.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> LapStopwatch<I> {
+        self.inner
+    }
+
+    /// Returns the laps recorded, in order.
+    #[inline]
+    #[must_use]
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// Removes the laps recorded, keeping the buffer's capacity. The
+    /// stopwatch and the current lap are unaffected.
+    pub fn clear_laps(&mut self) {
+        self.laps.clear();
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        self.inner.elapsed_at(anchor)
+    }
+
+    /// Records a lap, returning the time elapsed since the previous lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwLap`](crate::Error::SwLap) if the stopwatch is stopped.
+    pub fn lap(&mut self) -> crate::Result<Duration> {
+        self.lap_at(I::now())
+    }
+
+    /// Records a lap as if the current time were `anchor`, returning the time
+    /// elapsed since the previous lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwLap`](crate::Error::SwLap) if the stopwatch is stopped.
+    ///
+    /// # Notes
+    ///
+    /// See [`LapStopwatch::lap_at`] for notes about the chronology of
+    /// `anchor`.
+    pub fn lap_at(&mut self, anchor: I) -> crate::Result<Duration> {
+        let lap = self.inner.lap_at(anchor)?;
+        self.laps.push(lap);
+        Ok(lap)
+    }
+
+    /// Starts measuring the time elapsed, resuming the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.inner.start()
+    }
+
+    /// Starts measuring the time elapsed as if the current time were
+    /// `anchor`, resuming the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed, pausing the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.inner.stop()
+    }
+
+    /// Stops measuring the time elapsed as if the current time were `anchor`,
+    /// pausing the current lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Stops and resets the elapsed time and the current lap to zero, and
+    /// [clears the laps](Self::clear_laps) recorded.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+        self.clear_laps();
+    }
+}
+
+impl<I: Instant> Default for LapRecorder<I> {
+    /// Returns the default stopwatch. Same as calling [`LapRecorder::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<LapStopwatch<I>> for LapRecorder<I> {
+    fn from(inner: LapStopwatch<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
@@ -26,17 +26,38 @@
 //!
 //! # Features
 //!
-//! | Name             | Features enabled                | Description                                                                                             |
-//! |------------------|---------------------------------|---------------------------------------------------------------------------------------------------------|
-//! | `default`        | `std_instant`, `std_systemtime` | Enabled by default.                                                                                     |
-//! | `std`            |                                 | Depends on the standard library. Implements `std::error::Error` for [`Error`].                          |
-//! | `nightly`        |                                 | Implements `core::error::Error` for [`Error`] **if** `std` is not enabled. Requires a nightly compiler. |
-//! | `std_instant`    | `std`                           | Implements [`Instant`] for `std::time::Instant`. Exposes `Sw` type alias.                               |
-//! | `std_systemtime` | `std`                           | Implements [`Instant`] for `std::time::SystemTime`. Exposes `SystemSw` type alias.                      |
-//! | `tokio`          | `std`                           | Implements [`Instant`] for `tokio::time::Instant`. Exposes `TokioSw` type alias.                        |
-//! | `coarsetime`     | `std`                           | Implements [`Instant`] for `coarsetime::Instant`. Exposes `CoarseSw` type alias.                        |
-//! | `quanta`         | `std`                           | Implements [`Instant`] for `quanta::Instant`. Exposes `QuantaSw` type alias.                            |
-//! | `time`           | `std`                           | Deprecated. Implements [`Instant`] for `time::Instant`. Exposes `TimeSw` type alias.                    |
+//! | Name                | Features enabled                | Description                                                                                             |
+//! |---------------------|---------------------------------|---------------------------------------------------------------------------------------------------------|
+//! | `default`           | `std_instant`, `std_systemtime` | Enabled by default.                                                                                     |
+//! | `std`               | `alloc`                         | Depends on the standard library. Implements `std::error::Error` for [`Error`].                          |
+//! | `alloc`             |                                 | Depends on `alloc`. Exposes `LapRecorder`, `start_all`, `stop_all`, and methods returning a `String`.   |
+//! | `nightly`           |                                 | Implements `core::error::Error` for [`Error`] **if** `std` is not enabled. Requires a nightly compiler. |
+//! | `std_instant`       | `std`                           | Implements [`Instant`] for `std::time::Instant`. Exposes `Sw` and `thread_local`.                       |
+//! | `std_systemtime`    | `std`                           | Implements [`Instant`] for `std::time::SystemTime`. Exposes `SystemSw` type alias.                      |
+//! | `tokio`             | `std`                           | Implements [`Instant`] for `tokio::time::Instant`. Exposes `TokioSw` type alias.                        |
+//! | `coarsetime`        | `std`                           | Implements [`Instant`] for `coarsetime::Instant`. Exposes `CoarseSw` type alias.                        |
+//! | `quanta`            | `std`                           | Implements [`Instant`] for `quanta::Instant`. Exposes `QuantaSw` type alias and `QuantaClockInstant`.   |
+//! | `time`              | `std`                           | Deprecated. Implements [`Instant`] for `time::Instant`. Exposes `TimeSw` type alias.                    |
+//! | `web_time`          | `std`                           | Exposes `WebInstant`, which works in the browser, and `WebSw` type alias.                               |
+//! | `minstant`          | `std`                           | Exposes `MinstantInstant`, read cheaply from the CPU's timestamp counter, and `MinstantSw` type alias.  |
+//! | `chrono`            | `std`                           | Exposes `ChronoInstant`, a wall clock read from `chrono::Utc`, and `ChronoSw` type alias.               |
+//! | `embedded_time`     |                                 | Exposes `EmbeddedInstant`, read from an `embedded-time` clock, implementing [`TryInstant`].             |
+//! | `fugit`             |                                 | Exposes `FugitInstant`, read from a `fugit` timer paired with a `FugitClock`, like an RTIC monotonic.   |
+//! | `cpu_time`          | `std`                           | Exposes `ProcessCpuInstant`, measuring the process's CPU time, and `CpuSw` type alias.                  |
+//! | `boottime`          | `std`                           | Exposes `BoottimeInstant`, which counts time while suspended, and `BoottimeSw` type alias.              |
+//! | `global_clock`      |                                 | Exposes `GlobalInstant`, read from a runtime-registered `u64` nanosecond counter, and `GlobalSw` alias. |
+//! | `driven_clock`      |                                 | Exposes `DrivenInstant`, read from a global clock set by `DrivenClock`, and `DrivenSw` type alias.      |
+//! | `manual_clock`      | `std`                           | Exposes `ManualInstant`, read from a per-thread clock advanced by hand, and `ManualSw` type alias.      |
+//! | `test_util`         |                                 | Exposes `MockInstant`, read from a global clock advanced by hand for tests, and `MockSw` type alias.    |
+//! | `parse_duration`    |                                 | Exposes `parse_duration`, which parses compact human durations like `1h30m` without allocating.         |
+//! | `interpolate`       |                                 | Exposes `InterpolatedSw`, which smooths the elapsed time of coarse clocks.                              |
+//! | `heapless`          |                                 | Exposes `ArrayStopwatchGroup`, a fixed-capacity group of stopwatches which needs no allocator.          |
+//! | `opentelemetry`     | `std`                           | Exposes `OtelGuard`, which reports guarded time to an OpenTelemetry span.                               |
+//! | `serde`             |                                 | Implements `Serialize` and `Deserialize` for [`StopwatchImpl`] and [`StopwatchDto`].                    |
+//! | `serde_json`        | `std`                           | Exposes `StopwatchImpl::to_json_value`, returning a `serde_json::Value`.                                |
+//! | `ufmt`              |                                 | Implements `ufmt::uDisplay` and `ufmt::uDebug` for [`StopwatchImpl`] and [`Error`].                     |
+//! | `defmt`             |                                 | Implements `defmt::Format` for [`StopwatchImpl`] and [`Error`].                                         |
+//! | `no_inline_elapsed` |                                 | Prevents inlining of hot methods like `elapsed`, so they appear in profiles. May slow the hot path.     |
 //!
 //! ## Timekeeping support
 //!
@@ -50,6 +71,8 @@
 //!
 //! The `std` feature flag unsets `#[no_std]`. It is enabled by default, but you
 //! can disable it by disabling the default features.
+//! Types which need an allocator, but not the rest of the standard library,
+//! are available with the `alloc` feature flag.
 //!
 //! In `Cargo.toml`,
 //!
@@ -73,16 +96,157 @@
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, clippy::pedantic, clippy::cargo)]
+// `no_inline_elapsed` only removes inlining, so it doesn't remove functionality
+#![allow(clippy::negative_feature_names)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate core;
 
+#[cfg(feature = "heapless")]
+mod array_group;
+#[cfg(all(feature = "std_instant", target_has_atomic = "64"))]
+mod atomic;
+mod backoff;
+mod builder;
+mod config;
+mod deadline;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+#[cfg(all(feature = "driven_clock", target_has_atomic = "64"))]
+mod driven_clock;
+mod dto;
+mod elapsed;
 mod error;
+mod fixed;
+mod format;
+#[cfg(feature = "global_clock")]
+mod global_clock;
+#[cfg(feature = "std")]
+mod group;
 mod guard;
+mod instant_impls;
+#[cfg(feature = "interpolate")]
+mod interpolate;
+mod iter;
+mod lap;
+#[cfg(feature = "alloc")]
+mod lap_recorder;
+#[cfg(feature = "manual_clock")]
+mod manual_clock;
+mod metrics;
+#[cfg(all(feature = "test_util", target_has_atomic = "64"))]
+mod mock;
+mod monotonic;
+#[cfg(feature = "opentelemetry")]
+mod otel;
+mod parse;
+mod resolution;
+#[cfg(all(feature = "std_systemtime", feature = "chrono"))]
+mod rfc3339;
+mod scaled;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shared;
+mod signed;
 mod stopwatch;
+#[cfg(feature = "std_instant")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std_instant")))]
+pub mod thread_local;
+mod tracking;
+mod try_instant;
+#[cfg(feature = "ufmt")]
+mod ufmt_impls;
+mod windowed;
 
+#[cfg(feature = "heapless")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heapless")))]
+pub use crate::array_group::ArrayStopwatchGroup;
+#[cfg(all(feature = "std_instant", target_has_atomic = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std_instant")))]
+pub use crate::atomic::AtomicStopwatch;
+pub use crate::backoff::BackoffTimer;
+pub use crate::builder::StopwatchBuilder;
+pub use crate::config::{ConfiguredStopwatch, OverflowPolicy, StopwatchConfig};
+pub use crate::deadline::Deadline;
+#[cfg(all(feature = "driven_clock", target_has_atomic = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "driven_clock")))]
+pub use crate::driven_clock::{DrivenClock, DrivenInstant};
+pub use crate::dto::StopwatchDto;
+pub use crate::elapsed::Elapsed;
 pub use crate::error::{Error, Result};
-pub use crate::guard::Guard;
+pub use crate::fixed::FixedStopwatch;
+pub use crate::format::{FormattedElapsed, TimeUnit};
+#[cfg(feature = "global_clock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "global_clock")))]
+pub use crate::global_clock::{has_global_clock, set_global_clock, GlobalInstant};
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub use crate::group::StopwatchGroup;
+pub use crate::guard::{Guard, GuardFn, GuardInto, SlaGuard};
+#[cfg(feature = "boottime")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "boottime")))]
+pub use crate::instant_impls::BoottimeInstant;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub use crate::instant_impls::ChronoInstant;
+#[cfg(feature = "embedded_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "embedded_time")))]
+pub use crate::instant_impls::EmbeddedInstant;
+#[cfg(feature = "minstant")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "minstant")))]
+pub use crate::instant_impls::MinstantInstant;
+#[cfg(all(feature = "std_instant", feature = "std_systemtime"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(feature = "std_instant", feature = "std_systemtime")))
+)]
+pub use crate::instant_impls::MonotonicSystemInstant;
+#[cfg(feature = "cpu_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "cpu_time")))]
+pub use crate::instant_impls::ProcessCpuInstant;
+#[cfg(feature = "quanta")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "quanta")))]
+pub use crate::instant_impls::QuantaClockInstant;
+#[cfg(feature = "web_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "web_time")))]
+pub use crate::instant_impls::WebInstant;
+#[cfg(feature = "fugit")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fugit")))]
+pub use crate::instant_impls::{FugitClock, FugitInstant};
+#[cfg(feature = "interpolate")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "interpolate")))]
+pub use crate::interpolate::InterpolatedSw;
+pub use crate::iter::{CumulativeElapsed, TimedIteratorExt};
+pub use crate::lap::{LapStopwatch, RunningStats};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub use crate::lap_recorder::LapRecorder;
+#[cfg(feature = "manual_clock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "manual_clock")))]
+pub use crate::manual_clock::ManualInstant;
+pub use crate::metrics::{
+    relative_progress, speed_factor, utilization, weighted_mean, RateEstimator,
+};
+#[cfg(all(feature = "test_util", target_has_atomic = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test_util")))]
+pub use crate::mock::MockInstant;
+pub use crate::monotonic::MonotonicStopwatch;
+#[cfg(feature = "opentelemetry")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "opentelemetry")))]
+pub use crate::otel::OtelGuard;
+#[cfg(feature = "parse_duration")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parse_duration")))]
+pub use crate::parse::parse_duration;
+pub use crate::parse::ParseError;
+pub use crate::resolution::Resolution;
+pub use crate::scaled::ScaledStopwatch;
+pub use crate::shared::{SharedGuard, SharedStopwatch};
+pub use crate::signed::SignedStopwatch;
 pub use crate::stopwatch::StopwatchImpl;
+pub use crate::tracking::TrackingStopwatch;
+pub use crate::try_instant::{ClockError, TryInstant};
+pub use crate::windowed::WindowedStopwatch;
 pub use libsw_core::Instant;
 
 /// Alias to [`StopwatchImpl`] using the standard library's
@@ -136,5 +300,78 @@ pub type QuantaSw = StopwatchImpl<::quanta::Instant>;
 )]
 pub type TimeSw = StopwatchImpl<::time::Instant>;
 
+/// Alias to [`StopwatchImpl`] using the process CPU time
+/// [`ProcessCpuInstant`] type.
+#[cfg(feature = "cpu_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "cpu_time")))]
+pub type CpuSw = StopwatchImpl<ProcessCpuInstant>;
+
+/// Alias to [`StopwatchImpl`] using the [`WebInstant`] type, which works in
+/// the browser.
+#[cfg(feature = "web_time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "web_time")))]
+pub type WebSw = StopwatchImpl<WebInstant>;
+
+/// Alias to [`StopwatchImpl`] using the [`MinstantInstant`] type, which reads
+/// the CPU's timestamp counter.
+#[cfg(feature = "minstant")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "minstant")))]
+pub type MinstantSw = StopwatchImpl<MinstantInstant>;
+
+/// Alias to [`StopwatchImpl`] using the [`ChronoInstant`] type, which reads
+/// the `chrono` crate's UTC wall clock.
+///
+/// This clock isn't monotonic, and can jump forwards or backwards.
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub type ChronoSw = StopwatchImpl<ChronoInstant>;
+
+/// Alias to [`StopwatchImpl`] using the [`BoottimeInstant`] type, which counts
+/// time while the system is suspended.
+#[cfg(feature = "boottime")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "boottime")))]
+pub type BoottimeSw = StopwatchImpl<BoottimeInstant>;
+
+/// Alias to [`StopwatchImpl`] using the externally driven [`DrivenInstant`]
+/// type.
+#[cfg(all(feature = "driven_clock", target_has_atomic = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "driven_clock")))]
+pub type DrivenSw = StopwatchImpl<DrivenInstant>;
+
+/// Alias to [`StopwatchImpl`] using the wall clock [`MonotonicSystemInstant`]
+/// type, measured by a monotonic clock.
+#[cfg(all(feature = "std_instant", feature = "std_systemtime"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(feature = "std_instant", feature = "std_systemtime")))
+)]
+pub type MonotonicSystemSw = StopwatchImpl<MonotonicSystemInstant>;
+
+/// Alias to [`StopwatchImpl`] using the manually advanced [`ManualInstant`]
+/// type.
+#[cfg(feature = "manual_clock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "manual_clock")))]
+pub type ManualSw = StopwatchImpl<ManualInstant>;
+
+/// Alias to [`StopwatchImpl`] using the global mock [`MockInstant`] type.
+#[cfg(all(feature = "test_util", target_has_atomic = "64"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test_util")))]
+pub type MockSw = StopwatchImpl<MockInstant>;
+
+/// Alias to [`StopwatchImpl`] using the runtime-registered [`GlobalInstant`]
+/// type.
+#[cfg(feature = "global_clock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "global_clock")))]
+pub type GlobalSw = StopwatchImpl<GlobalInstant>;
+
 #[cfg(test)]
+// stopwatches implement `Ord`, so clippy suggests simplifying tests which
+// check `!=` against `==` on purpose
+#[allow(clippy::nonminimal_bool)]
+// lints added by newer versions of clippy, which older tests predate
+#[allow(
+    clippy::should_panic_without_expect,
+    clippy::unchecked_time_subtraction,
+    clippy::unnecessary_wraps
+)]
 mod tests;
@@ -0,0 +1,102 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::cell::Cell;
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+std::thread_local! {
+    static NOW: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// An instant read from a manually advanced clock.
+///
+/// Each thread has its own manual clock, which starts at zero and only moves
+/// when told to with [`advance`](Self::advance) or [`set`](Self::set). This
+/// suits frame-based clocks in games, where time advances by a fixed step per
+/// frame, and deterministic tests and simulations, which shouldn't sleep.
+///
+/// A `ManualInstant` is the time since the clock's epoch.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{ManualInstant, ManualSw};
+/// # use core::time::Duration;
+/// # fn main() -> libsw::Result<()> {
+/// const FRAME: Duration = Duration::from_millis(16);
+///
+/// let mut sw = ManualSw::new_started();
+/// for _ in 0..60 {
+///     ManualInstant::advance(FRAME);
+/// }
+/// sw.stop()?;
+/// assert_eq!(sw.elapsed(), FRAME * 60);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ManualInstant {
+    since_epoch: Duration,
+}
+
+impl ManualInstant {
+    /// Returns an instant `since_epoch` after the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn from_duration(since_epoch: Duration) -> Self {
+        Self { since_epoch }
+    }
+
+    /// Returns the time since the clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn as_duration(&self) -> Duration {
+        self.since_epoch
+    }
+
+    /// Advances the manual clock of the current thread by `dur`.
+    ///
+    /// # Notes
+    ///
+    /// The clock saturates to [`Duration::MAX`] on overflow.
+    pub fn advance(dur: Duration) {
+        NOW.with(|now| now.set(now.get().saturating_add(dur)));
+    }
+
+    /// Sets the manual clock of the current thread to `now`.
+    ///
+    /// The clock may be set backwards, which stopwatches handle the same way
+    /// as any clock which isn't monotonic.
+    pub fn set(now: Self) {
+        NOW.with(|cell| cell.set(now.since_epoch));
+    }
+}
+
+impl Instant for ManualInstant {
+    /// Returns the current instant according to the manual clock of the
+    /// current thread.
+    fn now() -> Self {
+        Self::from_duration(NOW.with(Cell::get))
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.since_epoch
+            .checked_add(duration)
+            .map(Self::from_duration)
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.since_epoch
+            .checked_sub(duration)
+            .map(Self::from_duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.since_epoch.saturating_sub(earlier.since_epoch)
+    }
+}
+
+impl Resolution for ManualInstant {}
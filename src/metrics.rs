@@ -0,0 +1,268 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// Returns the ratio of the elapsed time of `cpu` to the elapsed time of
+/// `wall`.
+///
+/// This is intended for comparing a stopwatch driven by a CPU time clock to
+/// one driven by a wall clock, to compute CPU utilization. The stopwatches may
+/// use different [`Instant`] types.
+///
+/// # Notes
+///
+/// - If the elapsed time of `wall` is zero, returns `0.0`.
+///
+/// - Running stopwatches are measured at the time of the call, each with its
+///   own clock.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{Sw, SystemSw};
+/// # use core::time::Duration;
+/// let cpu = Sw::with_elapsed(Duration::from_millis(250));
+/// let wall = SystemSw::with_elapsed(Duration::from_secs(1));
+/// assert_eq!(libsw::utilization(&cpu, &wall), 0.25);
+/// ```
+#[must_use]
+pub fn utilization<A: Instant, B: Instant>(cpu: &StopwatchImpl<A>, wall: &StopwatchImpl<B>) -> f64 {
+    let wall = wall.elapsed().as_secs_f64();
+    if wall == 0.0 {
+        0.0
+    } else {
+        cpu.elapsed().as_secs_f64() / wall
+    }
+}
+
+/// Returns the ratio of the elapsed time of `sw` to the real time elapsed from
+/// `real_start` to `real_now`.
+///
+/// This exposes the scaling of a simulated or mock clock driving `sw`. For
+/// example, a simulation running at 10x real time has a speed factor of
+/// `10.0`. The reference clock `R` may be a different [`Instant`] type.
+///
+/// # Notes
+///
+/// - If no real time has elapsed, including if `real_now` is earlier than
+///   `real_start`, returns `0.0`.
+///
+/// - If `sw` is running, it's measured at the time of the call.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::time::SystemTime;
+/// let sw = Sw::with_elapsed(Duration::from_secs(20));
+/// let real_start = SystemTime::UNIX_EPOCH;
+/// let real_now = real_start + Duration::from_secs(2);
+/// assert_eq!(libsw::speed_factor(&sw, real_start, real_now), 10.0);
+/// ```
+#[must_use]
+pub fn speed_factor<I: Instant, R: Instant>(
+    sw: &StopwatchImpl<I>,
+    real_start: R,
+    real_now: R,
+) -> f64 {
+    let real = real_now.saturating_duration_since(real_start).as_secs_f64();
+    if real == 0.0 {
+        0.0
+    } else {
+        sw.elapsed().as_secs_f64() / real
+    }
+}
+
+/// Returns the ratio of how far `a` advanced to how far `b` advanced, between
+/// the instants `t0` and `t1`.
+///
+/// This is useful for diagnosing a stalled stopwatch, by comparing it against
+/// a known-good one over the same interval. Stopwatches advancing at the same
+/// rate have a relative progress of `1.0`, and a stopped `a` has a relative
+/// progress of `0.0`.
+///
+/// # Notes
+///
+/// - If `b` didn't advance, including if `t1` is earlier than `t0`, returns
+///   `0.0`.
+///
+/// - Instants saturate to the last instant each stopwatch was started, as with
+///   [`elapsed_at`](StopwatchImpl::elapsed_at).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let t0 = Instant::now();
+/// let t1 = t0 + Duration::from_secs(1);
+/// let healthy = Sw::new_started_at(t0);
+/// let stalled = Sw::with_elapsed(Duration::from_secs(3));
+/// assert_eq!(libsw::relative_progress(&healthy, &healthy, t0, t1), 1.0);
+/// assert_eq!(libsw::relative_progress(&stalled, &healthy, t0, t1), 0.0);
+/// ```
+#[must_use]
+pub fn relative_progress<I: Instant>(
+    a: &StopwatchImpl<I>,
+    b: &StopwatchImpl<I>,
+    t0: I,
+    t1: I,
+) -> f64 {
+    let progress = |sw: &StopwatchImpl<I>| {
+        sw.elapsed_at(t1)
+            .saturating_sub(sw.elapsed_at(t0))
+            .as_secs_f64()
+    };
+    let b = progress(b);
+    if b == 0.0 {
+        0.0
+    } else {
+        progress(a) / b
+    }
+}
+
+/// Returns the mean elapsed time of `entries`, weighted by their counts.
+///
+/// Each entry pairs a count, such as how many operations of a kind were
+/// performed, with a stopwatch. The result is the sum of each count multiplied
+/// by its stopwatch's elapsed time, divided by the sum of the counts.
+///
+/// # Notes
+///
+/// - If `entries` is empty or the counts sum to zero, returns
+///   [`Duration::ZERO`].
+///
+/// - The current time is read once, and running stopwatches are all measured
+///   at that instant.
+///
+/// - If the weighted sum overflows, returns [`Duration::MAX`]. This takes
+///   both enormous counts and elapsed times of centuries.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use core::time::Duration;
+/// let reads = Sw::with_elapsed(Duration::from_millis(10));
+/// let writes = Sw::with_elapsed(Duration::from_millis(40));
+/// let mean = libsw::weighted_mean(&[(3, &reads), (1, &writes)]);
+/// assert_eq!(mean, Duration::from_micros(17_500));
+/// ```
+#[must_use]
+pub fn weighted_mean<I: Instant>(entries: &[(u64, &StopwatchImpl<I>)]) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    let now = I::now();
+    let mut weighted = 0_u128;
+    let mut total = 0_u128;
+    for (count, sw) in entries {
+        let count = u128::from(*count);
+        let nanos = sw.elapsed_at(now).as_nanos().checked_mul(count);
+        match nanos.and_then(|nanos| weighted.checked_add(nanos)) {
+            Some(sum) => weighted = sum,
+            None => return Duration::MAX,
+        }
+        total += count;
+    }
+
+    match weighted.checked_div(total) {
+        Some(mean) => match u64::try_from(mean / NANOS_PER_SEC) {
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(secs) => Duration::new(secs, (mean % NANOS_PER_SEC) as u32),
+            Err(_) => Duration::MAX,
+        },
+        None => Duration::ZERO,
+    }
+}
+
+/// Estimates how fast the elapsed time of a [stopwatch](StopwatchImpl) is
+/// advancing relative to a wall clock `W`.
+///
+/// Each observation pairs an elapsed time with the wall clock instant it was
+/// read at. The [rate](Self::rate) is the change in elapsed time divided by
+/// the change in wall time between the two most recent observations.
+///
+/// A running stopwatch whose clock keeps pace with `W` has a rate of about
+/// `1.0`, and a stopped stopwatch has a rate of `0.0`. A rate below `1.0` for
+/// a running stopwatch suggests its clock is frozen or slowed, such as a
+/// paused Tokio clock.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{RateEstimator, Sw};
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let mut rate = RateEstimator::<Instant>::new();
+/// let start = Instant::now();
+/// rate.record(Duration::ZERO, start);
+/// rate.record(Duration::from_millis(500), start + Duration::from_secs(1));
+/// assert_eq!(rate.rate(), Some(0.5));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct RateEstimator<W: Instant> {
+    previous: Option<(Duration, W)>,
+    latest: Option<(Duration, W)>,
+}
+
+impl<W: Instant> RateEstimator<W> {
+    /// Returns an estimator with no observations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            previous: None,
+            latest: None,
+        }
+    }
+
+    /// Records that the elapsed time was `elapsed` at the wall clock instant
+    /// `wall`.
+    pub fn record(&mut self, elapsed: Duration, wall: W) {
+        self.previous = self.latest;
+        self.latest = Some((elapsed, wall));
+    }
+
+    /// Records the elapsed time of `sw`, paired with the current time of the
+    /// wall clock.
+    pub fn observe<I: Instant>(&mut self, sw: &StopwatchImpl<I>) {
+        self.record(sw.elapsed(), W::now());
+    }
+
+    /// Returns the ratio of the change in elapsed time to the change in wall
+    /// time between the two most recent observations.
+    ///
+    /// # Notes
+    ///
+    /// Returns [`None`] if there are fewer than two observations, or if no
+    /// wall time passed between them. If the elapsed time decreased, the rate
+    /// is `0.0`.
+    #[must_use]
+    pub fn rate(&self) -> Option<f64> {
+        let ((prev_elapsed, prev_wall), (elapsed, wall)) = (self.previous?, self.latest?);
+        let wall = wall.saturating_duration_since(prev_wall).as_secs_f64();
+        if wall == 0.0 {
+            None
+        } else {
+            Some(elapsed.saturating_sub(prev_elapsed).as_secs_f64() / wall)
+        }
+    }
+
+    /// Clears all observations.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<W: Instant> Default for RateEstimator<W> {
+    /// Returns an estimator with no observations. Same as calling
+    /// [`RateEstimator::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
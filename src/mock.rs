@@ -0,0 +1,111 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ::core::sync::atomic::{AtomicU64, Ordering};
+use ::core::time::Duration;
+
+use crate::{Instant, Resolution};
+
+static NOW: AtomicU64 = AtomicU64::new(0);
+
+/// An instant read from a global mock clock, for deterministic tests.
+///
+/// The mock clock starts at zero and only moves when told to with
+/// [`advance`](Self::advance), [`rewind`](Self::rewind), or
+/// [`set`](Self::set), so tests of code using stopwatches don't need to sleep.
+/// It counts nanoseconds in an atomic integer, so it can be read and moved
+/// from any thread.
+///
+/// # Notes
+///
+/// The mock clock is shared by the whole process. Tests run in parallel by
+/// default, so tests which move the clock should only assert lower bounds, or
+/// run serially. For a clock local to each thread, see `ManualInstant`.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{MockInstant, MockSw};
+/// # use core::time::Duration;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = MockSw::new_started();
+/// MockInstant::advance(Duration::from_secs(1));
+/// sw.stop()?;
+/// assert_eq!(sw.elapsed(), Duration::from_secs(1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MockInstant {
+    nanos: u64,
+}
+
+impl MockInstant {
+    /// Returns an instant `nanos` nanoseconds after the mock clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self { nanos }
+    }
+
+    /// Returns the number of nanoseconds since the mock clock's epoch.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos(&self) -> u64 {
+        self.nanos
+    }
+
+    /// Advances the mock clock by `dur`.
+    ///
+    /// # Notes
+    ///
+    /// The clock saturates to [`u64::MAX`] nanoseconds on overflow.
+    pub fn advance(dur: Duration) {
+        let nanos = u64::try_from(dur.as_nanos()).unwrap_or(u64::MAX);
+        _ = NOW.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |now| {
+            Some(now.saturating_add(nanos))
+        });
+    }
+
+    /// Moves the mock clock back by `dur`, as a clock which isn't monotonic
+    /// might.
+    ///
+    /// # Notes
+    ///
+    /// The clock saturates to its epoch on underflow.
+    pub fn rewind(dur: Duration) {
+        let nanos = u64::try_from(dur.as_nanos()).unwrap_or(u64::MAX);
+        _ = NOW.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |now| {
+            Some(now.saturating_sub(nanos))
+        });
+    }
+
+    /// Sets the mock clock to `now`.
+    pub fn set(now: Self) {
+        NOW.store(now.nanos, Ordering::SeqCst);
+    }
+}
+
+impl Instant for MockInstant {
+    /// Returns the current instant according to the global mock clock.
+    fn now() -> Self {
+        Self::from_nanos(NOW.load(Ordering::SeqCst))
+    }
+
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        self.nanos.checked_add(nanos).map(Self::from_nanos)
+    }
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        self.nanos.checked_sub(nanos).map(Self::from_nanos)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.nanos.saturating_sub(earlier.nanos))
+    }
+}
+
+impl Resolution for MockInstant {}
@@ -0,0 +1,204 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::cell::Cell;
+use core::fmt;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// Returns `now`, clamped to be no earlier than `last`, and records the
+/// result as the latest instant read.
+pub(crate) fn clamp_to_last<I: Instant>(last: &Cell<Option<I>>, now: I) -> I {
+    match last.get() {
+        Some(last) if last.saturating_duration_since(now) > Duration::ZERO => last,
+        _ => {
+            last.set(Some(now));
+            now
+        }
+    }
+}
+
+/// A [stopwatch](StopwatchImpl) which guards against its clock going
+/// backwards.
+///
+/// Some platforms, notably certain hypervisors, have been observed returning
+/// an earlier instant from [`I::now()`](Instant::now) than a previous read.
+/// `MonotonicStopwatch` remembers the latest instant it has read, and clamps
+/// any earlier read to it. As a result, its elapsed time never regresses while
+/// running.
+///
+/// `MonotonicStopwatch`es are returned by
+/// [`StopwatchImpl::into_monotonic`].
+///
+/// # Notes
+///
+/// Only reads of the current time made through this type are guarded. Time
+/// lost to a backwards jump is not recovered; the stopwatch simply doesn't
+/// advance until the clock catches up with the latest instant read.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = Sw::new().into_monotonic();
+/// sw.start()?;
+/// let first = sw.elapsed();
+/// let second = sw.elapsed();
+/// assert!(second >= first);
+/// # Ok(())
+/// # }
+/// ```
+pub struct MonotonicStopwatch<I: Instant> {
+    inner: StopwatchImpl<I>,
+    last: Cell<Option<I>>,
+}
+
+impl<I: Instant> MonotonicStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        let mut sw = Self::new();
+        _ = sw.start();
+        sw
+    }
+
+    /// Returns a monotonic stopwatch wrapping `inner`.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self {
+            inner,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the latest instant read by the stopwatch, if any.
+    #[inline]
+    #[must_use]
+    pub fn last_now(&self) -> Option<I> {
+        self.last.get()
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the current time, clamped to be no earlier than the latest
+    /// instant read.
+    #[must_use]
+    pub fn now(&self) -> I {
+        clamp_to_last(&self.last, I::now())
+    }
+
+    /// Returns the total time elapsed, as of the [clamped](Self::now) current
+    /// time.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed_at(self.now())
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        let now = self.now();
+        self.inner.start_at(now)
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        let now = self.now();
+        self.inner.stop_at(now)
+    }
+
+    /// Stops and resets the elapsed time to zero.
+    ///
+    /// The latest instant read is kept, so later reads remain guarded.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<I: Instant> StopwatchImpl<I> {
+    /// Returns a [`MonotonicStopwatch`] wrapping the stopwatch, which clamps
+    /// backwards reads of the current time.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`MonotonicStopwatch`] for a related example.
+    #[must_use]
+    pub const fn into_monotonic(self) -> MonotonicStopwatch<I> {
+        MonotonicStopwatch::from_inner(self)
+    }
+}
+
+impl<I: Instant> Clone for MonotonicStopwatch<I> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner,
+            last: Cell::new(self.last.get()),
+        }
+    }
+}
+
+impl<I: Instant> fmt::Debug for MonotonicStopwatch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MonotonicStopwatch")
+            .field("inner", &self.inner)
+            .field("last", &self.last.get())
+            .finish()
+    }
+}
+
+impl<I: Instant> Default for MonotonicStopwatch<I> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`MonotonicStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for MonotonicStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
@@ -0,0 +1,154 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::fmt;
+use core::time::Duration;
+use opentelemetry::trace::Span;
+use opentelemetry::KeyValue;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// A running, guarded, [stopwatch](StopwatchImpl) which reports to an
+/// OpenTelemetry [`Span`]. When [dropped](OtelGuard::drop), the stopwatch will
+/// automatically stop, and the span is ended.
+///
+/// Before the span is ended, the time elapsed while guarded is recorded on it
+/// as the [`ELAPSED_KEY`](OtelGuard::ELAPSED_KEY) attribute, in seconds.
+///
+/// `OtelGuard`s are returned by the `StopwatchImpl` methods
+/// [`guard_otel`](StopwatchImpl::guard_otel) and
+/// [`guard_otel_at`](StopwatchImpl::guard_otel_at).
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::Sw;
+/// # use opentelemetry::trace::{noop::NoopSpan, Span};
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = Sw::new();
+/// {
+///     let _guard = sw.guard_otel(NoopSpan::DEFAULT)?;
+///     // do some work...
+/// }
+/// assert!(sw.is_stopped());
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "if unused, the inner stopwatch will immediately stop again"]
+pub struct OtelGuard<'sw, I: Instant, S: Span> {
+    // invariant: sw must be running
+    inner: &'sw mut StopwatchImpl<I>,
+    span: S,
+}
+
+impl<'sw, I: Instant, S: Span> OtelGuard<'sw, I, S> {
+    /// The attribute key under which the time elapsed while guarded is
+    /// recorded, in seconds.
+    pub const ELAPSED_KEY: &'static str = "libsw.elapsed_secs";
+
+    /// Returns an `OtelGuard` to a running [stopwatch](StopwatchImpl), which
+    /// will report to `span`.
+    ///
+    /// # Errors
+    ///
+    /// If the stopwatch is stopped, returns [`GuardNew`](Error::GuardNew).
+    pub fn new(sw: &'sw mut StopwatchImpl<I>, span: S) -> crate::Result<Self> {
+        if sw.is_running() {
+            Ok(Self { inner: sw, span })
+        } else {
+            Err(Error::GuardNew)
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns a reference to the guarded span.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> &S {
+        &self.span
+    }
+
+    /// Returns a mutable reference to the guarded span.
+    #[inline]
+    #[must_use]
+    pub fn span_mut(&mut self) -> &mut S {
+        &mut self.span
+    }
+}
+
+impl<I: Instant, S: Span> Drop for OtelGuard<'_, I, S> {
+    /// Releases the guard, stopping the guarded [stopwatch](StopwatchImpl),
+    /// recording the time elapsed since it was last started on the span, and
+    /// ending the span.
+    fn drop(&mut self) {
+        debug_assert!(self.inner.is_running());
+        let now = I::now();
+        let segment = self
+            .inner
+            .inner
+            .start
+            .map_or(Duration::ZERO, |start| now.saturating_duration_since(start));
+        _ = self.inner.stop_at(now);
+
+        self.span
+            .set_attribute(KeyValue::new(Self::ELAPSED_KEY, segment.as_secs_f64()));
+        self.span.end();
+    }
+}
+
+impl<I: Instant, S: Span> fmt::Debug for OtelGuard<'_, I, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OtelGuard")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Instant> StopwatchImpl<I> {
+    /// Starts the stopwatch, returning an [`OtelGuard`] which when dropped,
+    /// will stop the stopwatch and report the time elapsed while guarded to
+    /// `span`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Examples
+    ///
+    /// For examples on how to use `OtelGuard`s, see the [struct
+    /// documentation](OtelGuard).
+    pub fn guard_otel<S: Span>(&mut self, span: S) -> crate::Result<OtelGuard<'_, I, S>> {
+        self.guard_otel_at(I::now(), span)
+    }
+
+    /// Starts the stopwatch as if the current time were `anchor`, returning an
+    /// [`OtelGuard`], which when dropped, will stop the stopwatch and report
+    /// the time elapsed while guarded to `span`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// For details about `anchor`, see [`start_at`](Self::start_at). For
+    /// examples on how to use `OtelGuard`s, see the [struct
+    /// documentation](OtelGuard).
+    pub fn guard_otel_at<S: Span>(
+        &mut self,
+        anchor: I,
+        span: S,
+    ) -> crate::Result<OtelGuard<'_, I, S>> {
+        self.start_at(anchor).map_err(|_| Error::SwGuard)?;
+        let guard = OtelGuard::new(self, span);
+        debug_assert!(guard.is_ok());
+        guard
+    }
+}
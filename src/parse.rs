@@ -0,0 +1,225 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::fmt;
+use core::str::FromStr;
+use core::time::Duration;
+
+use crate::error::state_to_str;
+use crate::{Instant, StopwatchImpl};
+
+/// Error returned when parsing a [stopwatch](StopwatchImpl) from its
+/// [`Display`](fmt::Display) form fails.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseError {
+    /// The elapsed time isn't of the form `HH:MM:SS.mmm`, or a human duration
+    /// is malformed.
+    Format,
+
+    /// The minutes or seconds are 60 or more, or the elapsed time can't be
+    /// represented by a [`Duration`].
+    Range,
+
+    /// The elapsed time isn't followed by ` (running)` or ` (stopped)`.
+    State,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Format => "elapsed time is not of the form `HH:MM:SS.mmm`",
+            Self::Range => "elapsed time is out of range",
+            Self::State => "state is not `(running)` or `(stopped)`",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ParseError {}
+
+#[cfg(all(feature = "nightly", not(feature = "std")))]
+impl ::core::error::Error for ParseError {}
+
+impl<I: Instant> FromStr for StopwatchImpl<I> {
+    type Err = ParseError;
+
+    /// Parses a stopwatch from the form written by its
+    /// [`Display`](fmt::Display) implementation, `HH:MM:SS.mmm (stopped)`.
+    ///
+    /// A stopped stopwatch is parsed with the given elapsed time. A running
+    /// stopwatch is parsed as if it were started at [`I::now`](Instant::now)
+    /// with the given elapsed time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] describing why `s` couldn't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{ParseError, Sw};
+    /// # use core::time::Duration;
+    /// let sw: Sw = "01:02:03.045 (stopped)".parse()?;
+    /// assert_eq!(sw, Sw::with_elapsed(Duration::from_millis(3_723_045)));
+    /// assert_eq!(sw.to_string().parse(), Ok(sw));
+    ///
+    /// assert_eq!("1:02:03 (stopped)".parse::<Sw>(), Err(ParseError::Format));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (elapsed, state) = s
+            .strip_suffix(')')
+            .and_then(|s| s.rsplit_once(" ("))
+            .ok_or(ParseError::State)?;
+        let elapsed = parse_elapsed(elapsed)?;
+        if state == state_to_str(true) {
+            Ok(Self::with_elapsed_started(elapsed))
+        } else if state == state_to_str(false) {
+            Ok(Self::with_elapsed(elapsed))
+        } else {
+            Err(ParseError::State)
+        }
+    }
+}
+
+/// Parses an elapsed time of the form `HH:MM:SS.mmm`, where hours have at
+/// least two digits.
+fn parse_elapsed(s: &str) -> Result<Duration, ParseError> {
+    let (hours, rest) = s.split_once(':').ok_or(ParseError::Format)?;
+    let (mins, rest) = rest.split_once(':').ok_or(ParseError::Format)?;
+    let (secs, millis) = rest.split_once('.').ok_or(ParseError::Format)?;
+    if hours.len() < 2 || mins.len() != 2 || secs.len() != 2 || millis.len() != 3 {
+        return Err(ParseError::Format);
+    }
+
+    let hours = parse_digits(hours)?;
+    let (mins, secs) = (parse_digits(mins)?, parse_digits(secs)?);
+    let millis = parse_digits(millis)?;
+    if mins >= 60 || secs >= 60 {
+        return Err(ParseError::Range);
+    }
+
+    let total = hours
+        .checked_mul(3600)
+        .and_then(|total| total.checked_add(mins * 60 + secs))
+        .ok_or(ParseError::Range)?;
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(Duration::new(total, millis as u32 * 1_000_000))
+}
+
+/// Parses a non-empty string of ASCII digits.
+fn parse_digits(s: &str) -> Result<u64, ParseError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::Format);
+    }
+    // only fails on overflow, since the digits were checked
+    s.parse().map_err(|_| ParseError::Range)
+}
+
+/// Parses a compact human duration, such as `"1h30m"` or `"2.5s"`.
+///
+/// The duration is a sequence of numbers, each followed by a unit: `h`, `m`,
+/// `s`, `ms`, `us` or `µs`, or `ns`. Numbers may have a fractional part, and
+/// the values of all parts are summed. Parsing doesn't allocate.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Format`] if `s` is empty, or a number or unit is
+/// malformed. Returns [`ParseError::Range`] if the duration can't be
+/// represented by a [`Duration`].
+///
+/// # Notes
+///
+/// Fractions of a nanosecond are truncated.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{ParseError, Sw};
+/// # use core::time::Duration;
+/// let elapsed = libsw::parse_duration("1h30m")?;
+/// assert_eq!(elapsed, Duration::from_secs(5400));
+/// assert_eq!(libsw::parse_duration("1.5ms")?, Duration::from_micros(1500));
+/// assert_eq!(libsw::parse_duration("1d"), Err(ParseError::Format));
+///
+/// let sw = Sw::with_elapsed(libsw::parse_duration("2m5s")?);
+/// assert_eq!(sw.elapsed(), Duration::from_secs(125));
+/// # Ok::<(), ParseError>(())
+/// ```
+#[cfg(feature = "parse_duration")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parse_duration")))]
+pub fn parse_duration(s: &str) -> Result<Duration, ParseError> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    if s.is_empty() {
+        return Err(ParseError::Format);
+    }
+    let mut rest = s;
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or(ParseError::Format)?;
+        let (number, after) = rest.split_at(number_len);
+        let unit_len = after
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        let unit: u128 = match unit {
+            "h" => 3600 * NANOS_PER_SEC,
+            "m" => 60 * NANOS_PER_SEC,
+            "s" => NANOS_PER_SEC,
+            "ms" => 1_000_000,
+            "us" | "µs" => 1_000,
+            "ns" => 1,
+            _ => return Err(ParseError::Format),
+        };
+        let nanos = parse_decimal(number, unit)?;
+        total = total.checked_add(nanos).ok_or(ParseError::Range)?;
+        rest = after;
+    }
+
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| ParseError::Range)?;
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(Duration::new(secs, (total % NANOS_PER_SEC) as u32))
+}
+
+/// Parses a decimal number with an optional fractional part, multiplied by
+/// `unit` and truncated to an integer.
+#[cfg(feature = "parse_duration")]
+fn parse_decimal(s: &str, unit: u128) -> Result<u128, ParseError> {
+    // digits beyond this can't affect whole nanoseconds of any unit
+    const MAX_FRACTION_DIGITS: usize = 15;
+
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(ParseError::Format);
+    }
+    if !whole
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(ParseError::Format);
+    }
+
+    let mut nanos: u128 = 0;
+    for digit in whole.bytes() {
+        nanos = nanos
+            .checked_mul(10)
+            .and_then(|nanos| nanos.checked_add(u128::from(digit - b'0')))
+            .ok_or(ParseError::Range)?;
+    }
+    nanos = nanos.checked_mul(unit).ok_or(ParseError::Range)?;
+
+    let mut numerator: u128 = 0;
+    let mut denominator: u128 = 1;
+    for digit in fraction.bytes().take(MAX_FRACTION_DIGITS) {
+        numerator = numerator * 10 + u128::from(digit - b'0');
+        denominator *= 10;
+    }
+    nanos
+        .checked_add(numerator * unit / denominator)
+        .ok_or(ParseError::Range)
+}
@@ -0,0 +1,142 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// The resolution of the operating system's clocks, which is 100 nanoseconds
+/// on Windows and nominally 1 nanosecond elsewhere.
+#[allow(dead_code)]
+pub(crate) const OS_RESOLUTION: Duration = if cfg!(windows) {
+    Duration::from_nanos(100)
+} else {
+    Duration::from_nanos(1)
+};
+
+/// An [`Instant`] whose clock has a known resolution.
+///
+/// The resolution is the smallest difference between two instants the clock
+/// can report. Durations shorter than the resolution can't be measured
+/// reliably, and may be reported as zero.
+///
+/// # Provided implementations
+///
+/// | Type                                              | Resolution                                    |
+/// |---------------------------------------------------|-----------------------------------------------|
+/// | `std::time::Instant`                              | 100 ns on Windows, 1 ns elsewhere             |
+/// | `std::time::SystemTime`, `MonotonicSystemInstant` | 100 ns on Windows, 1 ns elsewhere             |
+/// | `tokio::time::Instant`                            | 100 ns on Windows, 1 ns elsewhere             |
+/// | `coarsetime::Instant`                             | 1 ms                                          |
+/// | `quanta::Instant`, `QuantaClockInstant`           | 1 ns                                          |
+/// | `time::Instant`                                   | 100 ns on Windows, 1 ns elsewhere             |
+/// | `ProcessCpuInstant`                               | 100 ns on Windows, 1 ns elsewhere             |
+/// | `BoottimeInstant`                                 | 1 ns                                          |
+/// | `WebInstant`                                      | 100 µs in the browser, as `Instant` elsewhere |
+/// | `MinstantInstant`                                 | 1 ns                                          |
+/// | `ChronoInstant`                                   | 100 ns on Windows, 1 ns elsewhere             |
+/// | `EmbeddedInstant`, `FugitInstant`                 | 1 tick                                        |
+/// | `GlobalInstant`, manual clocks                    | 1 ns                                          |
+///
+/// # Notes
+///
+/// Resolutions are nominal, and the clock may be coarser in practice. For
+/// example, `coarsetime` reads a clock which only advances once per kernel
+/// tick, which is between 1 and 10 milliseconds depending on the system, and
+/// browsers coarsen timers to between 5 and 100 microseconds to mitigate
+/// timing attacks.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{Instant, Resolution, StopwatchImpl};
+/// # use core::time::Duration;
+/// #[derive(Clone, Copy, Debug)]
+/// struct Frame(u64);
+///
+/// impl Instant for Frame {
+///     // ...
+///     # fn now() -> Self { Self(0) }
+///     # fn checked_add(&self, _: Duration) -> Option<Self> { Some(*self) }
+///     # fn checked_sub(&self, _: Duration) -> Option<Self> { Some(*self) }
+///     # fn saturating_duration_since(&self, _: Self) -> Duration { Duration::ZERO }
+/// }
+///
+/// impl Resolution for Frame {
+///     fn resolution() -> Duration {
+///         Duration::from_millis(16)
+///     }
+/// }
+///
+/// let measured = Duration::from_millis(5);
+/// if measured < StopwatchImpl::<Frame>::clock_resolution() {
+///     // warn that the measurement is below the clock's resolution
+/// }
+/// ```
+pub trait Resolution: Instant {
+    /// Returns the resolution of the clock.
+    ///
+    /// The default implementation returns 1 nanosecond, the resolution of
+    /// [`Duration`].
+    #[must_use]
+    fn resolution() -> Duration {
+        Duration::from_nanos(1)
+    }
+}
+
+impl<I: Resolution> StopwatchImpl<I> {
+    /// Returns the resolution of the clock `I`, below which durations can't
+    /// be measured reliably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// assert!(Sw::clock_resolution() <= Duration::from_micros(1));
+    /// ```
+    #[must_use]
+    pub fn clock_resolution() -> Duration {
+        I::resolution()
+    }
+}
+
+#[cfg(feature = "std_instant")]
+impl Resolution for ::std::time::Instant {
+    fn resolution() -> Duration {
+        OS_RESOLUTION
+    }
+}
+
+#[cfg(feature = "std_systemtime")]
+impl Resolution for ::std::time::SystemTime {
+    fn resolution() -> Duration {
+        OS_RESOLUTION
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Resolution for ::tokio::time::Instant {
+    fn resolution() -> Duration {
+        OS_RESOLUTION
+    }
+}
+
+#[cfg(feature = "coarsetime")]
+impl Resolution for ::coarsetime::Instant {
+    fn resolution() -> Duration {
+        Duration::from_millis(1)
+    }
+}
+
+#[cfg(feature = "quanta")]
+impl Resolution for ::quanta::Instant {}
+
+#[cfg(feature = "time")]
+#[allow(deprecated)]
+impl Resolution for ::time::Instant {
+    fn resolution() -> Duration {
+        OS_RESOLUTION
+    }
+}
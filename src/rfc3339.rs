@@ -0,0 +1,89 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::string::String;
+use std::time::SystemTime;
+
+use crate::{StopwatchImpl, TrackingStopwatch};
+
+// converting with `From<SystemTime>` panics if `time` is out of chrono's range
+fn to_rfc3339(time: SystemTime) -> Option<String> {
+    let (secs, nanos) = match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since) => (i64::try_from(since.as_secs()).ok()?, since.subsec_nanos()),
+        Err(err) => {
+            let before = err.duration();
+            let secs = i64::try_from(before.as_secs()).ok()?.checked_neg()?;
+            match before.subsec_nanos() {
+                0 => (secs, 0),
+                nanos => (secs.checked_sub(1)?, 1_000_000_000 - nanos),
+            }
+        }
+    };
+    let time = DateTime::<Utc>::from_timestamp(secs, nanos)?;
+    Some(time.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std_systemtime", feature = "chrono"))))]
+impl StopwatchImpl<SystemTime> {
+    /// Returns the instant the stopwatch was last started as an RFC 3339
+    /// timestamp in UTC, or [`None`] if it's stopped or the instant is out of
+    /// the range of `chrono`'s `DateTime`.
+    ///
+    /// This is useful for human-readable audit logs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::SystemSw;
+    /// # use core::time::Duration;
+    /// # use std::time::SystemTime;
+    /// let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let sw = SystemSw::new_started_at(start);
+    /// assert_eq!(sw.start_rfc3339().as_deref(), Some("2023-11-14T22:13:20Z"));
+    /// assert_eq!(SystemSw::new().start_rfc3339(), None);
+    /// ```
+    #[must_use]
+    pub fn start_rfc3339(&self) -> Option<String> {
+        self.inner.start.and_then(to_rfc3339)
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std_systemtime", feature = "chrono"))))]
+impl TrackingStopwatch<SystemTime> {
+    /// Returns the instant the stopwatch was last started as an RFC 3339
+    /// timestamp in UTC, or [`None`] if it's stopped or the instant is out of
+    /// range.
+    ///
+    /// See [`StopwatchImpl::start_rfc3339`].
+    #[must_use]
+    pub fn start_rfc3339(&self) -> Option<String> {
+        self.inner().start_rfc3339()
+    }
+
+    /// Returns the instant the last segment completed as an RFC 3339
+    /// timestamp in UTC, or [`None`] if the stopwatch is running, no segment
+    /// has completed, or the instant is out of range. See
+    /// [`stopped_at`](Self::stopped_at).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::TrackingStopwatch;
+    /// # use core::time::Duration;
+    /// # use std::time::SystemTime;
+    /// # fn main() -> libsw::Result<()> {
+    /// let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let mut sw = TrackingStopwatch::<SystemTime>::new();
+    /// sw.start_at(start)?;
+    /// sw.stop_at(start + Duration::from_millis(1500))?;
+    /// assert_eq!(sw.last_stop_rfc3339().as_deref(), Some("2023-11-14T22:13:21.500Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn last_stop_rfc3339(&self) -> Option<String> {
+        self.stopped_at().and_then(to_rfc3339)
+    }
+}
@@ -0,0 +1,240 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) which accumulates scaled time, such as for a
+/// simulation running at a variable speed.
+///
+/// While running, the time elapsed is multiplied by the
+/// [time scale](Self::time_scale) before it's added to the total. A scale of
+/// `2.0` accumulates time twice as fast as the clock, and `0.0` freezes the
+/// total while the stopwatch keeps running. Changing the scale only affects
+/// time measured afterwards.
+///
+/// # Notes
+///
+/// Scaled time is computed with [`f64`] seconds, so it's exact only to about
+/// 15 significant digits. A scale of `1.0` measures time exactly. Overflows of
+/// the elapsed time are saturated to [`Duration::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::ScaledStopwatch;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut sw = ScaledStopwatch::<Instant>::new();
+/// sw.start_at(start)?;
+/// sw.set_time_scale_at(2.0, start + Duration::from_secs(1));
+/// sw.stop_at(start + Duration::from_secs(2))?;
+/// assert_eq!(sw.elapsed(), Duration::from_secs(3));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaledStopwatch<I: Instant> {
+    // elapsed is the scaled time banked before the current segment
+    inner: StopwatchImpl<I>,
+    time_scale: f64,
+}
+
+impl<I: Instant> ScaledStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time and a time scale of
+    /// `1.0`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time and a
+    /// time scale of `1.0`.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self::from_inner(StopwatchImpl::new_started())
+    }
+
+    /// Returns a scaled stopwatch wrapping `inner`, with a time scale of
+    /// `1.0`.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self {
+            inner,
+            time_scale: 1.0,
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`], whose elapsed time
+    /// is the scaled time accumulated before the current segment.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`], whose elapsed time is the scaled
+    /// time accumulated before the current segment.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the factor by which time is scaled while running.
+    #[inline]
+    #[must_use]
+    pub const fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Sets the factor by which time is scaled while running.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative, infinite, or NaN.
+    pub fn set_time_scale(&mut self, factor: f64) {
+        self.set_time_scale_at(factor, I::now());
+    }
+
+    /// Sets the factor by which time is scaled while running, as if the
+    /// current time were `anchor`. Time before `anchor` keeps the previous
+    /// scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative, infinite, or NaN.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    pub fn set_time_scale_at(&mut self, factor: f64, anchor: I) {
+        assert!(
+            factor.is_finite() && factor >= 0.0,
+            "time scale must be finite and non-negative"
+        );
+        if let Some(start) = self.inner.inner.start {
+            let segment = anchor.saturating_duration_since(start);
+            if segment != Duration::ZERO {
+                let elapsed = self.elapsed_at(anchor);
+                self.inner = StopwatchImpl::from_raw(elapsed, Some(anchor));
+            }
+        }
+        self.time_scale = factor;
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total scaled time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(I::now())
+    }
+
+    /// Returns the total scaled time elapsed, measured as if the current time
+    /// were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        let banked = self.inner.inner.elapsed;
+        match self.inner.inner.start {
+            Some(start) => {
+                banked.saturating_add(self.scale(anchor.saturating_duration_since(start)))
+            }
+            None => banked,
+        }
+    }
+
+    /// Starts measuring scaled time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.start_at(I::now())
+    }
+
+    /// Starts measuring scaled time, as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring scaled time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.stop_at(I::now())
+    }
+
+    /// Stops measuring scaled time, as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        if self.is_stopped() {
+            return Err(Error::SwStop);
+        }
+        self.inner = StopwatchImpl::with_elapsed(self.elapsed_at(anchor));
+        Ok(())
+    }
+
+    /// Stops and resets the elapsed time to zero. The time scale is kept.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[allow(clippy::float_cmp)]
+    fn scale(&self, segment: Duration) -> Duration {
+        if self.time_scale == 1.0 {
+            return segment;
+        }
+        let secs = segment.as_secs_f64() * self.time_scale;
+        if secs >= Duration::MAX.as_secs_f64() {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+}
+
+impl<I: Instant> Default for ScaledStopwatch<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for ScaledStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
@@ -0,0 +1,48 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Instant, StopwatchImpl};
+
+/// Serialized representation of a [`StopwatchImpl`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "StopwatchImpl")]
+struct Repr {
+    elapsed: Duration,
+    running: bool,
+}
+
+impl<I: Instant> Serialize for StopwatchImpl<I> {
+    /// Serializes the total time elapsed and whether the stopwatch is running.
+    ///
+    /// A running stopwatch's current segment is folded into the serialized
+    /// elapsed time, measured at the time of serialization. The elapsed time
+    /// saturates as described in [`elapsed`](StopwatchImpl::elapsed).
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            elapsed: self.elapsed(),
+            running: self.is_running(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, I: Instant> Deserialize<'de> for StopwatchImpl<I> {
+    /// Deserializes a stopwatch with the serialized elapsed time.
+    ///
+    /// If the stopwatch was running when serialized, it's started at
+    /// [`I::now()`](Instant::now), so it resumes from the elapsed time it had
+    /// when serialized. Time that passed in between isn't counted. Otherwise,
+    /// it's stopped.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(if repr.running {
+            Self::with_elapsed_started(repr.elapsed)
+        } else {
+            Self::with_elapsed(repr.elapsed)
+        })
+    }
+}
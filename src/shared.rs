@@ -0,0 +1,183 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::cell::Cell;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) which can be guarded by many overlapping
+/// [guards](SharedGuard) at once.
+///
+/// The stopwatch runs while at least one guard is alive. The first guard
+/// starts it, and it only stops when the last outstanding guard is dropped.
+/// This suits recursive or nested code, where an inner [`Guard`](crate::Guard)
+/// would stop the stopwatch while an outer scope still expects it to run.
+///
+/// # Notes
+///
+/// The stopwatch and the count of guards are stored in [`Cell`]s, so guards
+/// only need a shared reference. `SharedStopwatch` is not [`Sync`], and is
+/// meant for guarding from a single thread. See
+/// [`AtomicStopwatch`](crate::AtomicStopwatch) for sharing a stopwatch
+/// between threads.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::SharedStopwatch;
+/// # use std::time::Instant;
+/// fn recurse(sw: &SharedStopwatch<Instant>, depth: u32) {
+///     let _guard = sw.guard();
+///     if depth > 0 {
+///         recurse(sw, depth - 1);
+///     }
+///     // still running until the outermost guard is dropped
+///     assert!(sw.is_running());
+/// }
+///
+/// let sw = SharedStopwatch::new();
+/// recurse(&sw, 3);
+/// assert!(sw.is_stopped());
+/// assert_eq!(sw.guards(), 0);
+/// ```
+#[derive(Debug)]
+pub struct SharedStopwatch<I: Instant> {
+    inner: Cell<StopwatchImpl<I>>,
+    guards: Cell<usize>,
+}
+
+impl<I: Instant> SharedStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time and no guards.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a shared stopwatch wrapping `inner`, with no guards.
+    ///
+    /// If `inner` is running, it keeps running until the last guard created
+    /// from now on is dropped.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self {
+            inner: Cell::new(inner),
+            guards: Cell::new(0),
+        }
+    }
+
+    /// Returns a copy of the inner [`StopwatchImpl`].
+    #[must_use]
+    pub fn get(&self) -> StopwatchImpl<I> {
+        self.inner.get()
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[must_use]
+    pub fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner.into_inner()
+    }
+
+    /// Returns the number of outstanding guards.
+    #[must_use]
+    pub fn guards(&self) -> usize {
+        self.guards.get()
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.get().is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.get().is_stopped()
+    }
+
+    /// Returns the total time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.get().elapsed()
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        self.get().elapsed_at(anchor)
+    }
+
+    /// Returns a new guard, starting the stopwatch if it's stopped.
+    pub fn guard(&self) -> SharedGuard<'_, I> {
+        self.guard_at(I::now())
+    }
+
+    /// Returns a new guard, starting the stopwatch as if the current time were
+    /// `anchor` if it's stopped.
+    pub fn guard_at(&self, anchor: I) -> SharedGuard<'_, I> {
+        let mut sw = self.get();
+        _ = sw.start_at(anchor);
+        self.inner.set(sw);
+        self.guards.set(self.guards.get() + 1);
+        SharedGuard { inner: self }
+    }
+
+    /// Releases one guard, stopping the stopwatch at `anchor` if it was the
+    /// last.
+    fn release_at(&self, anchor: I) {
+        let guards = self.guards.get() - 1;
+        self.guards.set(guards);
+        if guards == 0 {
+            let mut sw = self.get();
+            _ = sw.stop_at(anchor);
+            self.inner.set(sw);
+        }
+    }
+}
+
+impl<I: Instant> Default for SharedStopwatch<I> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`SharedStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for SharedStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
+
+/// A guard on a [`SharedStopwatch`]. When [dropped](SharedGuard::drop), the
+/// stopwatch stops if this was the last outstanding guard.
+///
+/// `SharedGuard`s are returned by the `SharedStopwatch` methods
+/// [`guard`](SharedStopwatch::guard) and
+/// [`guard_at`](SharedStopwatch::guard_at).
+#[must_use = "if unused, the inner stopwatch may immediately stop again"]
+#[derive(Debug)]
+pub struct SharedGuard<'sw, I: Instant> {
+    inner: &'sw SharedStopwatch<I>,
+}
+
+impl<I: Instant> SharedGuard<'_, I> {
+    /// Returns a reference to the guarded [`SharedStopwatch`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &SharedStopwatch<I> {
+        self.inner
+    }
+}
+
+impl<I: Instant> Drop for SharedGuard<'_, I> {
+    /// Releases the guard, stopping the [stopwatch](SharedStopwatch) if no
+    /// other guards are outstanding.
+    #[inline]
+    fn drop(&mut self) {
+        self.inner.release_at(I::now());
+    }
+}
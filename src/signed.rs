@@ -0,0 +1,289 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::hash::{Hash, Hasher};
+use core::ops;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A stopwatch whose total elapsed time may be negative.
+///
+/// This is useful for accounting where time can be owed. Time measured while
+/// running always counts up, as with [`StopwatchImpl`], but arbitrary
+/// durations may be added or subtracted, and subtracting below zero yields a
+/// negative total.
+///
+/// The total elapsed time is measured in nanoseconds as an [`i128`], and
+/// saturates on overflow.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::SignedStopwatch;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// let mut sw = SignedStopwatch::<Instant>::new();
+/// sw -= Duration::from_secs(2);
+/// sw += Duration::from_secs(1);
+/// assert_eq!(sw.elapsed_nanos(), -1_000_000_000);
+/// assert!(sw.is_negative());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SignedStopwatch<I: Instant> {
+    offset: i128,
+    inner: StopwatchImpl<I>,
+}
+
+fn duration_to_nanos(dur: Duration) -> i128 {
+    i128::try_from(dur.as_nanos()).unwrap_or(i128::MAX)
+}
+
+fn nanos_to_duration(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    // less than one billion
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// The state of a signed stopwatch with all of its elapsed time moved into its
+/// start instant, so that stopwatches with equal elapsed time compare equal
+/// however it is split between the offset and the inner stopwatch.
+#[derive(Clone, Copy)]
+enum Canonical<I: Instant> {
+    Stopped(i128),
+    Bounded(I),
+    // the start instant can't be represented, and all such stopwatches are
+    // considered equivalent, as with `StopwatchImpl`
+    Unbounded,
+}
+
+impl<I: Instant> Canonical<I> {
+    fn new(sw: &SignedStopwatch<I>) -> Self {
+        let elapsed = sw
+            .offset
+            .saturating_add(duration_to_nanos(sw.inner.inner.elapsed));
+        let start = match sw.inner.inner.start {
+            Some(start) => start,
+            None => return Self::Stopped(elapsed),
+        };
+        let moved = nanos_to_duration(elapsed.unsigned_abs()).and_then(|dur| {
+            if elapsed < 0 {
+                start.checked_add(dur)
+            } else {
+                start.checked_sub(dur)
+            }
+        });
+        moved.map_or(Self::Unbounded, Self::Bounded)
+    }
+}
+
+impl<I: Instant> PartialEq for Canonical<I> {
+    fn eq(&self, rhs: &Self) -> bool {
+        match (*self, *rhs) {
+            (Self::Stopped(lhs), Self::Stopped(rhs)) => lhs == rhs,
+            (Self::Bounded(lhs), Self::Bounded(rhs)) => {
+                lhs.saturating_duration_since(rhs) == rhs.saturating_duration_since(lhs)
+            }
+            (Self::Unbounded, Self::Unbounded) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<I: Instant> SignedStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            inner: StopwatchImpl::new(),
+        }
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self {
+            offset: 0,
+            inner: StopwatchImpl::new_started(),
+        }
+    }
+
+    /// Returns a stopped stopwatch with the given elapsed time in nanoseconds.
+    #[must_use]
+    pub const fn with_elapsed_nanos(nanos: i128) -> Self {
+        Self {
+            offset: nanos,
+            inner: StopwatchImpl::new(),
+        }
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed in nanoseconds.
+    #[must_use]
+    pub fn elapsed_nanos(&self) -> i128 {
+        self.elapsed_nanos_at(I::now())
+    }
+
+    /// Returns the total time elapsed in nanoseconds, measured as if the
+    /// current time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// See [`StopwatchImpl::elapsed_at`] for details about `anchor`.
+    #[must_use]
+    pub fn elapsed_nanos_at(&self, anchor: I) -> i128 {
+        self.offset
+            .saturating_add(duration_to_nanos(self.inner.elapsed_at(anchor)))
+    }
+
+    /// Returns `true` if the total time elapsed is negative.
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.elapsed_nanos() < 0
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.inner.start()
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.inner.stop()
+    }
+
+    /// Stops measuring the time elapsed since the last start as if the current
+    /// time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.stop_at(anchor)
+    }
+
+    /// Stops and resets the elapsed time to zero.
+    pub fn reset(&mut self) {
+        self.offset = 0;
+        self.inner.reset();
+    }
+
+    /// Adds `nanos` nanoseconds to the total elapsed time, which may be
+    /// negative. Saturates on overflow.
+    pub fn add_nanos(&mut self, nanos: i128) {
+        self.offset = self.offset.saturating_add(nanos);
+    }
+}
+
+impl<I: Instant> Default for SignedStopwatch<I> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`SignedStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> PartialEq for SignedStopwatch<I> {
+    /// Tests for equality between `self` and `rhs`.
+    ///
+    /// Stopwatches are equal if whether they are running and their signed
+    /// elapsed time are equal, regardless of how that time was accumulated.
+    fn eq(&self, rhs: &Self) -> bool {
+        Canonical::new(self) == Canonical::new(rhs)
+    }
+}
+
+impl<I: Instant> Eq for SignedStopwatch<I> {}
+
+impl<I: Instant + Hash> Hash for SignedStopwatch<I> {
+    /// Hashes `self`. These hashes are not dependent on the time of
+    /// measurement, so they can be used to test equality.
+    ///
+    /// # Support
+    ///
+    /// `I` (the [`Instant`] type used by the stopwatch) must implement
+    /// [`Hash`].
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match Canonical::new(self) {
+            Canonical::Stopped(elapsed) => {
+                0_u8.hash(state);
+                elapsed.hash(state);
+            }
+            Canonical::Bounded(start) => {
+                1_u8.hash(state);
+                start.hash(state);
+            }
+            Canonical::Unbounded => 2_u8.hash(state),
+        }
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for SignedStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self { offset: 0, inner }
+    }
+}
+
+impl<I: Instant> ops::AddAssign<Duration> for SignedStopwatch<I> {
+    fn add_assign(&mut self, dur: Duration) {
+        self.add_nanos(duration_to_nanos(dur));
+    }
+}
+
+impl<I: Instant> ops::SubAssign<Duration> for SignedStopwatch<I> {
+    fn sub_assign(&mut self, dur: Duration) {
+        self.add_nanos(duration_to_nanos(dur).saturating_neg());
+    }
+}
+
+impl<I: Instant> ops::Add<Duration> for SignedStopwatch<I> {
+    type Output = Self;
+
+    fn add(mut self, dur: Duration) -> Self {
+        self += dur;
+        self
+    }
+}
+
+impl<I: Instant> ops::Sub<Duration> for SignedStopwatch<I> {
+    type Output = Self;
+
+    fn sub(mut self, dur: Duration) -> Self {
+        self -= dur;
+        self
+    }
+}
@@ -4,16 +4,35 @@
 
 use libsw_core::Stopwatch as CoreSw;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::iter;
 use core::ops;
 use core::time::Duration;
 
-use crate::{Error, Guard, Instant};
+use crate::{Elapsed, Error, Guard, GuardFn, GuardInto, Instant, SlaGuard};
 
 /// A stopwatch measures and accumulates elapsed time between starts and stops.
 ///
 /// Stopwatches work with any type that implements [`Instant`].
+///
+/// # Serialization
+///
+/// When the `serde` feature is enabled, stopwatches implement `Serialize` and
+/// `Deserialize`. Instants can't meaningfully be saved, so only the total
+/// elapsed time and whether the stopwatch is running are serialized:
+///
+/// - A running stopwatch's current segment is folded into the serialized
+///   elapsed time, measured at the time of serialization.
+///
+/// - A stopped stopwatch round-trips exactly.
+///
+/// - A running stopwatch deserializes as running, started at
+///   [`I::now()`](Instant::now). The time between serialization and
+///   deserialization isn't counted.
 #[derive(Clone, Copy)]
 #[allow(clippy::module_name_repetitions)]
 pub struct StopwatchImpl<I: Instant> {
@@ -21,6 +40,10 @@ pub struct StopwatchImpl<I: Instant> {
 }
 
 impl<I: Instant> StopwatchImpl<I> {
+    /// The number of times [`elapsed_precise`](Self::elapsed_precise) reads
+    /// the current time to estimate jitter.
+    pub const DEFAULT_JITTER_SAMPLES: u32 = 4;
+
     /// Returns a stopped stopwatch with zero elapsed time.
     ///
     /// # Examples
@@ -187,6 +210,69 @@ impl<I: Instant> StopwatchImpl<I> {
         self.inner.is_stopped()
     }
 
+    /// Returns the instant the stopwatch was last started, or [`None`] if it's
+    /// stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use std::time::Instant;
+    /// let now = Instant::now();
+    /// let sw = Sw::new_started_at(now);
+    /// assert_eq!(sw.start_time(), Some(now));
+    /// assert_eq!(Sw::new().start_time(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn start_time(&self) -> Option<I> {
+        self.inner.start
+    }
+
+    /// Returns `true` if the stopwatch is running and was started at or before
+    /// the current time, so it's accumulating elapsed time.
+    ///
+    /// See [`is_accumulating_at`](Self::is_accumulating_at) for details.
+    #[must_use]
+    pub fn is_accumulating(&self) -> bool {
+        self.is_accumulating_at(I::now())
+    }
+
+    /// Returns `true` if the stopwatch is running and was started at or before
+    /// `anchor`, so it's accumulating elapsed time as of `anchor`.
+    ///
+    /// A stopwatch [started](Self::start_at) at a future instant is running,
+    /// but doesn't accumulate elapsed time until that instant is reached. This
+    /// distinguishes such a scheduled start from a stopwatch which is actively
+    /// counting.
+    ///
+    /// # Notes
+    ///
+    /// Before the start instant, [`elapsed_at`](Self::elapsed_at) returns the
+    /// elapsed time accumulated before the stopwatch was started, since
+    /// `anchor` saturates to the start instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let now = Instant::now();
+    /// let sw = Sw::new_started_at(now + Duration::from_secs(3));
+    /// assert!(sw.is_running());
+    /// assert!(!sw.is_accumulating_at(now));
+    /// assert_eq!(sw.elapsed_at(now), Duration::ZERO);
+    /// assert!(sw.is_accumulating_at(now + Duration::from_secs(3)));
+    /// ```
+    #[must_use]
+    pub fn is_accumulating_at(&self, anchor: I) -> bool {
+        match self.inner.start {
+            Some(start) => start.saturating_duration_since(anchor) == Duration::ZERO,
+            None => false,
+        }
+    }
+
     /// Returns the total time elapsed. If overflow occurs, the elapsed time is
     /// saturated to [`Duration::MAX`].
     ///
@@ -204,6 +290,7 @@ impl<I: Instant> StopwatchImpl<I> {
     /// # }
     /// ```
     #[must_use]
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
     pub fn elapsed(&self) -> Duration {
         self.inner.elapsed()
     }
@@ -227,10 +314,42 @@ impl<I: Instant> StopwatchImpl<I> {
     /// assert!(sw_1.elapsed_at(anchor) == sw_2.elapsed_at(anchor));
     /// ```
     #[must_use]
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
     pub fn elapsed_at(&self, anchor: I) -> Duration {
         self.inner.elapsed_at(anchor)
     }
 
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`, along with whether `anchor` was clamped.
+    ///
+    /// Like [`elapsed_at`](Self::elapsed_at), `anchor` saturates to the last
+    /// instant the stopwatch was started. The returned flag is `true` if
+    /// `anchor` was earlier than that instant, such as an out-of-order
+    /// timestamp, and so the elapsed time doesn't reflect it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let sw = Sw::from_raw(Duration::from_secs(1), Some(start));
+    ///
+    /// let later = start + Duration::from_secs(1);
+    /// assert_eq!(sw.elapsed_at_checked_clamp(later), (Duration::from_secs(2), false));
+    ///
+    /// let earlier = start - Duration::from_secs(1);
+    /// assert_eq!(sw.elapsed_at_checked_clamp(earlier), (Duration::from_secs(1), true));
+    /// ```
+    #[must_use]
+    pub fn elapsed_at_checked_clamp(&self, anchor: I) -> (Duration, bool) {
+        let clamped = self.inner.start.map_or(false, |start| {
+            start.saturating_duration_since(anchor) != Duration::ZERO
+        });
+        (self.elapsed_at(anchor), clamped)
+    }
+
     /// Computes the total time elapsed. If overflow occurred, returns [`None`].
     ///
     /// # Examples
@@ -249,6 +368,7 @@ impl<I: Instant> StopwatchImpl<I> {
     /// # }
     /// ```
     #[must_use]
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
     pub fn checked_elapsed(&self) -> Option<Duration> {
         self.inner.checked_elapsed()
     }
@@ -265,239 +385,1178 @@ impl<I: Instant> StopwatchImpl<I> {
     /// See the documentation for [`checked_elapsed`](Self::checked_elapsed) for
     /// a related example.
     #[must_use]
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
     pub fn checked_elapsed_at(&self, anchor: I) -> Option<Duration> {
         self.inner.checked_elapsed_at(anchor)
     }
 
-    /// Starts measuring the time elapsed.
-    ///
-    /// # Errors
+    /// Returns the time elapsed since the stopwatch was last started, or
+    /// [`None`] if it's stopped.
     ///
-    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    /// Unlike [`elapsed`](Self::elapsed), time accumulated before the last
+    /// start isn't included.
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
     /// # use core::time::Duration;
-    /// # use std::thread;
-    /// let mut sw = Sw::new();
-    /// assert!(sw.start().is_ok());
-    /// assert!(sw.start().is_err());
-    ///
-    /// let then = sw.elapsed();
-    /// thread::sleep(Duration::from_millis(100));
-    /// let now = sw.elapsed();
-    /// assert!(then != now);
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let sw = Sw::from_raw(Duration::from_secs(60), Some(start));
+    /// let now = start + Duration::from_secs(5);
+    /// assert_eq!(sw.current_segment_at(now), Some(Duration::from_secs(5)));
+    /// assert_eq!(sw.elapsed_at(now), Duration::from_secs(65));
+    /// assert_eq!(Sw::new().current_segment(), None);
     /// ```
-    pub fn start(&mut self) -> crate::Result<()> {
-        self.start_at(I::now())
+    #[must_use]
+    pub fn current_segment(&self) -> Option<Duration> {
+        self.current_segment_at(I::now())
     }
 
-    /// Stops measuring the time elapsed since the last start.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    /// Returns the time elapsed since the stopwatch was last started,
+    /// measured as if the current time were `anchor`, or [`None`] if it's
+    /// stopped.
     ///
     /// # Notes
     ///
-    /// Overflows of the new elapsed time are saturated to [`Duration::MAX`].
-    /// Use [`StopwatchImpl::checked_stop`] to explicitly check for overflow.
+    /// `anchor` saturates to the last instant the stopwatch was started, so
+    /// the segment is zero if `anchor` predates the start.
+    #[must_use]
+    pub fn current_segment_at(&self, anchor: I) -> Option<Duration> {
+        self.inner
+            .start
+            .map(|start| anchor.saturating_duration_since(start))
+    }
+
+    /// Returns the total time elapsed, in seconds as an [`f64`]. If overflow
+    /// occurs, the elapsed time is saturated to [`Duration::MAX`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
     /// # use core::time::Duration;
-    /// # use std::thread;
-    /// let mut sw = Sw::new_started();
-    /// assert!(sw.stop().is_ok());
-    /// assert!(sw.stop().is_err());
-    ///
-    /// let then = sw.elapsed();
-    /// thread::sleep(Duration::from_millis(100));
-    /// let now = sw.elapsed();
-    /// assert!(then == now);
+    /// let sw = Sw::with_elapsed(Duration::from_millis(2500));
+    /// assert_eq!(sw.elapsed_secs_f64(), 2.5);
     /// ```
-    #[inline]
-    pub fn stop(&mut self) -> crate::Result<()> {
-        self.stop_at(I::now())
+    #[must_use]
+    pub fn elapsed_secs_f64(&self) -> f64 {
+        self.elapsed().as_secs_f64()
     }
 
-    /// Starts measuring the time elapsed as if the current time were `anchor`.
+    /// Returns the total time elapsed, in seconds as an [`f64`], measured as if
+    /// the current time were `anchor`. If overflow occurs, the elapsed time is
+    /// saturated to [`Duration::MAX`].
     ///
-    /// # Errors
+    /// # Notes
     ///
-    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn elapsed_secs_f64_at(&self, anchor: I) -> f64 {
+        self.elapsed_at(anchor).as_secs_f64()
+    }
+
+    /// Returns the total time elapsed, in milliseconds as an [`f64`]. If
+    /// overflow occurs, the elapsed time is saturated to [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_micros(2500));
+    /// assert_eq!(sw.elapsed_millis_f64(), 2.5);
+    /// ```
+    #[must_use]
+    pub fn elapsed_millis_f64(&self) -> f64 {
+        millis_f64(self.elapsed())
+    }
+
+    /// Returns the total time elapsed, in milliseconds as an [`f64`], measured
+    /// as if the current time were `anchor`. If overflow occurs, the elapsed
+    /// time is saturated to [`Duration::MAX`].
     ///
     /// # Notes
     ///
-    /// If `anchor` is in the future, [`elapsed`](Self::elapsed) will return
-    /// [`Duration::ZERO`] until the current time catches up to it.
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn elapsed_millis_f64_at(&self, anchor: I) -> f64 {
+        millis_f64(self.elapsed_at(anchor))
+    }
+
+    /// Computes the total time elapsed, in seconds as an [`f64`]. If overflow
+    /// occurred, returns [`None`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
     /// # use core::time::Duration;
-    /// # use std::thread;
     /// # use std::time::Instant;
-    /// # fn main() -> libsw::Result<()> {
-    /// let mut sw_1 = Sw::new();
-    /// let mut sw_2 = Sw::new();
+    /// let sw = Sw::with_elapsed(Duration::from_millis(2500));
+    /// assert_eq!(sw.checked_elapsed_secs_f64(), Some(2.5));
     ///
     /// let start = Instant::now();
-    /// // off to the races! at the same time!
-    /// sw_1.start_at(start)?;
-    /// sw_2.start_at(start)?;
+    /// let sw = Sw::from_raw(Duration::MAX, Some(start));
+    /// let later = start + Duration::from_secs(1);
+    /// assert_eq!(sw.checked_elapsed_secs_f64_at(later), None);
+    /// ```
+    #[must_use]
+    pub fn checked_elapsed_secs_f64(&self) -> Option<f64> {
+        self.checked_elapsed().map(|elapsed| elapsed.as_secs_f64())
+    }
+
+    /// Computes the total time elapsed, in seconds as an [`f64`], measured as
+    /// if the current time were `anchor`. If overflow occurred, returns
+    /// [`None`].
     ///
-    /// thread::sleep(Duration::from_millis(100));
-    /// let anchor = Instant::now();
+    /// # Notes
     ///
-    /// assert_eq!(sw_1.elapsed_at(anchor), sw_2.elapsed_at(anchor)); // 'twas a tie
-    /// assert!(sw_1.elapsed_at(anchor) >= Duration::from_millis(100));
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
-        if self.is_stopped() {
-            self.inner.start_at(anchor);
-            Ok(())
-        } else {
-            Err(Error::SwStart)
-        }
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn checked_elapsed_secs_f64_at(&self, anchor: I) -> Option<f64> {
+        self.checked_elapsed_at(anchor)
+            .map(|elapsed| elapsed.as_secs_f64())
     }
 
-    /// Stops measuring the time elapsed since the last start as if the current
-    /// time were `anchor`.
+    /// Computes the total time elapsed, in milliseconds as an [`f64`]. If
+    /// overflow occurred, returns [`None`].
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    /// See the documentation for
+    /// [`checked_elapsed_secs_f64`](Self::checked_elapsed_secs_f64) for a
+    /// related example.
+    #[must_use]
+    pub fn checked_elapsed_millis_f64(&self) -> Option<f64> {
+        self.checked_elapsed().map(millis_f64)
+    }
+
+    /// Computes the total time elapsed, in milliseconds as an [`f64`], measured
+    /// as if the current time were `anchor`. If overflow occurred, returns
+    /// [`None`].
     ///
     /// # Notes
     ///
-    /// - If `anchor` is earlier than the last start, there is no effect on the
-    ///   elapsed time.
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn checked_elapsed_millis_f64_at(&self, anchor: I) -> Option<f64> {
+        self.checked_elapsed_at(anchor).map(millis_f64)
+    }
+
+    /// Returns the fraction of `target` which has elapsed, clamped to the
+    /// range `0.0..=1.0`.
     ///
-    /// - Overflows of the new elapsed time are saturated to [`Duration::MAX`].
-    ///   Use [`StopwatchImpl::checked_stop_at`] to explicitly check for overflow.
+    /// # Notes
+    ///
+    /// A `target` of [`Duration::ZERO`] is always reached, so returns `1.0`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
     /// # use core::time::Duration;
-    /// # use std::thread;
-    /// # use std::time::Instant;
-    /// # fn main() -> libsw::Result<()> {
-    /// let mut sw_1 = Sw::new_started();
-    /// let mut sw_2 = sw_1.clone();
-    /// let stop = Instant::now();
-    /// sw_1.stop_at(stop)?;
-    /// sw_2.stop_at(stop)?;
-    /// assert_eq!(sw_1, sw_2);
-    /// # Ok(())
-    /// # }
+    /// let sw = Sw::with_elapsed(Duration::from_secs(1));
+    /// assert_eq!(sw.progress(Duration::from_secs(4)), 0.25);
+    /// assert_eq!(sw.progress(Duration::from_millis(500)), 1.0);
+    /// assert_eq!(sw.progress(Duration::ZERO), 1.0);
     /// ```
-    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
-        if self.is_running() {
-            self.inner.stop_at(anchor);
-            Ok(())
+    #[must_use]
+    pub fn progress(&self, target: Duration) -> f64 {
+        self.progress_at(target, I::now())
+    }
+
+    /// Returns the fraction of `target` which has elapsed, measured as if the
+    /// current time were `anchor`, clamped to the range `0.0..=1.0`.
+    ///
+    /// # Notes
+    ///
+    /// - A `target` of [`Duration::ZERO`] is always reached, so returns `1.0`.
+    ///
+    /// - `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn progress_at(&self, target: Duration, anchor: I) -> f64 {
+        let elapsed = self.elapsed_at(anchor);
+        if elapsed >= target {
+            1.0
         } else {
-            Err(Error::SwStop)
+            (elapsed.as_secs_f64() / target.as_secs_f64()).clamp(0.0, 1.0)
         }
     }
 
-    /// Tries to stop the stopwatch. If the new elapsed time overflows, returns
-    /// [`None`] without mutating the stopwatch.
-    ///
-    /// # Errors
+    /// Returns how much longer the stopwatch can run before its elapsed time
+    /// saturates to [`Duration::MAX`].
     ///
-    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    /// Returns [`None`] if the stopwatch is stopped, if its elapsed time is
+    /// already saturated, or if its clock can't represent the instant at which
+    /// it would saturate.
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
     /// # use core::time::Duration;
-    /// # fn main() -> libsw::Result<()> {
-    /// let mut sw = Sw::new_started();
-    /// assert!(sw.checked_stop()?.is_some());
-    /// sw.set(Duration::MAX);
-    /// sw.start()?;
-    /// assert!(sw.checked_stop()?.is_none());
-    /// # Ok(())
-    /// # }
+    /// let near_max = Duration::MAX.saturating_sub(Duration::from_secs(60));
+    /// let sw = Sw::with_elapsed_started(near_max);
+    /// let remaining = sw.time_until_saturation().unwrap();
+    /// assert!(remaining <= Duration::from_secs(60));
+    ///
+    /// assert_eq!(Sw::with_elapsed(near_max).time_until_saturation(), None);
     /// ```
-    pub fn checked_stop(&mut self) -> crate::Result<Option<()>> {
-        self.checked_stop_at(I::now())
+    #[must_use]
+    pub fn time_until_saturation(&self) -> Option<Duration> {
+        self.time_until_saturation_at(I::now())
     }
 
-    /* TODO: these checked fallible type signatures are silly yet consistent */
-    /// Tries to stop the stopwatch, as if the current time were `anchor`. If
-    /// the new elapsed time overflows, returns [`None`] without mutating the
-    /// stopwatch.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    /// Returns how much longer the stopwatch can run, from `anchor`, before
+    /// its elapsed time saturates to [`Duration::MAX`].
     ///
     /// # Notes
     ///
-    /// If `anchor` is earlier than the last start, there is no effect on the
-    /// elapsed time.
+    /// `anchor` saturates to the last instant the stopwatch was started. See
+    /// [`time_until_saturation`](Self::time_until_saturation) for when
+    /// [`None`] is returned.
     ///
     /// # Examples
     ///
-    /// See [`StopwatchImpl::checked_stop`] for comparable example usage.
-    pub fn checked_stop_at(&mut self, anchor: I) -> crate::Result<Option<()>> {
-        if self.is_running() {
-            let overflow: bool = !self.inner.checked_stop_at(anchor);
-            let flag = if overflow { None } else { Some(()) };
-            Ok(flag)
+    /// See the documentation for
+    /// [`time_until_saturation`](Self::time_until_saturation) for a related
+    /// example.
+    #[must_use]
+    pub fn time_until_saturation_at(&self, anchor: I) -> Option<Duration> {
+        let start = self.inner.start?;
+        let anchor = if anchor.saturating_duration_since(start) == Duration::ZERO {
+            start
         } else {
-            Err(Error::SwStop)
+            anchor
+        };
+        let remaining = Duration::MAX.checked_sub(self.checked_elapsed_at(anchor)?)?;
+        if remaining == Duration::ZERO {
+            return None;
         }
+        // if the clock can't reach the saturating instant, it never saturates
+        anchor.checked_add(remaining)?;
+        Some(remaining)
     }
 
-    /// Toggles whether the stopwatch is running or stopped.
+    /// Returns the absolute difference between the elapsed times of `self` and
+    /// `other`.
     ///
     /// # Notes
     ///
-    /// See [`stop`](Self::stop) for details about how overflow is handled.
+    /// Running stopwatches are measured at the same instant. Elapsed times
+    /// saturate as described in [`elapsed`](Self::elapsed).
     ///
     /// # Examples
     ///
     /// ```
     /// # use libsw::Sw;
-    /// let mut sw = Sw::new();
-    /// sw.toggle();
-    /// assert!(sw.is_running());
-    /// sw.toggle();
-    /// assert!(sw.is_stopped());
+    /// # use core::time::Duration;
+    /// let a = Sw::with_elapsed(Duration::from_secs(3));
+    /// let b = Sw::with_elapsed(Duration::from_secs(5));
+    /// assert_eq!(a.abs_diff(&b), Duration::from_secs(2));
+    /// assert_eq!(b.abs_diff(&a), Duration::from_secs(2));
     /// ```
-    pub fn toggle(&mut self) {
-        self.toggle_at(I::now());
+    #[must_use]
+    pub fn abs_diff(&self, other: &Self) -> Duration {
+        self.abs_diff_at(other, I::now())
     }
 
-    /// Toggles whether the stopwatch is running or stopped, as if the current
-    /// time were `anchor`.
+    /// Returns the absolute difference between the elapsed times of `self` and
+    /// `other`, measured as if the current time were `anchor`.
     ///
     /// # Notes
     ///
-    /// See [`start_at`](Self::start_at) and [`stop_at`](Self::stop_at) for
-    /// notes about the chronology of `anchor`, as well as what happens if
-    /// overflow occurs.
+    /// `anchor` saturates to the last instant each stopwatch was started.
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use libsw::Sw;
-    /// # use std::time::Instant;
-    /// let mut left = Sw::new();
-    /// let mut right = Sw::new_started();
+    /// See the documentation for [`abs_diff`](Self::abs_diff) for a related
+    /// example.
+    #[must_use]
+    pub fn abs_diff_at(&self, other: &Self, anchor: I) -> Duration {
+        let lhs = self.elapsed_at(anchor);
+        let rhs = other.elapsed_at(anchor);
+        lhs.saturating_sub(rhs).max(rhs.saturating_sub(lhs))
+    }
+
+    /// Compares the elapsed times of `self` and `other`, which may use
+    /// different [`Instant`] types.
+    ///
+    /// Each stopwatch is measured against its own clock's current time, so
+    /// only the accumulated elapsed times are compared.
+    ///
+    /// # Notes
+    ///
+    /// The two clocks are read one after the other, and may differ in
+    /// resolution, monotonicity, and how they behave across system suspend.
+    /// If both stopwatches are running, the result is only as meaningful as
+    /// the agreement between the two clocks. For stopwatches sharing an
+    /// `Instant` type, prefer [`abs_diff_at`](Self::abs_diff_at) or comparing
+    /// [`elapsed_at`](Self::elapsed_at) with a single anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Sw, SystemSw};
+    /// # use core::cmp::Ordering;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(3));
+    /// let system = SystemSw::with_elapsed(Duration::from_secs(5));
+    /// assert_eq!(sw.elapsed_cmp(&system), Ordering::Less);
+    /// assert_eq!(system.elapsed_cmp(&sw), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn elapsed_cmp<J: Instant>(&self, other: &StopwatchImpl<J>) -> Ordering {
+        self.elapsed().cmp(&other.elapsed())
+    }
+
+    /// Compares only the elapsed times of `self` and `other`, both measured as
+    /// if the current time were `anchor`.
+    ///
+    /// Measuring both stopwatches at one instant makes the comparison
+    /// consistent, such as when deciding which of two running stopwatches is
+    /// ahead. Unlike [`cmp_at`](Self::cmp_at), whether the stopwatches are
+    /// running isn't considered.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant each stopwatch was started. If
+    /// `anchor` predates a stopwatch's start, that stopwatch is measured with
+    /// only the time it had accumulated before it was started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::cmp::Ordering;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let a = Sw::new_started_at(start);
+    /// let b = Sw::new_started_at(start);
+    /// let anchor = start + Duration::from_secs(1);
+    /// assert_eq!(a.cmp_elapsed_at(&b, anchor), Ordering::Equal);
+    ///
+    /// let stopped = Sw::with_elapsed(Duration::from_secs(1));
+    /// assert_eq!(a.cmp_elapsed_at(&stopped, anchor), Ordering::Equal);
+    /// ```
+    #[must_use]
+    pub fn cmp_elapsed_at(&self, other: &Self, anchor: I) -> Ordering {
+        self.elapsed_at(anchor).cmp(&other.elapsed_at(anchor))
+    }
+
+    /// Compares `self` and `other` by elapsed time, measured as if the current
+    /// time were `anchor`.
+    ///
+    /// This is the deterministic counterpart to the [`Ord`] implementation,
+    /// which measures both stopwatches at the time of comparison.
+    ///
+    /// # Notes
+    ///
+    /// - Stopwatches which are [equal](PartialEq) compare as
+    ///   [`Equal`](Ordering::Equal).
+    ///
+    /// - Otherwise, if their elapsed times are equal, a stopped stopwatch is
+    ///   ordered before a running one.
+    ///
+    /// - `anchor` saturates to the last instant each stopwatch was started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::cmp::Ordering;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let a = Sw::new_started_at(start);
+    /// let b = Sw::with_elapsed(Duration::from_secs(1));
+    /// let anchor = start + Duration::from_secs(2);
+    /// assert_eq!(a.cmp_at(&b, anchor), Ordering::Greater);
+    /// assert_eq!(a.cmp_at(&b, start), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn cmp_at(&self, other: &Self, anchor: I) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        self.elapsed_at(anchor)
+            .cmp(&other.elapsed_at(anchor))
+            .then(self.is_running().cmp(&other.is_running()))
+    }
+
+    /// Compares the elapsed time of `self` to `dur`, measured as if the current
+    /// time were `anchor`.
+    ///
+    /// This is the deterministic counterpart to comparing a stopwatch to a
+    /// [`Duration`] with operators such as `>`, which measure a running
+    /// stopwatch at the time of comparison.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::cmp::Ordering;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let sw = Sw::new_started_at(start);
+    /// let limit = Duration::from_secs(1);
+    /// assert_eq!(sw.cmp_duration_at(limit, start), Ordering::Less);
+    /// let anchor = start + Duration::from_secs(2);
+    /// assert_eq!(sw.cmp_duration_at(limit, anchor), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn cmp_duration_at(&self, dur: Duration, anchor: I) -> Ordering {
+        self.elapsed_at(anchor).cmp(&dur)
+    }
+
+    /// Returns the total time elapsed, along with whether it was saturated to
+    /// [`Duration::MAX`].
+    ///
+    /// This combines [`elapsed`](Self::elapsed) and
+    /// [`checked_elapsed`](Self::checked_elapsed) with a single read of the
+    /// current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let sw = Sw::with_elapsed_started(Duration::MAX);
+    /// thread::sleep(Duration::from_millis(10));
+    /// let elapsed = sw.elapsed_full();
+    /// assert!(elapsed.saturated);
+    /// assert_eq!(*elapsed, Duration::MAX);
+    /// ```
+    #[must_use]
+    pub fn elapsed_full(&self) -> Elapsed {
+        self.elapsed_full_at(I::now())
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`, along with whether it was saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`elapsed_full`](Self::elapsed_full) for a
+    /// related example.
+    #[must_use]
+    pub fn elapsed_full_at(&self, anchor: I) -> Elapsed {
+        Elapsed::from_checked(self.checked_elapsed_at(anchor))
+    }
+
+    /// Returns the total time elapsed, saturating to [`Duration::MAX`], and
+    /// `true` if it was saturated.
+    ///
+    /// Same as converting the result of [`elapsed_full`](Self::elapsed_full)
+    /// into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::MAX);
+    /// // a genuine maximum is not saturated
+    /// assert_eq!(sw.elapsed_checked_flagged(), (Duration::MAX, false));
+    /// ```
+    #[must_use]
+    pub fn elapsed_checked_flagged(&self) -> (Duration, bool) {
+        self.elapsed_full().into()
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`, saturating to [`Duration::MAX`], and `true` if it was
+    /// saturated.
+    ///
+    /// Same as converting the result of
+    /// [`elapsed_full_at`](Self::elapsed_full_at) into a tuple.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for
+    /// [`elapsed_checked_flagged`](Self::elapsed_checked_flagged) for a
+    /// related example.
+    #[must_use]
+    pub fn elapsed_checked_flagged_at(&self, anchor: I) -> (Duration, bool) {
+        self.elapsed_full_at(anchor).into()
+    }
+
+    /// Returns the total time elapsed, along with an estimate of the jitter of
+    /// reading the current time.
+    ///
+    /// The jitter is estimated by reading [`I::now()`](Instant::now)
+    /// [`DEFAULT_JITTER_SAMPLES`](Self::DEFAULT_JITTER_SAMPLES) times in quick
+    /// succession, and taking the largest difference between consecutive
+    /// reads. Use [`elapsed_precise_sampled`](Self::elapsed_precise_sampled)
+    /// to choose the number of samples.
+    ///
+    /// # Notes
+    ///
+    /// The elapsed time is measured at the last sample, and saturates as
+    /// described in [`elapsed`](Self::elapsed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(1));
+    /// let (elapsed, jitter) = sw.elapsed_precise();
+    /// assert_eq!(elapsed, Duration::from_secs(1));
+    /// assert!(jitter < Duration::from_secs(1));
+    /// ```
+    #[must_use]
+    pub fn elapsed_precise(&self) -> (Duration, Duration) {
+        self.elapsed_precise_sampled(Self::DEFAULT_JITTER_SAMPLES)
+    }
+
+    /// Returns the total time elapsed, along with an estimate of the jitter of
+    /// reading the current time, sampling the clock `samples` times.
+    ///
+    /// # Notes
+    ///
+    /// `samples` is clamped to be at least 2. See
+    /// [`elapsed_precise`](Self::elapsed_precise) for more details.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`elapsed_precise`](Self::elapsed_precise)
+    /// for a related example.
+    #[must_use]
+    pub fn elapsed_precise_sampled(&self, samples: u32) -> (Duration, Duration) {
+        let mut prev = I::now();
+        let mut jitter = Duration::ZERO;
+        for _ in 1..samples.max(2) {
+            let now = I::now();
+            jitter = jitter.max(now.saturating_duration_since(prev));
+            prev = now;
+        }
+        (self.elapsed_at(prev), jitter)
+    }
+
+    /// Returns the number of whole `unit`s of time elapsed.
+    ///
+    /// This is useful for measuring elapsed time in domain-specific units, such
+    /// as frames at a fixed frame rate.
+    ///
+    /// # Notes
+    ///
+    /// - The result is truncated towards zero, so partially elapsed units are
+    ///   not counted.
+    ///
+    /// - If the result exceeds [`u64::MAX`], it is saturated.
+    ///
+    /// - The elapsed time saturates as described in [`elapsed`](Self::elapsed).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// // 60 frames per second
+    /// let frame = Duration::from_nanos(16_666_667);
+    /// let sw = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert_eq!(sw.elapsed_in_units(frame), 119);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn elapsed_in_units(&self, unit: Duration) -> u64 {
+        self.elapsed_in_units_at(unit, I::now())
+    }
+
+    /// Returns the number of whole `unit`s of time elapsed, measured as if the
+    /// current time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// See [`elapsed_in_units`](Self::elapsed_in_units) for details about
+    /// truncation and saturation. `anchor` saturates to the last instant the
+    /// stopwatch was started.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`elapsed_in_units`](Self::elapsed_in_units)
+    /// for a related example.
+    #[must_use]
+    #[track_caller]
+    pub fn elapsed_in_units_at(&self, unit: Duration, anchor: I) -> u64 {
+        assert!(unit != Duration::ZERO, "unit of time must be non-zero");
+        let units = self.elapsed_at(anchor).as_nanos() / unit.as_nanos();
+        u64::try_from(units).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the total number of whole milliseconds elapsed.
+    ///
+    /// This is a compatibility alias matching the shape of `chrono`'s
+    /// `Duration::num_milliseconds`, to ease migrating from `chrono`.
+    ///
+    /// # Notes
+    ///
+    /// The elapsed time is never negative. If the result exceeds
+    /// [`i64::MAX`], it is saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_micros(2_500_999));
+    /// assert_eq!(sw.num_milliseconds(), 2500);
+    /// ```
+    #[must_use]
+    pub fn num_milliseconds(&self) -> i64 {
+        i64::try_from(self.elapsed().as_millis()).unwrap_or(i64::MAX)
+    }
+
+    /// Returns the total number of whole seconds elapsed.
+    ///
+    /// This is a compatibility alias matching the shape of `chrono`'s
+    /// `Duration::num_seconds`, to ease migrating from `chrono`.
+    ///
+    /// # Notes
+    ///
+    /// The elapsed time is never negative. If the result exceeds
+    /// [`i64::MAX`], it is saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_millis(2999));
+    /// assert_eq!(sw.num_seconds(), 2);
+    /// ```
+    #[must_use]
+    pub fn num_seconds(&self) -> i64 {
+        i64::try_from(self.elapsed().as_secs()).unwrap_or(i64::MAX)
+    }
+
+    /// Returns the total number of whole milliseconds elapsed, saturating to
+    /// [`u32::MAX`].
+    ///
+    /// This is convenient for embedded timer APIs which take `u32`
+    /// milliseconds. `u32::MAX` milliseconds is about 49.7 days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_micros(1999));
+    /// assert_eq!(sw.elapsed_millis_u32_saturating(), 1);
+    ///
+    /// let sw = Sw::with_elapsed(Duration::from_secs(60 * 60 * 24 * 50));
+    /// assert_eq!(sw.elapsed_millis_u32_saturating(), u32::MAX);
+    /// ```
+    #[must_use]
+    pub fn elapsed_millis_u32_saturating(&self) -> u32 {
+        u32::try_from(self.elapsed().as_millis()).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the total time elapsed, clamped to the range `min..=max`.
+    ///
+    /// This is useful for displaying progress which shouldn't fall below a
+    /// minimum or exceed a maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like [`Ord::clamp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let min = Duration::from_secs(1);
+    /// let max = Duration::from_secs(10);
+    /// let sw = Sw::with_elapsed(Duration::from_secs(30));
+    /// assert_eq!(sw.elapsed_clamped_range(min, max), max);
+    /// assert_eq!(Sw::new().elapsed_clamped_range(min, max), min);
+    /// ```
+    #[must_use]
+    pub fn elapsed_clamped_range(&self, min: Duration, max: Duration) -> Duration {
+        self.elapsed_clamped_range_at(min, max, I::now())
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`, clamped to the range `min..=max`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like [`Ord::clamp`].
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for
+    /// [`elapsed_clamped_range`](Self::elapsed_clamped_range) for a related
+    /// example.
+    #[must_use]
+    pub fn elapsed_clamped_range_at(&self, min: Duration, max: Duration, anchor: I) -> Duration {
+        self.elapsed_at(anchor).clamp(min, max)
+    }
+
+    /// Returns `true` if the total time elapsed exceeds `baseline` by more
+    /// than `allowed_slack`.
+    ///
+    /// This is useful for detecting performance regressions against a saved
+    /// baseline.
+    ///
+    /// # Notes
+    ///
+    /// `baseline + allowed_slack` saturates to [`Duration::MAX`], so a
+    /// stopwatch can't regress from a saturated limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let baseline = Duration::from_millis(100);
+    /// let slack = Duration::from_millis(10);
+    /// let fast = Sw::with_elapsed(Duration::from_millis(105));
+    /// let slow = Sw::with_elapsed(Duration::from_millis(120));
+    /// assert!(!fast.regressed_from(baseline, slack));
+    /// assert!(slow.regressed_from(baseline, slack));
+    /// ```
+    #[must_use]
+    pub fn regressed_from(&self, baseline: Duration, allowed_slack: Duration) -> bool {
+        self.regressed_from_at(baseline, allowed_slack, I::now())
+    }
+
+    /// Returns `true` if the total time elapsed, measured as if the current
+    /// time were `anchor`, exceeds `baseline` by more than `allowed_slack`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`regressed_from`](Self::regressed_from) for
+    /// a related example.
+    #[must_use]
+    pub fn regressed_from_at(
+        &self,
+        baseline: Duration,
+        allowed_slack: Duration,
+        anchor: I,
+    ) -> bool {
+        self.elapsed_at(anchor) > baseline.saturating_add(allowed_slack)
+    }
+
+    /// Starts measuring the time elapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sw = Sw::new();
+    /// assert!(sw.start().is_ok());
+    /// assert!(sw.start().is_err());
+    ///
+    /// let then = sw.elapsed();
+    /// thread::sleep(Duration::from_millis(100));
+    /// let now = sw.elapsed();
+    /// assert!(then != now);
+    /// ```
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.start_at(I::now())
+    }
+
+    /// Stops measuring the time elapsed since the last start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// # Notes
+    ///
+    /// Overflows of the new elapsed time are saturated to [`Duration::MAX`].
+    /// Use [`StopwatchImpl::checked_stop`] to explicitly check for overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sw = Sw::new_started();
+    /// assert!(sw.stop().is_ok());
+    /// assert!(sw.stop().is_err());
+    ///
+    /// let then = sw.elapsed();
+    /// thread::sleep(Duration::from_millis(100));
+    /// let now = sw.elapsed();
+    /// assert!(then == now);
+    /// ```
+    #[cfg_attr(not(feature = "no_inline_elapsed"), inline)]
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.stop_at(I::now())
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// If `anchor` is in the future, [`elapsed`](Self::elapsed) will return
+    /// [`Duration::ZERO`] until the current time catches up to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// # use std::time::Instant;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut sw_1 = Sw::new();
+    /// let mut sw_2 = Sw::new();
+    ///
+    /// let start = Instant::now();
+    /// // off to the races! at the same time!
+    /// sw_1.start_at(start)?;
+    /// sw_2.start_at(start)?;
+    ///
+    /// thread::sleep(Duration::from_millis(100));
+    /// let anchor = Instant::now();
+    ///
+    /// assert_eq!(sw_1.elapsed_at(anchor), sw_2.elapsed_at(anchor)); // 'twas a tie
+    /// assert!(sw_1.elapsed_at(anchor) >= Duration::from_millis(100));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        if self.is_stopped() {
+            self.inner.start_at(anchor);
+            Ok(())
+        } else {
+            Err(Error::SwStart)
+        }
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`,
+    /// checking that `anchor` isn't so far in the past that the elapsed time
+    /// would immediately overflow.
+    ///
+    /// This protects against pathological anchors, such as those from untrusted
+    /// input.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`SwStart`](Error::SwStart) if the stopwatch is running.
+    ///
+    /// - Returns [`Overflow`](Error::Overflow) if the current elapsed time plus
+    ///   the time since `anchor` (measured at [`I::now()`](Instant::now))
+    ///   exceeds [`Duration::MAX`]. The stopwatch is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Error, Sw};
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// # fn main() -> libsw::Result<()> {
+    /// let past = Instant::now() - Duration::from_secs(1);
+    ///
+    /// let mut sw = Sw::new();
+    /// sw.checked_start_at_past(past)?;
+    /// assert!(sw.elapsed() >= Duration::from_secs(1));
+    ///
+    /// let mut sw = Sw::with_elapsed(Duration::MAX);
+    /// assert_eq!(sw.checked_start_at_past(past), Err(Error::Overflow));
+    /// assert!(sw.is_stopped());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_start_at_past(&mut self, anchor: I) -> crate::Result<()> {
+        if self.is_running() {
+            return Err(Error::SwStart);
+        }
+        let since_anchor = I::now().saturating_duration_since(anchor);
+        if self.inner.elapsed.checked_add(since_anchor).is_none() {
+            return Err(Error::Overflow);
+        }
+        self.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed since the last start as if the current
+    /// time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// # Notes
+    ///
+    /// - If `anchor` is earlier than the last start, there is no effect on the
+    ///   elapsed time.
+    ///
+    /// - Overflows of the new elapsed time are saturated to [`Duration::MAX`].
+    ///   Use [`StopwatchImpl::checked_stop_at`] to explicitly check for overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// # use std::time::Instant;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut sw_1 = Sw::new_started();
+    /// let mut sw_2 = sw_1.clone();
+    /// let stop = Instant::now();
+    /// sw_1.stop_at(stop)?;
+    /// sw_2.stop_at(stop)?;
+    /// assert_eq!(sw_1, sw_2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "no_inline_elapsed", inline(never))]
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        if self.is_running() {
+            self.inner.stop_at(anchor);
+            Ok(())
+        } else {
+            Err(Error::SwStop)
+        }
+    }
+
+    /// Tries to stop the stopwatch. If the new elapsed time overflows, returns
+    /// [`None`] without mutating the stopwatch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # fn main() -> libsw::Result<()> {
+    /// let mut sw = Sw::new_started();
+    /// assert!(sw.checked_stop()?.is_some());
+    /// sw.set(Duration::MAX);
+    /// sw.start()?;
+    /// assert!(sw.checked_stop()?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_stop(&mut self) -> crate::Result<Option<()>> {
+        self.checked_stop_at(I::now())
+    }
+
+    /* TODO: these checked fallible type signatures are silly yet consistent */
+    /// Tries to stop the stopwatch, as if the current time were `anchor`. If
+    /// the new elapsed time overflows, returns [`None`] without mutating the
+    /// stopwatch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped.
+    ///
+    /// # Notes
+    ///
+    /// If `anchor` is earlier than the last start, there is no effect on the
+    /// elapsed time.
+    ///
+    /// # Examples
+    ///
+    /// See [`StopwatchImpl::checked_stop`] for comparable example usage.
+    pub fn checked_stop_at(&mut self, anchor: I) -> crate::Result<Option<()>> {
+        if self.is_running() {
+            let overflow: bool = !self.inner.checked_stop_at(anchor);
+            let flag = if overflow { None } else { Some(()) };
+            Ok(flag)
+        } else {
+            Err(Error::SwStop)
+        }
+    }
+
+    /// Starts each stopwatch in `sws` at the same instant, reading the current
+    /// time once. Returns the result of starting each stopwatch, in order.
+    ///
+    /// This avoids the skew of reading the current time separately for each
+    /// stopwatch, so their elapsed times can be compared fairly.
+    ///
+    /// # Notes
+    ///
+    /// Stopwatches which are already running are left unchanged, and their
+    /// results are [`SwStart`](Error::SwStart), as with
+    /// [`start`](Self::start).
+    ///
+    /// Without an allocator, call [`start_at`](Self::start_at) on each
+    /// stopwatch with a shared anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Error, Sw};
+    /// let mut sws = vec![Sw::new(), Sw::new_started(), Sw::new()];
+    /// assert_eq!(Sw::start_all(&mut sws), [Ok(()), Err(Error::SwStart), Ok(())]);
+    /// assert!(sws.iter().all(Sw::is_running));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn start_all(sws: &mut [Self]) -> Vec<crate::Result<()>> {
+        Self::start_all_at(sws, I::now())
+    }
+
+    /// Starts each stopwatch in `sws` as if the current time were `anchor`.
+    /// Returns the result of starting each stopwatch, in order.
+    ///
+    /// # Notes
+    ///
+    /// See [`start_all`](Self::start_all) for notes about stopwatches which
+    /// are already running, and [`start_at`](Self::start_at) for notes about
+    /// the chronology of `anchor`.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`stop_all_at`](Self::stop_all_at) for a
+    /// related example.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn start_all_at(sws: &mut [Self], anchor: I) -> Vec<crate::Result<()>> {
+        sws.iter_mut().map(|sw| sw.start_at(anchor)).collect()
+    }
+
+    /// Stops each stopwatch in `sws` at the same instant, reading the current
+    /// time once. Returns the result of stopping each stopwatch, in order.
+    ///
+    /// This avoids the skew of reading the current time separately for each
+    /// stopwatch, so their elapsed times can be compared fairly.
+    ///
+    /// # Notes
+    ///
+    /// Stopwatches which are already stopped are left unchanged, and their
+    /// results are [`SwStop`](Error::SwStop), as with [`stop`](Self::stop).
+    ///
+    /// Without an allocator, call [`stop_at`](Self::stop_at) on each
+    /// stopwatch with a shared anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Error, Sw};
+    /// let mut sws = [Sw::new_started(), Sw::new_started(), Sw::new()];
+    /// assert_eq!(Sw::stop_all(&mut sws), [Ok(()), Ok(()), Err(Error::SwStop)]);
+    /// assert!(sws.iter().all(Sw::is_stopped));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn stop_all(sws: &mut [Self]) -> Vec<crate::Result<()>> {
+        Self::stop_all_at(sws, I::now())
+    }
+
+    /// Stops each stopwatch in `sws` as if the current time were `anchor`.
+    /// Returns the result of stopping each stopwatch, in order.
+    ///
+    /// # Notes
+    ///
+    /// See [`stop_all`](Self::stop_all) for notes about stopwatches which are
+    /// already stopped, and [`stop_at`](Self::stop_at) for notes about the
+    /// chronology of `anchor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// let mut sws = vec![Sw::new(), Sw::with_elapsed(Duration::from_secs(1)), Sw::new()];
+    /// // only the first two stopwatches
+    /// Sw::start_all_at(&mut sws[..2], start);
+    /// Sw::stop_all_at(&mut sws, start + Duration::from_secs(1));
+    /// assert_eq!(sws[0].elapsed(), Duration::from_secs(1));
+    /// assert_eq!(sws[1].elapsed(), Duration::from_secs(2));
+    /// assert_eq!(sws[2].elapsed(), Duration::ZERO);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn stop_all_at(sws: &mut [Self], anchor: I) -> Vec<crate::Result<()>> {
+        sws.iter_mut().map(|sw| sw.stop_at(anchor)).collect()
+    }
+
+    /// Toggles whether the stopwatch is running or stopped.
+    ///
+    /// # Notes
+    ///
+    /// See [`stop`](Self::stop) for details about how overflow is handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// let mut sw = Sw::new();
+    /// sw.toggle();
+    /// assert!(sw.is_running());
+    /// sw.toggle();
+    /// assert!(sw.is_stopped());
+    /// ```
+    pub fn toggle(&mut self) {
+        self.toggle_at(I::now());
+    }
+
+    /// Toggles whether the stopwatch is running or stopped, as if the current
+    /// time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// See [`start_at`](Self::start_at) and [`stop_at`](Self::stop_at) for
+    /// notes about the chronology of `anchor`, as well as what happens if
+    /// overflow occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use std::time::Instant;
+    /// let mut left = Sw::new();
+    /// let mut right = Sw::new_started();
     ///
     /// // perfect swap of left and right running
     /// let now = Instant::now();
@@ -547,6 +1606,128 @@ impl<I: Instant> StopwatchImpl<I> {
         }
     }
 
+    /// Tries to toggle every stopwatch in `sws`, all at the same instant. If
+    /// any new elapsed time overflows, returns the index of the first
+    /// stopwatch to overflow without mutating any of them.
+    ///
+    /// This is useful for swapping the states of many stopwatches in sync.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first stopwatch whose new elapsed time
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sws = [Sw::new(), Sw::new_started()];
+    /// assert_eq!(Sw::checked_toggle_all(&mut sws), Ok(()));
+    /// assert!(sws[0].is_running());
+    /// assert!(sws[1].is_stopped());
+    ///
+    /// let mut sws = [Sw::new(), Sw::with_elapsed_started(Duration::MAX)];
+    /// thread::sleep(Duration::from_millis(100));
+    /// assert_eq!(Sw::checked_toggle_all(&mut sws), Err(1));
+    /// assert!(sws[0].is_stopped());
+    /// ```
+    pub fn checked_toggle_all(sws: &mut [Self]) -> core::result::Result<(), usize> {
+        Self::checked_toggle_all_at(sws, I::now())
+    }
+
+    /// Tries to toggle every stopwatch in `sws` as if the current time were
+    /// `anchor`. If any new elapsed time overflows, returns the index of the
+    /// first stopwatch to overflow without mutating any of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first stopwatch whose new elapsed time
+    /// overflows.
+    ///
+    /// # Notes
+    ///
+    /// See [`checked_toggle_at`](Self::checked_toggle_at) for details about
+    /// toggling each stopwatch.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`checked_toggle_all`](Self::checked_toggle_all)
+    /// for a related example.
+    pub fn checked_toggle_all_at(sws: &mut [Self], anchor: I) -> core::result::Result<(), usize> {
+        // check every toggle succeeds before committing any of them
+        for (idx, sw) in sws.iter().enumerate() {
+            let mut sw = *sw;
+            if sw.checked_toggle_at(anchor).is_none() {
+                return Err(idx);
+            }
+        }
+        for sw in sws.iter_mut() {
+            sw.toggle_at(anchor);
+        }
+        Ok(())
+    }
+
+    /// Records a lap if the current segment has run for longer than
+    /// `threshold`, returning the length of the lap.
+    ///
+    /// The current segment is the time elapsed since the stopwatch was last
+    /// started. Recording a lap ends the current segment and immediately
+    /// begins a new one, so the total elapsed time is unaffected.
+    ///
+    /// If the stopwatch is stopped, or the segment has not exceeded
+    /// `threshold`, returns [`None`] without mutating the stopwatch.
+    ///
+    /// # Notes
+    ///
+    /// The lap's length is added to the elapsed time as described in
+    /// [`stop`](Self::stop), saturating to [`Duration::MAX`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sw = Sw::new_started();
+    /// assert_eq!(sw.auto_lap_if(Duration::from_secs(1)), None);
+    ///
+    /// thread::sleep(Duration::from_millis(100));
+    /// let lap = sw.auto_lap_if(Duration::from_millis(50)).unwrap();
+    /// assert!(lap >= Duration::from_millis(100));
+    /// assert!(sw.is_running());
+    /// assert!(sw.elapsed() >= lap);
+    /// ```
+    pub fn auto_lap_if(&mut self, threshold: Duration) -> Option<Duration> {
+        self.auto_lap_if_at(threshold, I::now())
+    }
+
+    /// Records a lap if the current segment has run for longer than
+    /// `threshold` as of `anchor`, returning the length of the lap.
+    ///
+    /// If a lap is recorded, the new segment begins at `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// If `anchor` is earlier than the last start, the segment is considered
+    /// empty and no lap is recorded.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`auto_lap_if`](Self::auto_lap_if) for a
+    /// related example.
+    pub fn auto_lap_if_at(&mut self, threshold: Duration, anchor: I) -> Option<Duration> {
+        let segment = anchor.saturating_duration_since(self.inner.start?);
+        if segment > threshold {
+            self.inner.stop_at(anchor);
+            self.inner.start_at(anchor);
+            Some(segment)
+        } else {
+            None
+        }
+    }
+
     /// Starts the stopwatch, returning a [`Guard`] which when dropped, will
     /// stop the stopwatch.
     ///
@@ -580,6 +1761,241 @@ impl<I: Instant> StopwatchImpl<I> {
         guard
     }
 
+    /// Starts the stopwatch, returning a [`GuardInto`] which when dropped, will
+    /// stop the stopwatch and add the time elapsed while guarded to `acc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Examples
+    ///
+    /// For examples on how to use `GuardInto`s, see the [struct
+    /// documentation](GuardInto).
+    pub fn guard_into<'acc>(
+        &mut self,
+        acc: &'acc mut Duration,
+    ) -> crate::Result<GuardInto<'_, 'acc, I>> {
+        self.guard_into_at(I::now(), acc)
+    }
+
+    /// Starts the stopwatch as if the current time were `anchor`, returning a
+    /// [`GuardInto`], which when dropped, will stop the stopwatch and add the
+    /// time elapsed while guarded to `acc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// For details about `anchor`, see [`start_at`](Self::start_at). For
+    /// examples on how to use `GuardInto`s, see the [struct
+    /// documentation](GuardInto).
+    pub fn guard_into_at<'acc>(
+        &mut self,
+        anchor: I,
+        acc: &'acc mut Duration,
+    ) -> crate::Result<GuardInto<'_, 'acc, I>> {
+        self.start_at(anchor).map_err(|_| Error::SwGuard)?;
+        let guard = GuardInto::new(self, acc);
+        debug_assert!(guard.is_ok());
+        guard
+    }
+
+    /// Starts the stopwatch, returning an [`SlaGuard`] which when dropped, will
+    /// stop the stopwatch and call `on_breach` if the time elapsed while
+    /// guarded exceeds `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Examples
+    ///
+    /// For examples on how to use `SlaGuard`s, see the [struct
+    /// documentation](SlaGuard).
+    pub fn sla_guard(
+        &mut self,
+        threshold: Duration,
+        on_breach: fn(Duration),
+    ) -> crate::Result<SlaGuard<'_, I>> {
+        self.sla_guard_at(I::now(), threshold, on_breach)
+    }
+
+    /// Starts the stopwatch as if the current time were `anchor`, returning an
+    /// [`SlaGuard`], which when dropped, will stop the stopwatch and call
+    /// `on_breach` if the time elapsed while guarded exceeds `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// For details about `anchor`, see [`start_at`](Self::start_at). For
+    /// examples on how to use `SlaGuard`s, see the [struct
+    /// documentation](SlaGuard).
+    pub fn sla_guard_at(
+        &mut self,
+        anchor: I,
+        threshold: Duration,
+        on_breach: fn(Duration),
+    ) -> crate::Result<SlaGuard<'_, I>> {
+        self.start_at(anchor).map_err(|_| Error::SwGuard)?;
+        let guard = SlaGuard::new(self, threshold, on_breach);
+        debug_assert!(guard.is_ok());
+        guard
+    }
+
+    /// Starts the stopwatch, returning a [`GuardFn`] which when dropped, will
+    /// stop the stopwatch and then call `f` with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Examples
+    ///
+    /// For examples on how to use `GuardFn`s, see the [struct
+    /// documentation](GuardFn).
+    pub fn guard_with<F: FnOnce(&mut Self)>(&mut self, f: F) -> crate::Result<GuardFn<'_, I, F>> {
+        self.guard_with_at(I::now(), f)
+    }
+
+    /// Starts the stopwatch as if the current time were `anchor`, returning a
+    /// [`GuardFn`], which when dropped, will stop the stopwatch and then call
+    /// `f` with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwGuard`](Error::SwGuard) if the stopwatch is running.
+    ///
+    /// # Notes
+    ///
+    /// For details about `anchor`, see [`start_at`](Self::start_at). For
+    /// examples on how to use `GuardFn`s, see the [struct
+    /// documentation](GuardFn).
+    pub fn guard_with_at<F: FnOnce(&mut Self)>(
+        &mut self,
+        anchor: I,
+        f: F,
+    ) -> crate::Result<GuardFn<'_, I, F>> {
+        self.start_at(anchor).map_err(|_| Error::SwGuard)?;
+        let guard = GuardFn::new(self, f);
+        debug_assert!(guard.is_ok());
+        guard
+    }
+
+    /// Calls `f`, returning its value and the time it took, measured with a
+    /// fresh stopwatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let (value, elapsed) = Sw::measure(|| {
+    ///     thread::sleep(Duration::from_millis(100));
+    ///     42
+    /// });
+    /// assert_eq!(value, 42);
+    /// assert!(elapsed >= Duration::from_millis(100));
+    /// ```
+    pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+        Self::new().measure_with(f)
+    }
+
+    /// Calls `f`, returning its value and the time it took, measured with a
+    /// fresh stopwatch. Same as calling [`measure`](Self::measure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let (value, elapsed) = Sw::time_fn(|| {
+    ///     thread::sleep(Duration::from_millis(100));
+    ///     42
+    /// });
+    /// assert_eq!(value, 42);
+    /// assert!(elapsed >= Duration::from_millis(100));
+    /// ```
+    pub fn time_fn<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+        Self::measure(f)
+    }
+
+    /// Calls `f`, returning its value and the time it took, and adds that
+    /// time to the stopwatch.
+    ///
+    /// # Notes
+    ///
+    /// If the stopwatch is running, it keeps running, since the time `f` took
+    /// is already being measured. Otherwise, the stopwatch is
+    /// [guarded](Self::guard) while `f` runs and remains stopped afterwards,
+    /// even if `f` panics, in which case the time until the panic is added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sw = Sw::new();
+    /// for _ in 0..3 {
+    ///     let ((), elapsed) = sw.measure_with(|| thread::sleep(Duration::from_millis(10)));
+    ///     assert!(elapsed >= Duration::from_millis(10));
+    /// }
+    /// assert!(sw.is_stopped());
+    /// assert!(sw.elapsed() >= Duration::from_millis(30));
+    /// ```
+    pub fn measure_with<T, F: FnOnce() -> T>(&mut self, f: F) -> (T, Duration) {
+        let start = I::now();
+        if self.is_running() {
+            let value = f();
+            return (value, I::now().saturating_duration_since(start));
+        }
+        self.inner.start_at(start);
+        // stops the stopwatch when dropped, including while unwinding
+        let mut guard = Guard::new_started(self);
+        let value = f();
+        let end = I::now();
+        _ = guard.pause_at(end);
+        (value, end.saturating_duration_since(start))
+    }
+
+    /// Calls `f`, returning its value, and adds the time it took to the
+    /// stopwatch.
+    ///
+    /// This is [`measure_with`](Self::measure_with) without the time `f` took.
+    ///
+    /// # Notes
+    ///
+    /// If the stopwatch is already running, it keeps running rather than
+    /// returning an error. Otherwise, it is stopped again once `f` returns,
+    /// including if `f` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let mut sw = Sw::new();
+    /// let value = sw.time(|| {
+    ///     thread::sleep(Duration::from_millis(100));
+    ///     42
+    /// });
+    /// assert_eq!(value, 42);
+    /// assert!(sw.is_stopped());
+    /// assert!(sw.elapsed() >= Duration::from_millis(100));
+    /// ```
+    pub fn time<T, F: FnOnce() -> T>(&mut self, f: F) -> T {
+        self.measure_with(f).0
+    }
+
     /// Stops and resets the elapsed time to zero.
     ///
     /// # Examples
@@ -636,6 +2052,47 @@ impl<I: Instant> StopwatchImpl<I> {
         self.inner.reset_in_place_at(start);
     }
 
+    /// Resets the elapsed time to zero and starts the stopwatch, whether or
+    /// not it was running.
+    ///
+    /// If the stopwatch is running, the time elapsed so far is discarded and
+    /// it keeps running from now, as if it were stopped, reset, and started
+    /// again. Unlike [`reset_in_place`](Self::reset_in_place), a stopped
+    /// stopwatch is also started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let mut sw = Sw::with_elapsed(Duration::from_secs(1));
+    /// for _ in 0..3 {
+    ///     sw.restart();
+    ///     assert!(sw.is_running());
+    ///     // new elapsed time is close to zero
+    ///     assert!(sw.elapsed() < Duration::from_millis(1));
+    /// }
+    /// ```
+    pub fn restart(&mut self) {
+        self.restart_at(I::now());
+    }
+
+    /// Resets the elapsed time to zero and starts the stopwatch as if the
+    /// current time were `anchor`, whether or not it was running.
+    ///
+    /// # Notes
+    ///
+    /// See [`start_at`](Self::start_at) for notes about the chronology of
+    /// `anchor`.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`restart`](Self::restart) for a related
+    /// example.
+    pub fn restart_at(&mut self, anchor: I) {
+        *self = Self::from_raw(Duration::ZERO, Some(anchor));
+    }
+
     /// Stops and sets the total elapsed time to `new`.
     ///
     /// # Examples
@@ -701,16 +2158,57 @@ impl<I: Instant> StopwatchImpl<I> {
     /// # use libsw::Sw;
     /// # use core::time::Duration;
     /// let mut sw = Sw::with_elapsed(Duration::from_secs(3));
-    /// let previous = sw.replace(Duration::from_secs(1));
-    /// assert_eq!(previous, Duration::from_secs(3));
-    /// assert_eq!(sw.elapsed(), Duration::from_secs(1));
+    /// let previous = sw.replace(Duration::from_secs(1));
+    /// assert_eq!(previous, Duration::from_secs(3));
+    /// assert_eq!(sw.elapsed(), Duration::from_secs(1));
+    /// ```
+    pub fn replace(&mut self, new: Duration) -> Duration {
+        self.inner.replace(new)
+    }
+
+    /// Stops and sets the total elapsed time to `new`, returning the previous
+    /// elapsed time as if the current time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// See [`elapsed_at`](Self::elapsed_at) for notes about the chronology of
+    /// `anchor`.
+    ///
+    /// # Examples
+    ///
+    /// See the documentation for [`replace`](Self::replace) for a related
+    /// example.
+    pub fn replace_at(&mut self, new: Duration, anchor: I) -> Duration {
+        self.inner.replace_at(new, anchor)
+    }
+
+    /// Sets the total elapsed time to `new` and whether the stopwatch is
+    /// running to `run`, returning the previous elapsed time and whether it
+    /// was running.
+    ///
+    /// If `run` is `true`, the stopwatch is started at
+    /// [`I::now()`](Instant::now).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let mut sw = Sw::with_elapsed(Duration::from_secs(3));
+    /// let previous = sw.replace_state(Duration::from_secs(1), true);
+    /// assert_eq!(previous, (Duration::from_secs(3), false));
+    /// assert!(sw.is_running());
+    /// assert!(sw.elapsed() >= Duration::from_secs(1));
     /// ```
-    pub fn replace(&mut self, new: Duration) -> Duration {
-        self.inner.replace(new)
+    pub fn replace_state(&mut self, new: Duration, run: bool) -> (Duration, bool) {
+        self.replace_state_at(new, run, I::now())
     }
 
-    /// Stops and sets the total elapsed time to `new`, returning the previous
-    /// elapsed time as if the current time were `anchor`.
+    /// Sets the total elapsed time to `new` and whether the stopwatch is
+    /// running to `run`, returning the previous elapsed time, as if the
+    /// current time were `anchor`, and whether it was running.
+    ///
+    /// If `run` is `true`, the stopwatch is started at `anchor`.
     ///
     /// # Notes
     ///
@@ -719,10 +2217,12 @@ impl<I: Instant> StopwatchImpl<I> {
     ///
     /// # Examples
     ///
-    /// See the documentation for [`replace`](Self::replace) for a related
-    /// example.
-    pub fn replace_at(&mut self, new: Duration, anchor: I) -> Duration {
-        self.inner.replace_at(new, anchor)
+    /// See the documentation for [`replace_state`](Self::replace_state) for a
+    /// related example.
+    pub fn replace_state_at(&mut self, new: Duration, run: bool, anchor: I) -> (Duration, bool) {
+        let previous = (self.elapsed_at(anchor), self.is_running());
+        *self = Self::from_raw(new, if run { Some(anchor) } else { None });
+        previous
     }
 
     /// Adds `dur` to the total elapsed time. If overflow occurred, the total
@@ -880,6 +2380,123 @@ impl<I: Instant> StopwatchImpl<I> {
         self.inner = self.inner.checked_sub_at(dur, anchor)?;
         Some(self)
     }
+
+    /// Returns a stopped stopwatch whose elapsed time is the current elapsed
+    /// time of `self`, without mutating `self`.
+    ///
+    /// Unlike [`clone`](Clone::clone), the snapshot doesn't keep running, so
+    /// it compares equal to other stopped stopwatches with the same elapsed
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let sw = Sw::new_started();
+    /// thread::sleep(Duration::from_millis(100));
+    /// let snapshot = sw.snapshot();
+    /// assert!(sw.is_running());
+    /// assert!(snapshot.is_stopped());
+    /// assert!(snapshot.elapsed() >= Duration::from_millis(100));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> Self {
+        self.snapshot_at(I::now())
+    }
+
+    /// Returns a stopped stopwatch whose elapsed time is the elapsed time of
+    /// `self` measured as if the current time were `anchor`, without mutating
+    /// `self`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant the stopwatch was started.
+    #[must_use]
+    pub fn snapshot_at(&self, anchor: I) -> Self {
+        Self::with_elapsed(self.elapsed_at(anchor))
+    }
+
+    /// Returns a stopped stopwatch whose elapsed time is the sum of the
+    /// elapsed times of `self` and `other`. If overflow occurs, the elapsed
+    /// time is saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The current time is read once, and running stopwatches are both
+    /// measured at that instant. Use [`merge_at`](Self::merge_at) to choose
+    /// the instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let parse = Sw::with_elapsed(Duration::from_secs(1));
+    /// let render = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert_eq!(parse.merge(render), Sw::with_elapsed(Duration::from_secs(3)));
+    /// ```
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        self.merge_at(other, I::now())
+    }
+
+    /// Returns a stopped stopwatch whose elapsed time is the sum of the
+    /// elapsed times of `self` and `other`, measured as if the current time
+    /// were `anchor`. If overflow occurs, the elapsed time is saturated to
+    /// [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant each stopwatch was started.
+    #[must_use]
+    pub fn merge_at(self, other: Self, anchor: I) -> Self {
+        Self::with_elapsed(
+            self.elapsed_at(anchor)
+                .saturating_add(other.elapsed_at(anchor)),
+        )
+    }
+
+    /// Returns a stopped stopwatch whose elapsed time is the sum of the
+    /// elapsed times of `self` and `other`. If overflow occurs, returns
+    /// [`None`].
+    ///
+    /// # Notes
+    ///
+    /// The current time is read once, and running stopwatches are both
+    /// measured at that instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(1));
+    /// assert_eq!(sw.checked_merge(sw), Some(Sw::with_elapsed(Duration::from_secs(2))));
+    ///
+    /// let full = Sw::with_elapsed(Duration::MAX);
+    /// assert_eq!(full.checked_merge(sw), None);
+    /// ```
+    #[must_use]
+    pub fn checked_merge(self, other: Self) -> Option<Self> {
+        self.checked_merge_at(other, I::now())
+    }
+
+    /// Returns a stopped stopwatch whose elapsed time is the sum of the
+    /// elapsed times of `self` and `other`, measured as if the current time
+    /// were `anchor`. If overflow occurs, returns [`None`].
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the last instant each stopwatch was started.
+    #[must_use]
+    pub fn checked_merge_at(self, other: Self, anchor: I) -> Option<Self> {
+        let elapsed = self
+            .checked_elapsed_at(anchor)?
+            .checked_add(other.checked_elapsed_at(anchor)?)?;
+        Some(Self::with_elapsed(elapsed))
+    }
 }
 
 impl<I: Instant> From<StopwatchImpl<I>> for CoreSw<I> {
@@ -894,6 +2511,36 @@ impl<I: Instant> From<CoreSw<I>> for StopwatchImpl<I> {
     }
 }
 
+impl<I: Instant> TryFrom<StopwatchImpl<I>> for Duration {
+    type Error = Error;
+
+    /// Returns the total time elapsed of `sw`.
+    ///
+    /// This is a strict counterpart to [`elapsed`](StopwatchImpl::elapsed),
+    /// which saturates on overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overflow`](Error::Overflow) if the elapsed time overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::{Error, Sw};
+    /// # use core::time::Duration;
+    /// # use std::thread;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(1));
+    /// assert_eq!(Duration::try_from(sw), Ok(Duration::from_secs(1)));
+    ///
+    /// let sw = Sw::with_elapsed_started(Duration::MAX);
+    /// thread::sleep(Duration::from_millis(100));
+    /// assert_eq!(Duration::try_from(sw), Err(Error::Overflow));
+    /// ```
+    fn try_from(sw: StopwatchImpl<I>) -> crate::Result<Self> {
+        sw.checked_elapsed().ok_or(Error::Overflow)
+    }
+}
+
 impl<I: Instant> fmt::Debug for StopwatchImpl<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("StopwatchImpl")
@@ -948,6 +2595,61 @@ impl<I: Instant> ops::Sub<Duration> for StopwatchImpl<I> {
     }
 }
 
+impl<I: Instant> ops::Add for StopwatchImpl<I> {
+    type Output = Self;
+
+    /// Returns `self` with the elapsed time of `rhs` added to it. If overflow
+    /// occurs, the elapsed time is saturated to [`Duration::MAX`].
+    ///
+    /// This is the same as `self += rhs`, so whether `self` is running is
+    /// unchanged. Use [`merge`](Self::merge) to get a stopped stopwatch
+    /// instead.
+    ///
+    /// # Notes
+    ///
+    /// If `rhs` is running, its elapsed time is measured at the time of the
+    /// call, and later time isn't added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(1)) + Sw::with_elapsed(Duration::from_secs(2));
+    /// assert_eq!(sw, Sw::with_elapsed(Duration::from_secs(3)));
+    /// ```
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<I: Instant> ops::AddAssign for StopwatchImpl<I> {
+    /// Adds the elapsed time of `rhs` to the elapsed time of `self`. If
+    /// overflow occurs, the elapsed time is saturated to [`Duration::MAX`].
+    ///
+    /// Whether `self` is running is unchanged.
+    ///
+    /// # Notes
+    ///
+    /// If `rhs` is running, its elapsed time is measured at the time of the
+    /// call, and later time isn't added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let mut parent = Sw::new_started();
+    /// parent += Sw::with_elapsed(Duration::from_secs(1));
+    /// assert!(parent.is_running());
+    /// assert!(parent.elapsed() >= Duration::from_secs(1));
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.saturating_add(rhs.elapsed());
+    }
+}
+
 impl<I: Instant> ops::AddAssign<Duration> for StopwatchImpl<I> {
     #[track_caller]
     fn add_assign(&mut self, dur: Duration) {
@@ -962,6 +2664,95 @@ impl<I: Instant> ops::SubAssign<Duration> for StopwatchImpl<I> {
     }
 }
 
+impl<I: Instant> iter::Sum<StopwatchImpl<I>> for Duration {
+    /// Sums the elapsed times of the stopwatches. If overflow occurs, the sum
+    /// is saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The current time is read once, and running stopwatches are all
+    /// measured at that instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sws = [1, 2, 3].map(|secs| Sw::with_elapsed(Duration::from_secs(secs)));
+    /// let total: Duration = sws.into_iter().sum();
+    /// assert_eq!(total, Duration::from_secs(6));
+    /// ```
+    fn sum<It: Iterator<Item = StopwatchImpl<I>>>(iter: It) -> Self {
+        let now = I::now();
+        iter.fold(Self::ZERO, |total, sw| {
+            total.saturating_add(sw.elapsed_at(now))
+        })
+    }
+}
+
+impl<'a, I: Instant> iter::Sum<&'a StopwatchImpl<I>> for Duration {
+    /// Sums the elapsed times of the stopwatches. If overflow occurs, the sum
+    /// is saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The current time is read once, and running stopwatches are all
+    /// measured at that instant.
+    fn sum<It: Iterator<Item = &'a StopwatchImpl<I>>>(iter: It) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<I: Instant> ops::Add<StopwatchImpl<I>> for Duration {
+    type Output = Self;
+
+    /// Returns the sum of `self` and the elapsed time of `sw`. If overflow
+    /// occurs, the sum is saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// If `sw` is running, its elapsed time is measured at the time of the
+    /// call. Unlike adding two [`Duration`]s, this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let sw = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert_eq!(Duration::from_secs(1) + sw, Duration::from_secs(3));
+    /// assert_eq!(Duration::MAX + sw, Duration::MAX);
+    /// ```
+    fn add(self, sw: StopwatchImpl<I>) -> Self::Output {
+        self.saturating_add(sw.elapsed())
+    }
+}
+
+impl<I: Instant> ops::AddAssign<StopwatchImpl<I>> for Duration {
+    /// Adds the elapsed time of `sw` to `self`. If overflow occurs, `self` is
+    /// saturated to [`Duration::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// If `sw` is running, its elapsed time is measured at the time of the
+    /// call, and later time isn't added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let mut total = Duration::ZERO;
+    /// for secs in [1, 2, 3] {
+    ///     total += Sw::with_elapsed(Duration::from_secs(secs));
+    /// }
+    /// assert_eq!(total, Duration::from_secs(6));
+    /// ```
+    fn add_assign(&mut self, sw: StopwatchImpl<I>) {
+        *self = *self + sw;
+    }
+}
+
 impl<I: Instant> PartialEq for StopwatchImpl<I> {
     /// Tests for equality between `self` and `rhs`.
     ///
@@ -974,6 +2765,165 @@ impl<I: Instant> PartialEq for StopwatchImpl<I> {
 
 impl<I: Instant> Eq for StopwatchImpl<I> {}
 
+impl<I: Instant> PartialOrd for StopwatchImpl<I> {
+    /// Compares `self` and `rhs` by elapsed time. Same as calling
+    /// [`Ord::cmp`].
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<I: Instant> Ord for StopwatchImpl<I> {
+    /// Compares `self` and `rhs` by elapsed time.
+    ///
+    /// This is consistent with the [`PartialEq`] implementation: stopwatches
+    /// which are equal compare as [`Equal`](Ordering::Equal). Otherwise, if
+    /// their elapsed times are equal, a stopped stopwatch is ordered before a
+    /// running one.
+    ///
+    /// # Notes
+    ///
+    /// - The current time is read once per comparison, and running stopwatches
+    ///   are measured at that instant, so the ordering of running stopwatches
+    ///   may change over time. Sorting reads the current time for every
+    ///   comparison it makes, so the order isn't total while running
+    ///   stopwatches are being sorted.
+    ///
+    ///   **Sorting running stopwatches with this implementation is
+    ///   unsupported.** It may give an unspecified order, and the sort
+    ///   implementations of Rust 1.81 and later may panic when they detect an
+    ///   inconsistent order.
+    ///
+    /// - Running stopwatches whose elapsed time can't be subtracted from their
+    ///   start instant are all [equal](PartialEq), even if their elapsed times
+    ///   differ. Comparing them to other stopwatches by elapsed time may then
+    ///   be non-transitive, so sorting them may give an unspecified order.
+    ///
+    /// To sort stopwatches which may be running, compare their elapsed times
+    /// at one anchor with
+    /// [`cmp_elapsed_at`](StopwatchImpl::cmp_elapsed_at), such as with
+    /// `sort_by(|a, b| a.cmp_elapsed_at(b, anchor))`, which is a total order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let mut sws = [3, 1, 2].map(|secs| Sw::with_elapsed(Duration::from_secs(secs)));
+    /// sws.sort();
+    /// assert_eq!(sws.map(|sw| sw.elapsed().as_secs()), [1, 2, 3]);
+    /// ```
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp_at(rhs, I::now())
+    }
+}
+
+impl<I: Instant> PartialEq<&Duration> for StopwatchImpl<I> {
+    /// Tests whether the elapsed time of `self` is equal to `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `self` is running, its elapsed time is measured once at the time of
+    /// comparison.
+    fn eq(&self, rhs: &&Duration) -> bool {
+        self.elapsed() == **rhs
+    }
+}
+
+impl<I: Instant> PartialOrd<&Duration> for StopwatchImpl<I> {
+    /// Compares the elapsed time of `self` to `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `self` is running, its elapsed time is measured once at the time of
+    /// comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let limit = Duration::from_secs(1);
+    /// let sw = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert!(sw > &limit);
+    /// ```
+    fn partial_cmp(&self, rhs: &&Duration) -> Option<Ordering> {
+        Some(self.elapsed().cmp(*rhs))
+    }
+}
+
+impl<I: Instant> PartialEq<Duration> for StopwatchImpl<I> {
+    /// Tests whether the elapsed time of `self` is equal to `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `self` is running, its elapsed time is measured once at the time of
+    /// comparison.
+    fn eq(&self, rhs: &Duration) -> bool {
+        self.elapsed() == *rhs
+    }
+}
+
+impl<I: Instant> PartialOrd<Duration> for StopwatchImpl<I> {
+    /// Compares the elapsed time of `self` to `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `self` is running, its elapsed time is measured once at the time of
+    /// comparison, so the result may change over time. Use
+    /// [`cmp_duration_at`](StopwatchImpl::cmp_duration_at) for a deterministic
+    /// comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let limit = Duration::from_secs(1);
+    /// let sw = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert!(sw > limit);
+    /// ```
+    fn partial_cmp(&self, rhs: &Duration) -> Option<Ordering> {
+        Some(self.elapsed().cmp(rhs))
+    }
+}
+
+impl<I: Instant> PartialEq<StopwatchImpl<I>> for Duration {
+    /// Tests whether `self` is equal to the elapsed time of `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `rhs` is running, its elapsed time is measured once at the time of
+    /// comparison.
+    fn eq(&self, rhs: &StopwatchImpl<I>) -> bool {
+        *self == rhs.elapsed()
+    }
+}
+
+impl<I: Instant> PartialOrd<StopwatchImpl<I>> for Duration {
+    /// Compares `self` to the elapsed time of `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// If `rhs` is running, its elapsed time is measured once at the time of
+    /// comparison, so the result may change over time. Use
+    /// [`cmp_duration_at`](StopwatchImpl::cmp_duration_at) for a deterministic
+    /// comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use libsw::Sw;
+    /// # use core::time::Duration;
+    /// let limit = Duration::from_secs(1);
+    /// let sw = Sw::with_elapsed(Duration::from_secs(2));
+    /// assert!(limit < sw);
+    /// ```
+    fn partial_cmp(&self, rhs: &StopwatchImpl<I>) -> Option<Ordering> {
+        Some(self.cmp(&rhs.elapsed()))
+    }
+}
+
 impl<I: Instant + Hash> Hash for StopwatchImpl<I> {
     /// Hashes `self` and `rhs`. These hashes are not dependent on the time of
     /// measurement, so they can be used to test equality.
@@ -986,3 +2936,10 @@ impl<I: Instant + Hash> Hash for StopwatchImpl<I> {
         self.inner.hash(state);
     }
 }
+
+/// Returns `dur` in milliseconds as an [`f64`], without the rounding error of
+/// scaling [`Duration::as_secs_f64`].
+#[allow(clippy::cast_precision_loss)]
+fn millis_f64(dur: Duration) -> f64 {
+    dur.as_secs() as f64 * 1e3 + f64::from(dur.subsec_nanos()) / 1e6
+}
@@ -9,7 +9,7 @@ use core::time::Duration;
 use std::collections::hash_map::DefaultHasher;
 use std::thread;
 
-use crate::Error;
+use crate::{Error, StopwatchDto};
 
 /* TODO: manually changing these aliases if i want to test all supported
  * `Instant` impls is annoying */
@@ -84,6 +84,443 @@ fn checked_toggle() {
     assert!(sw.is_stopped());
 }
 
+#[test]
+fn checked_toggle_all() {
+    let start = Instant::now();
+    let later = start.checked_add(DELAY).unwrap();
+
+    let mut sws = [
+        Stopwatch::new(),
+        Stopwatch::from_raw(DELAY, Some(start)),
+        Stopwatch::with_elapsed(DELAY),
+    ];
+    assert_eq!(Stopwatch::checked_toggle_all_at(&mut sws, later), Ok(()));
+    assert_eq!(
+        sws,
+        [
+            Stopwatch::from_raw(Duration::ZERO, Some(later)),
+            Stopwatch::with_elapsed(DELAY * 2),
+            Stopwatch::from_raw(DELAY, Some(later)),
+        ]
+    );
+
+    // the second-to-last stopwatch overflows, so none are toggled
+    let before = [
+        Stopwatch::new(),
+        Stopwatch::from_raw(DELAY, Some(start)),
+        Stopwatch::from_raw(Duration::MAX, Some(start)),
+        Stopwatch::from_raw(Duration::MAX, Some(start)),
+    ];
+    let mut sws = before;
+    assert_eq!(Stopwatch::checked_toggle_all_at(&mut sws, later), Err(2));
+    for (sw, before) in sws.iter().zip(before) {
+        assert_eq!(sw.inner.elapsed, before.inner.elapsed);
+        assert_eq!(sw.inner.start, before.inner.start);
+    }
+
+    assert_eq!(Stopwatch::checked_toggle_all(&mut []), Ok(()));
+}
+
+#[test]
+fn elapsed_f64() {
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(Duration::from_micros(1_500), Some(start));
+    let later = start.checked_add(Duration::from_millis(2)).unwrap();
+    assert_approx_eq(sw.elapsed_secs_f64_at(later), 0.0035);
+    assert_approx_eq(sw.elapsed_millis_f64_at(later), 3.5);
+    assert_approx_eq(sw.checked_elapsed_secs_f64_at(later).unwrap(), 0.0035);
+    assert_approx_eq(sw.checked_elapsed_millis_f64_at(later).unwrap(), 3.5);
+    assert_approx_eq(sw.elapsed_secs_f64_at(start), 0.0015);
+
+    let sw = Stopwatch::with_elapsed(Duration::new(1, 1));
+    assert_approx_eq(sw.elapsed_secs_f64(), 1.000_000_001);
+    assert_approx_eq(sw.elapsed_millis_f64(), 1_000.000_001);
+    assert_eq!(
+        sw.checked_elapsed_millis_f64(),
+        Some(sw.elapsed_millis_f64())
+    );
+
+    // saturates consistently with elapsed
+    let sw = Stopwatch::from_raw(Duration::MAX, Some(start));
+    assert_approx_eq(sw.elapsed_secs_f64_at(later), Duration::MAX.as_secs_f64());
+    assert_eq!(sw.checked_elapsed_secs_f64_at(later), None);
+    assert_eq!(sw.checked_elapsed_millis_f64_at(later), None);
+    assert!(sw.elapsed_millis_f64_at(later) > 1e21);
+}
+
+#[test]
+fn sum() {
+    let sws = [
+        Stopwatch::with_elapsed(DELAY),
+        Stopwatch::with_elapsed(DELAY * 2),
+    ];
+    assert_eq!(sws.iter().sum::<Duration>(), DELAY * 3);
+    assert_eq!(sws.into_iter().sum::<Duration>(), DELAY * 3);
+
+    assert_eq!(
+        core::iter::empty::<Stopwatch>().sum::<Duration>(),
+        Duration::ZERO
+    );
+
+    let sws = [
+        Stopwatch::with_elapsed(Duration::MAX),
+        Stopwatch::with_elapsed(DELAY),
+        Stopwatch::new(),
+    ];
+    assert_eq!(sws.iter().sum::<Duration>(), Duration::MAX);
+
+    let running = Stopwatch::new_started();
+    thread::sleep(DELAY);
+    assert!(
+        [running, Stopwatch::with_elapsed(DELAY)]
+            .iter()
+            .sum::<Duration>()
+            >= DELAY * 2
+    );
+}
+
+#[test]
+fn add_stopwatch() {
+    let sum = Stopwatch::with_elapsed(DELAY) + Stopwatch::with_elapsed(DELAY * 2);
+    assert!(sum.is_stopped());
+    assert_eq!(sum.elapsed(), DELAY * 3);
+
+    let sum = Stopwatch::with_elapsed(Duration::MAX) + Stopwatch::with_elapsed(DELAY);
+    assert_eq!(sum, Stopwatch::with_elapsed(Duration::MAX));
+
+    let start = Instant::now();
+    let running = Stopwatch::from_raw(DELAY, Some(start));
+    let sum = running + Stopwatch::with_elapsed(DELAY);
+    assert!(sum.is_running());
+    assert_eq!(sum, Stopwatch::from_raw(DELAY * 2, Some(start)));
+
+    // same as adding in place
+    let mut assigned = running;
+    assigned += Stopwatch::with_elapsed(DELAY);
+    assert_eq!(sum, assigned);
+}
+
+#[test]
+fn snapshot() {
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(DELAY, Some(start));
+    let snapshot = sw.snapshot_at(start.checked_add(DELAY * 2).unwrap());
+    assert_eq!(snapshot, Stopwatch::with_elapsed(DELAY * 3));
+    assert_eq!(sw, Stopwatch::from_raw(DELAY, Some(start)));
+    assert_eq!(sw.snapshot_at(start), Stopwatch::with_elapsed(DELAY));
+
+    let stopped = Stopwatch::with_elapsed(DELAY);
+    assert_eq!(stopped.snapshot(), stopped);
+
+    let sw = Stopwatch::new_started();
+    thread::sleep(DELAY);
+    let snapshot = sw.snapshot();
+    assert!(sw.is_running());
+    assert!(snapshot.is_stopped());
+    assert!(snapshot.elapsed() >= DELAY);
+    assert!(sw.elapsed() >= snapshot.elapsed());
+}
+
+#[test]
+fn merge() {
+    let a = Stopwatch::with_elapsed(DELAY);
+    let b = Stopwatch::with_elapsed(DELAY * 2);
+    assert_eq!(a.merge(b), Stopwatch::with_elapsed(DELAY * 3));
+    assert_eq!(a.checked_merge(b), Some(Stopwatch::with_elapsed(DELAY * 3)));
+
+    let full = Stopwatch::with_elapsed(Duration::MAX);
+    assert_eq!(full.merge(a), full);
+    assert_eq!(full.checked_merge(a), None);
+    assert_eq!(full.checked_merge(Stopwatch::new()), Some(full));
+
+    // running stopwatches are measured at the anchor
+    let start = Instant::now();
+    let anchor = start.checked_add(DELAY * 2).unwrap();
+    let running = Stopwatch::from_raw(DELAY, Some(start));
+    let merged = running.merge_at(a, anchor);
+    assert!(merged.is_stopped());
+    assert_eq!(merged.elapsed(), DELAY * 4);
+    assert_eq!(
+        running.checked_merge_at(running, anchor),
+        Some(Stopwatch::with_elapsed(DELAY * 6))
+    );
+
+    let running = Stopwatch::from_raw(Duration::MAX, Some(start));
+    assert_eq!(running.checked_merge_at(Stopwatch::new(), anchor), None);
+    assert_eq!(running.merge_at(Stopwatch::new(), anchor), full);
+}
+
+#[test]
+fn shared_guards_nest() {
+    use crate::SharedStopwatch;
+
+    let start = Instant::now();
+    let sw = SharedStopwatch::<Instant>::new();
+    {
+        let outer = sw.guard_at(start);
+        assert!(sw.is_running());
+        {
+            let _inner = outer.inner().guard();
+            let _innermost = sw.guard();
+            assert_eq!(sw.guards(), 3);
+        }
+        // inner guards don't stop the stopwatch
+        assert_eq!(sw.guards(), 1);
+        assert!(sw.is_running());
+        assert_eq!(sw.elapsed_at(start.checked_add(DELAY).unwrap()), DELAY);
+    }
+    assert_eq!(sw.guards(), 0);
+    assert!(sw.is_stopped());
+    let elapsed = sw.elapsed();
+    assert_eq!(sw.get(), Stopwatch::with_elapsed(elapsed));
+
+    // guards resume a stopped stopwatch
+    {
+        let _guard = sw.guard();
+        thread::sleep(DELAY);
+    }
+    assert!(sw.elapsed() >= elapsed + DELAY);
+    assert!(sw.into_inner().is_stopped());
+
+    // a running stopwatch is stopped by its last guard
+    let sw = SharedStopwatch::from(Stopwatch::new_started());
+    drop(sw.guard());
+    assert!(sw.is_stopped());
+}
+
+#[test]
+fn format_elapsed() {
+    use crate::TimeUnit;
+
+    let sw = Stopwatch::with_elapsed(Duration::new(1, 230_456_789));
+    for (unit, precision, expected) in [
+        (TimeUnit::Secs, 2, "1.23s"),
+        (TimeUnit::Secs, 0, "1s"),
+        (TimeUnit::Secs, 12, "1.230456789s"),
+        (TimeUnit::Millis, usize::MAX, "1230.456789000ms"),
+        (TimeUnit::Millis, 0, "1230ms"),
+        (TimeUnit::Millis, 3, "1230.456ms"),
+        (TimeUnit::Micros, 0, "1230456µs"),
+        (TimeUnit::Nanos, 0, "1230456789ns"),
+        (TimeUnit::Nanos, 2, "1230456789.00ns"),
+    ] {
+        assert_eq!(sw.format_elapsed(unit, precision).to_string(), expected);
+    }
+
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    let anchor = start.checked_add(DELAY).unwrap();
+    assert_eq!(
+        sw.format_elapsed_at(TimeUnit::Secs, 1, anchor).to_string(),
+        "0.1s"
+    );
+    assert_eq!(
+        Stopwatch::with_elapsed(Duration::MAX)
+            .format_elapsed(TimeUnit::Nanos, 0)
+            .to_string(),
+        Duration::MAX.as_nanos().to_string() + "ns"
+    );
+    assert_eq!(
+        std::format!(
+            "{:>15}|{:-<8}|{:^9}",
+            Stopwatch::with_elapsed(Duration::MAX).format_elapsed(TimeUnit::Secs, 9),
+            Stopwatch::with_elapsed(DELAY).format_elapsed(TimeUnit::Millis, 1),
+            Stopwatch::with_elapsed(DELAY).format_elapsed(TimeUnit::Micros, 0),
+        ),
+        "18446744073709551615.999999999s|100.0ms-|100000µs "
+    );
+}
+
+#[test]
+fn parse_round_trip() -> Result<(), crate::ParseError> {
+    for millis in [0, 45, 3_723_045, 100 * 3_600_000 + 59_999] {
+        let sw = Stopwatch::with_elapsed(Duration::from_millis(millis));
+        assert_eq!(sw.to_string().parse::<Stopwatch>()?, sw);
+    }
+
+    // truncated to whole milliseconds
+    let sw = Stopwatch::with_elapsed(Duration::new(1, 999_999));
+    assert_eq!(
+        sw.to_string().parse::<Stopwatch>()?,
+        Stopwatch::with_elapsed(Duration::from_secs(1))
+    );
+
+    let sw = "00:00:01.500 (running)".parse::<Stopwatch>()?;
+    assert!(sw.is_running());
+    assert!(sw.elapsed() >= Duration::from_millis(1500));
+    Ok(())
+}
+
+#[test]
+fn parse_malformed() {
+    use crate::ParseError;
+
+    for (input, err) in [
+        ("", ParseError::State),
+        ("00:00:00.000", ParseError::State),
+        ("00:00:00.000 (paused)", ParseError::State),
+        ("00:00:00.000 (stopped", ParseError::State),
+        ("00:00:00.000(stopped)", ParseError::State),
+        (" (stopped)", ParseError::Format),
+        ("0:00:00.000 (stopped)", ParseError::Format),
+        ("00:0:00.000 (stopped)", ParseError::Format),
+        ("00:00:00.00 (stopped)", ParseError::Format),
+        ("00:00:00 (stopped)", ParseError::Format),
+        ("00:00.000 (stopped)", ParseError::Format),
+        ("+0:00:00.000 (stopped)", ParseError::Format),
+        ("00:0a:00.000 (stopped)", ParseError::Format),
+        ("00:60:00.000 (stopped)", ParseError::Range),
+        ("00:00:60.000 (stopped)", ParseError::Range),
+        (
+            "99999999999999999999:00:00.000 (stopped)",
+            ParseError::Range,
+        ),
+        ("9999999999999999:00:00.000 (stopped)", ParseError::Range),
+    ] {
+        assert_eq!(input.parse::<Stopwatch>(), Err(err), "{input:?}");
+    }
+    assert_eq!(
+        ParseError::Range.to_string(),
+        "elapsed time is out of range"
+    );
+}
+
+#[cfg(feature = "parse_duration")]
+#[test]
+fn parse_duration() {
+    use crate::{parse_duration, ParseError};
+
+    for (input, expected) in [
+        ("1h30m", Ok(Duration::from_secs(5400))),
+        ("2.5s", Ok(Duration::from_millis(2500))),
+        ("1m1s1ms1us1ns", Ok(Duration::new(61, 1_001_001))),
+        ("3µs", Ok(Duration::from_micros(3))),
+        (".5h", Ok(Duration::from_secs(1800))),
+        ("1.s", Ok(Duration::from_secs(1))),
+        ("0.0000000019s", Ok(Duration::from_nanos(1))),
+        ("1.9ns", Ok(Duration::from_nanos(1))),
+        ("", Err(ParseError::Format)),
+        ("1", Err(ParseError::Format)),
+        ("s", Err(ParseError::Format)),
+        (".s", Err(ParseError::Format)),
+        ("1.2.3s", Err(ParseError::Format)),
+        ("1 s", Err(ParseError::Format)),
+        ("1d", Err(ParseError::Format)),
+        ("-1s", Err(ParseError::Format)),
+        ("18446744073709551615s", Ok(Duration::from_secs(u64::MAX))),
+        ("18446744073709551616s", Err(ParseError::Range)),
+        ("18446744073709551615s1s", Err(ParseError::Range)),
+        (
+            "999999999999999999999999999999999999999h",
+            Err(ParseError::Range),
+        ),
+    ] {
+        assert_eq!(parse_duration(input), expected, "{input:?}");
+    }
+}
+
+#[test]
+fn current_segment() -> crate::Result<()> {
+    let start = Instant::now();
+    let mut sw = Stopwatch::from_raw(DELAY * 5, Some(start));
+    let anchor = start.checked_add(DELAY * 2).unwrap();
+    assert_eq!(sw.current_segment_at(anchor), Some(DELAY * 2));
+    assert_eq!(sw.current_segment_at(start), Some(Duration::ZERO));
+    assert_eq!(sw.elapsed_at(anchor), DELAY * 7);
+
+    sw.stop_at(anchor)?;
+    assert_eq!(sw.current_segment_at(anchor), None);
+    assert_eq!(sw.current_segment(), None);
+
+    // a new segment starts from zero
+    let later = start.checked_add(DELAY * 4).unwrap();
+    sw.start_at(later)?;
+    assert_eq!(sw.current_segment_at(anchor), Some(Duration::ZERO));
+    assert_eq!(
+        sw.current_segment_at(later.checked_add(DELAY).unwrap()),
+        Some(DELAY)
+    );
+
+    let sw = Stopwatch::new_started();
+    thread::sleep(DELAY);
+    assert!(sw.current_segment().unwrap() >= DELAY);
+    Ok(())
+}
+
+#[test]
+fn start_time() -> crate::Result<()> {
+    let now = Instant::now();
+    let mut sw = Stopwatch::from_raw(DELAY, Some(now));
+    assert_eq!(sw.start_time(), Some(now));
+    sw.stop_at(now)?;
+    assert_eq!(sw.start_time(), None);
+
+    let later = now.checked_add(DELAY).unwrap();
+    sw.start_at(later)?;
+    assert_eq!(sw.start_time(), Some(later));
+    assert_eq!(sw.start_time(), sw.to_core().start);
+    Ok(())
+}
+
+#[test]
+fn is_accumulating() -> crate::Result<()> {
+    let now = Instant::now();
+    let future = now.checked_add(DELAY * 3).unwrap();
+
+    let mut sw = Stopwatch::with_elapsed(DELAY);
+    assert!(!sw.is_accumulating_at(now));
+    sw.start_at(future)?;
+    assert!(sw.is_running());
+    assert!(!sw.is_accumulating_at(now));
+    assert!(!sw.is_accumulating());
+    assert_eq!(sw.elapsed_at(now), DELAY);
+    assert!(sw.is_accumulating_at(future));
+    assert!(sw.is_accumulating_at(future.checked_add(DELAY).unwrap()));
+
+    assert!(Stopwatch::new_started().is_accumulating());
+    Ok(())
+}
+
+#[test]
+fn builder() {
+    let start = Instant::now();
+    assert_eq!(Stopwatch::builder().build(), Stopwatch::new());
+    assert_eq!(
+        Stopwatch::builder().elapsed(DELAY).build(),
+        Stopwatch::with_elapsed(DELAY)
+    );
+    assert_eq!(
+        Stopwatch::builder().started_at(start).build(),
+        Stopwatch::new_started_at(start)
+    );
+    assert_eq!(
+        Stopwatch::builder()
+            .elapsed(DELAY)
+            .started_at(start)
+            .build(),
+        Stopwatch::from_raw(DELAY, Some(start))
+    );
+
+    // stopping the builder forgets the start instant
+    assert_eq!(
+        Stopwatch::builder()
+            .started_at(start)
+            .started(false)
+            .build(),
+        Stopwatch::new()
+    );
+    assert_eq!(
+        Stopwatch::builder().started_at(start).started(true).build(),
+        Stopwatch::new_started_at(start)
+    );
+
+    let sw = Stopwatch::builder().elapsed(DELAY).started(true).build();
+    assert!(sw.is_running());
+    thread::sleep(DELAY);
+    assert!(sw.elapsed() >= DELAY * 2);
+    assert_eq!(crate::StopwatchBuilder::default(), Stopwatch::builder());
+}
+
 #[test]
 fn reset() -> crate::Result<()> {
     let mut sw = Stopwatch::new_started();
@@ -234,6 +671,50 @@ fn double_starts_stops_errs() {
     assert_eq!(sw.stop(), Err(Error::SwStop));
 }
 
+#[test]
+fn overflow_error() {
+    assert!(!Error::Overflow.expects_running());
+    assert!(!Error::Overflow.expects_stopped());
+    assert_eq!(
+        Error::Overflow.to_string(),
+        "stopwatch elapsed time overflowed"
+    );
+}
+
+#[test]
+fn error_message_matches_display() {
+    for err in [
+        Error::SwStart,
+        Error::SwStop,
+        Error::SwGuard,
+        Error::GuardNew,
+        Error::SwLap,
+        Error::Overflow,
+        Error::Clock,
+        Error::GroupFull,
+    ] {
+        assert_eq!(err.message(), err.to_string());
+    }
+}
+
+#[test]
+fn try_from_duration() {
+    assert_eq!(Duration::try_from(Stopwatch::new()), Ok(Duration::ZERO));
+    assert_eq!(
+        Duration::try_from(Stopwatch::with_elapsed(DELAY)),
+        Ok(DELAY)
+    );
+    assert_eq!(
+        Duration::try_from(Stopwatch::with_elapsed(Duration::MAX)),
+        Ok(Duration::MAX)
+    );
+
+    let start = Instant::now();
+    let running = Stopwatch::from_raw(Duration::MAX, Some(start));
+    thread::sleep(DELAY);
+    assert_eq!(Duration::try_from(running), Err(Error::Overflow));
+}
+
 #[test]
 fn sane_elapsed_while_stopped() -> crate::Result<()> {
     let mut sw = Stopwatch::new_started();
@@ -306,6 +787,25 @@ fn sub_at_earlier_anchor_behavior() -> crate::Result<()> {
     Ok(())
 }
 
+#[test]
+fn progress() {
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    for (n, expected) in [(0, 0.0), (1, 0.25), (2, 0.5), (4, 1.0), (9, 1.0)] {
+        let anchor = start.checked_add(DELAY * n).unwrap();
+        assert_approx_eq(sw.progress_at(DELAY * 4, anchor), expected);
+    }
+    assert_approx_eq(sw.progress_at(Duration::ZERO, start), 1.0);
+    assert_approx_eq(Stopwatch::new().progress(Duration::ZERO), 1.0);
+    assert_approx_eq(Stopwatch::new().progress(Duration::MAX), 0.0);
+
+    let sw = Stopwatch::with_elapsed(Duration::MAX);
+    assert_approx_eq(sw.progress(Duration::MAX), 1.0);
+    assert_approx_eq(sw.progress(DELAY), 1.0);
+    let sw = Stopwatch::with_elapsed(Duration::MAX.saturating_sub(DELAY));
+    assert!(sw.progress(Duration::MAX) <= 1.0);
+}
+
 #[test]
 fn elapsed_at_saturates() {
     let sw = Stopwatch::with_elapsed_started(DELAY);
@@ -330,6 +830,29 @@ fn start_in_future() -> crate::Result<()> {
     Ok(())
 }
 
+#[test]
+fn checked_start_at_past() -> crate::Result<()> {
+    let past = Instant::now().checked_sub(DELAY).unwrap();
+
+    let mut sw = Stopwatch::with_elapsed(DELAY);
+    sw.checked_start_at_past(past)?;
+    assert!(sw.elapsed() >= DELAY * 2);
+    assert_eq!(sw.checked_start_at_past(past), Err(Error::SwStart));
+
+    let mut sw = Stopwatch::with_elapsed(Duration::MAX.saturating_sub(DELAY / 2));
+    assert_eq!(sw.checked_start_at_past(past), Err(Error::Overflow));
+    assert_eq!(
+        sw,
+        Stopwatch::with_elapsed(Duration::MAX.saturating_sub(DELAY / 2))
+    );
+
+    // anchors in the future are fine
+    let mut sw = Stopwatch::with_elapsed(Duration::MAX);
+    sw.checked_start_at_past(Instant::now().checked_add(DELAY).unwrap())?;
+    assert!(sw.is_running());
+    Ok(())
+}
+
 #[test]
 fn stop_before_last_start() -> crate::Result<()> {
     let mut sw = Stopwatch::with_elapsed(DELAY);
@@ -364,80 +887,285 @@ fn checked_stop_stops() -> crate::Result<()> {
 }
 
 #[test]
-fn eq_properties() {
-    for [a, b, c] in mixed_stopwatches() {
-        dbg!(a, b, c);
-
-        // reflexive
-        assert!(a == a);
-        assert!(b == b);
+fn measure() {
+    let (value, elapsed) = Stopwatch::measure(|| {
+        thread::sleep(DELAY);
+        "done"
+    });
+    assert_eq!(value, "done");
+    assert!(elapsed >= DELAY);
+}
 
-        // symmetric
-        assert_eq!(a == b, b == a);
+#[test]
+fn measure_with() {
+    let mut sw = Stopwatch::with_elapsed(DELAY);
+    let (value, elapsed) = sw.measure_with(|| {
+        thread::sleep(DELAY);
+        1 + 1
+    });
+    assert_eq!(value, 2);
+    assert!(elapsed >= DELAY);
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), DELAY + elapsed);
 
-        // transitive
-        if (a == b) && (b == c) {
-            assert_eq!(a, c);
-        }
-    }
+    let mut running = Stopwatch::new_started();
+    let ((), elapsed) = running.measure_with(|| thread::sleep(DELAY));
+    assert!(running.is_running());
+    assert!(running.elapsed() >= elapsed);
 }
 
 #[test]
-fn eq_running() {
-    // whatever is compared shouldn't depend on the time of observation
-    let now = Instant::now();
-    let sw_1 = Stopwatch::new_started_at(now);
-    let sw_2 = Stopwatch::new_started_at(now);
-    let sw_3 = Stopwatch::from_raw(DELAY, Some(now));
-    assert_eq!(sw_1, sw_2);
-    assert_ne!(sw_1, sw_3);
+fn time() {
+    let (value, elapsed) = Stopwatch::time_fn(|| {
+        thread::sleep(DELAY);
+        "done"
+    });
+    assert_eq!(value, "done");
+    assert!(elapsed >= DELAY);
+
+    let mut sw = Stopwatch::with_elapsed(DELAY);
+    assert_eq!(sw.time(|| 1 + 1), 2);
+    assert!(sw.is_stopped());
+    assert!(sw.elapsed() >= DELAY);
+
+    let mut running = Stopwatch::new_started();
+    sw.time(|| thread::sleep(DELAY));
+    assert!(sw.elapsed() >= DELAY * 2);
+    running.time(|| thread::sleep(DELAY));
+    assert!(running.is_running());
+    assert!(running.elapsed() >= DELAY);
 }
 
 #[test]
-fn eq_correct() -> crate::Result<()> {
-    assert_ne!(Stopwatch::new(), Stopwatch::new_started());
-    assert_ne!(
-        Stopwatch::with_elapsed(Duration::from_secs(1)),
-        Stopwatch::with_elapsed(Duration::from_secs(2)),
-    );
-
-    let mut sw_1 = Stopwatch::new();
-    let mut sw_2 = Stopwatch::new();
+fn guard_inner_mut() -> crate::Result<()> {
     let start = Instant::now();
-    sw_1.start_at(start)?;
-    sw_2.start_at(start)?;
-    assert_eq!(sw_1, sw_2);
+    let at = |n| start.checked_add(DELAY * n).unwrap();
 
+    let mut sw = Stopwatch::new();
+    {
+        let mut guard = sw.guard_at(start)?;
+        *guard.inner_mut() += DELAY;
+        assert!(!guard.is_paused());
+
+        // stopping through the borrow pauses the guard
+        guard.inner_mut().stop_at(at(1))?;
+        assert!(guard.is_paused());
+        assert_eq!(guard.inner().elapsed(), DELAY * 2);
+
+        // and starting it again resumes it
+        guard.inner_mut().start_at(at(2))?;
+        assert!(!guard.is_paused());
+    }
+    assert!(sw.is_stopped());
+    assert!(sw.elapsed() >= DELAY * 2);
     Ok(())
 }
 
 #[test]
-fn partial_eq() {
-    for [a, b, _] in mixed_stopwatches() {
-        assert_eq!(a == b, !(a != b));
+fn guard_pause_resume() -> crate::Result<()> {
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+
+    let mut sw = Stopwatch::new();
+    {
+        let mut guard = sw.guard_at(start)?;
+        assert!(!guard.is_paused());
+        assert_eq!(guard.resume_at(at(1)), Err(Error::SwStart));
+
+        guard.pause_at(at(1))?;
+        assert!(guard.is_paused());
+        assert!(guard.inner().is_stopped());
+        assert_eq!(guard.pause_at(at(2)), Err(Error::SwStop));
+
+        guard.resume_at(at(3))?;
+        assert!(!guard.is_paused());
+        assert!(guard.inner().is_running());
+        guard.pause_at(at(4))?;
+        // dropping a paused guard leaves the stopwatch as it is
     }
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), DELAY * 2);
+    Ok(())
 }
 
 #[test]
-fn hash_and_eq() {
-    for [sw_1, sw_2, sw_3] in mixed_stopwatches() {
-        let mut hasher_1 = DefaultHasher::new();
-        let mut hasher_2 = DefaultHasher::new();
-        let mut hasher_3 = DefaultHasher::new();
-
-        sw_1.hash(&mut hasher_1);
-        sw_2.hash(&mut hasher_2);
-        sw_3.hash(&mut hasher_3);
+fn guard_into_accumulates() -> crate::Result<()> {
+    let mut sw = Stopwatch::new();
+    let mut total = Duration::ZERO;
 
-        // > When implementing both Hash and Eq, it is important that the following property holds:
-        // > k1 == k2 -> hash(k1) == hash(k2)
-        assert_eq!(sw_1 == sw_2, hasher_1.finish() == hasher_2.finish());
-        assert_eq!(sw_1 == sw_3, hasher_1.finish() == hasher_3.finish());
-        assert_eq!(sw_2 == sw_3, hasher_2.finish() == hasher_3.finish());
+    for _ in 0..3 {
+        let _guard = sw.guard_into(&mut total)?;
+        thread::sleep(DELAY);
     }
-}
 
-#[test]
+    assert!(sw.is_stopped());
+    assert!(total >= DELAY * 3);
+    assert_eq!(total, sw.elapsed());
+    Ok(())
+}
+
+#[test]
+fn guard_into_only_adds_guarded_time() -> crate::Result<()> {
+    let mut sw = Stopwatch::with_elapsed(DELAY * 5);
+    let mut total = DELAY;
+
+    let start = Instant::now();
+    {
+        let _guard = sw.guard_into_at(start, &mut total)?;
+    }
+    assert!(total >= DELAY);
+    assert!(total < DELAY * 2);
+    assert!(sw.elapsed() >= DELAY * 5);
+
+    sw.start()?;
+    assert_eq!(sw.guard_into(&mut total), Err(Error::SwGuard));
+    Ok(())
+}
+
+#[test]
+fn sla_guard() -> crate::Result<()> {
+    use core::cell::Cell;
+
+    thread_local! {
+        static BREACH: Cell<Option<Duration>> = const { Cell::new(None) };
+    }
+
+    fn on_breach(elapsed: Duration) {
+        BREACH.with(|breach| breach.set(Some(elapsed)));
+    }
+
+    let mut sw = Stopwatch::new();
+
+    // quick scope
+    {
+        let guard = sw.sla_guard(DELAY * 10, on_breach)?;
+        assert_eq!(guard.threshold(), DELAY * 10);
+    }
+    assert!(sw.is_stopped());
+    assert_eq!(BREACH.with(Cell::get), None);
+
+    // slow scope
+    {
+        let _guard = sw.sla_guard(DELAY / 2, on_breach)?;
+        thread::sleep(DELAY);
+    }
+    assert!(sw.is_stopped());
+    let breach = BREACH.with(Cell::get).unwrap();
+    assert!(breach >= DELAY);
+    assert!(sw.elapsed() >= breach);
+
+    sw.start()?;
+    assert_eq!(sw.sla_guard(DELAY, on_breach), Err(Error::SwGuard));
+    Ok(())
+}
+
+#[test]
+fn guard_with() -> crate::Result<()> {
+    let mut sw = Stopwatch::new();
+    let mut seen = None;
+    {
+        let guard = sw.guard_with(|sw| seen = Some((sw.is_stopped(), sw.elapsed())))?;
+        assert!(guard.inner().is_running());
+        thread::sleep(DELAY);
+    }
+    assert!(sw.is_stopped());
+    let (stopped, elapsed) = seen.unwrap();
+    assert!(stopped);
+    assert_eq!(elapsed, sw.elapsed());
+    assert!(elapsed >= DELAY);
+
+    // closure may mutate the stopwatch
+    {
+        let _guard = sw.guard_with(Stopwatch::reset)?;
+    }
+    assert_eq!(sw, Stopwatch::new());
+
+    let mut called = false;
+    sw.start()?;
+    assert!(matches!(
+        sw.guard_with(|_| called = true),
+        Err(Error::SwGuard)
+    ));
+    assert!(!called);
+    assert!(sw.is_running());
+    Ok(())
+}
+
+#[test]
+fn eq_properties() {
+    for [a, b, c] in mixed_stopwatches() {
+        dbg!(a, b, c);
+
+        // reflexive
+        assert!(a == a);
+        assert!(b == b);
+
+        // symmetric
+        assert_eq!(a == b, b == a);
+
+        // transitive
+        if (a == b) && (b == c) {
+            assert_eq!(a, c);
+        }
+    }
+}
+
+#[test]
+fn eq_running() {
+    // whatever is compared shouldn't depend on the time of observation
+    let now = Instant::now();
+    let sw_1 = Stopwatch::new_started_at(now);
+    let sw_2 = Stopwatch::new_started_at(now);
+    let sw_3 = Stopwatch::from_raw(DELAY, Some(now));
+    assert_eq!(sw_1, sw_2);
+    assert_ne!(sw_1, sw_3);
+}
+
+#[test]
+fn eq_correct() -> crate::Result<()> {
+    assert_ne!(Stopwatch::new(), Stopwatch::new_started());
+    assert_ne!(
+        Stopwatch::with_elapsed(Duration::from_secs(1)),
+        Stopwatch::with_elapsed(Duration::from_secs(2)),
+    );
+
+    let mut sw_1 = Stopwatch::new();
+    let mut sw_2 = Stopwatch::new();
+    let start = Instant::now();
+    sw_1.start_at(start)?;
+    sw_2.start_at(start)?;
+    assert_eq!(sw_1, sw_2);
+
+    Ok(())
+}
+
+#[test]
+fn partial_eq() {
+    for [a, b, _] in mixed_stopwatches() {
+        assert_eq!(a == b, !(a != b));
+    }
+}
+
+#[test]
+fn hash_and_eq() {
+    for [sw_1, sw_2, sw_3] in mixed_stopwatches() {
+        let mut hasher_1 = DefaultHasher::new();
+        let mut hasher_2 = DefaultHasher::new();
+        let mut hasher_3 = DefaultHasher::new();
+
+        sw_1.hash(&mut hasher_1);
+        sw_2.hash(&mut hasher_2);
+        sw_3.hash(&mut hasher_3);
+
+        // > When implementing both Hash and Eq, it is important that the following property holds:
+        // > k1 == k2 -> hash(k1) == hash(k2)
+        assert_eq!(sw_1 == sw_2, hasher_1.finish() == hasher_2.finish());
+        assert_eq!(sw_1 == sw_3, hasher_1.finish() == hasher_3.finish());
+        assert_eq!(sw_2 == sw_3, hasher_2.finish() == hasher_3.finish());
+    }
+}
+
+#[test]
 fn hash_running() {
     let now = Instant::now();
     let sw_1 = Stopwatch::new_started_at(now);
@@ -457,60 +1185,2839 @@ fn hash_running() {
     assert_ne!(hasher_1.finish(), hasher_3.finish());
 }
 
-fn mixed_stopwatches() -> [[Stopwatch; 3]; 11] {
-    let crafted_1;
-    let crafted_2;
-    {
-        let mut elapsed = Duration::from_secs(10);
-        let mut start = Instant::now();
-        crafted_1 = Stopwatch::from_raw(elapsed, Some(start));
+#[test]
+fn cmp_duration_ref() {
+    let threshold = &DELAY;
+    let sw = Stopwatch::with_elapsed(DELAY);
+    assert!(sw == threshold);
+    assert!(sw <= threshold);
+    assert!(sw >= threshold);
+    assert!(Stopwatch::new() < threshold);
+    assert!(Stopwatch::with_elapsed(DELAY * 2) > threshold);
+    assert!(Stopwatch::with_elapsed(DELAY * 2) != threshold);
+}
 
-        elapsed -= Duration::from_secs(1);
-        start = <Instant as crate::Instant>::checked_sub(&start, Duration::from_secs(1)).unwrap();
-        crafted_2 = Stopwatch::from_raw(elapsed, Some(start));
+#[test]
+#[allow(clippy::op_ref)]
+fn cmp_duration_ref_running() {
+    let sw = Stopwatch::new_started();
+    thread::sleep(DELAY);
+    assert!(sw >= &DELAY);
+    assert!(sw != &Duration::ZERO);
+}
+
+#[test]
+fn cmp_duration() {
+    let sw = Stopwatch::with_elapsed(DELAY);
+    assert!(sw == DELAY);
+    assert!(DELAY == sw);
+    assert!(sw <= DELAY);
+    assert!(sw >= DELAY);
+    assert!(Stopwatch::new() < DELAY);
+    assert!(DELAY > Stopwatch::new());
+    assert!(Stopwatch::with_elapsed(DELAY * 2) > DELAY);
+    assert!(DELAY < Stopwatch::with_elapsed(DELAY * 2));
+    assert!(DELAY != Stopwatch::with_elapsed(DELAY * 2));
+}
+
+#[test]
+fn cmp_duration_running() {
+    use core::cmp::Ordering;
+
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    assert_eq!(sw.cmp_duration_at(DELAY, at(0)), Ordering::Less);
+    assert_eq!(sw.cmp_duration_at(DELAY, at(1)), Ordering::Equal);
+    assert_eq!(sw.cmp_duration_at(DELAY, at(2)), Ordering::Greater);
+
+    thread::sleep(DELAY);
+    assert!(sw >= DELAY);
+    assert!(Duration::ZERO < sw);
+    assert!(sw != Duration::ZERO);
+}
+
+#[test]
+fn deadline_countdown_pauses() -> crate::Result<()> {
+    use crate::Deadline;
+
+    let start = Instant::now();
+    let mut timer = Deadline::new(Stopwatch::new(), DELAY * 5);
+    assert!(timer.is_stopped());
+    assert_eq!(timer.remaining(), DELAY * 5);
+
+    timer.start_at(start)?;
+    assert!(timer.is_running());
+    assert_eq!(timer.start(), Err(Error::SwStart));
+    timer.stop_at(start.checked_add(DELAY * 2).unwrap())?;
+    assert_eq!(timer.stop(), Err(Error::SwStop));
+
+    // stopped timers keep their frozen remaining time
+    thread::sleep(DELAY);
+    assert_eq!(timer.remaining(), DELAY * 3);
+    assert!(!timer.expired());
+
+    timer.start_at(start)?;
+    assert_eq!(
+        timer.remaining_at(start.checked_add(DELAY).unwrap()),
+        DELAY * 2
+    );
+    assert!(timer.expired_at(start.checked_add(DELAY * 3).unwrap()));
+    assert_eq!(
+        timer.remaining_at(start.checked_add(DELAY * 9).unwrap()),
+        Duration::ZERO
+    );
+
+    timer.reset();
+    assert!(timer.is_stopped());
+    assert_eq!(timer.remaining(), DELAY * 5);
+
+    // only expires once the elapsed time saturates
+    let timer = Deadline::new(
+        Stopwatch::with_elapsed(Duration::MAX.saturating_sub(DELAY)),
+        Duration::MAX,
+    );
+    assert!(!timer.expired());
+    let timer = Deadline::new(Stopwatch::with_elapsed(Duration::MAX), Duration::MAX);
+    assert!(timer.expired());
+    Ok(())
+}
+
+#[test]
+fn deadline() {
+    use crate::Deadline;
+
+    let start = Instant::now();
+    let deadline = Deadline::new(Stopwatch::from_raw(DELAY, Some(start)), DELAY * 3);
+    let before = start.checked_add(DELAY).unwrap();
+    let at = start.checked_add(DELAY * 2).unwrap();
+    assert!(!deadline.expired_at(before));
+    assert_eq!(deadline.remaining_at(before), DELAY);
+    assert!(deadline.expired_at(at));
+    assert_eq!(deadline.remaining_at(at), Duration::ZERO);
+    assert_eq!(deadline.deadline_instant(), Some(at));
+
+    let mut deadline = Deadline::new(Stopwatch::new_started(), DELAY);
+    assert!(!deadline.expired());
+    assert!(deadline.remaining() <= DELAY);
+    thread::sleep(DELAY);
+    assert!(deadline.expired());
+    assert_eq!(deadline.remaining(), Duration::ZERO);
+
+    deadline.inner_mut().stop().unwrap();
+    assert_eq!(deadline.deadline_instant(), None);
+}
+
+#[test]
+fn deadline_instant() {
+    use crate::Deadline;
+
+    // expired before starting
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(DELAY * 2, Some(start));
+    assert_eq!(Deadline::new(sw, DELAY).deadline_instant(), Some(start));
+
+    // unrepresentable
+    let sw = Stopwatch::new_started_at(start);
+    assert_eq!(Deadline::new(sw, Duration::MAX).deadline_instant(), None);
+}
+
+#[test]
+fn time_until_saturation() {
+    let near_max = Duration::MAX.checked_sub(DELAY * 10).unwrap();
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(near_max, Some(start));
+    assert_eq!(sw.time_until_saturation_at(start), Some(DELAY * 10));
+    let later = start.checked_add(DELAY * 4).unwrap();
+    assert_eq!(sw.time_until_saturation_at(later), Some(DELAY * 6));
+    assert!(sw.time_until_saturation().unwrap() <= DELAY * 10);
+
+    // saturated
+    let end = start.checked_add(DELAY * 10).unwrap();
+    assert_eq!(sw.time_until_saturation_at(end), None);
+    let past_end = start.checked_add(DELAY * 20).unwrap();
+    assert_eq!(sw.time_until_saturation_at(past_end), None);
+    assert_eq!(
+        Stopwatch::with_elapsed_started(Duration::MAX).time_until_saturation(),
+        None
+    );
+
+    // stopped
+    assert_eq!(
+        Stopwatch::with_elapsed(near_max).time_until_saturation(),
+        None
+    );
+    assert_eq!(Stopwatch::new().time_until_saturation(), None);
+}
+
+#[test]
+fn time_until_saturation_unreachable() {
+    use mock::MockInstant;
+
+    // a u64 nanosecond clock can't reach Duration::MAX
+    let sw = crate::StopwatchImpl::<MockInstant>::new_started();
+    assert_eq!(sw.time_until_saturation(), None);
+}
+
+#[test]
+fn ord_sorts_by_elapsed() {
+    let mut sws: std::vec::Vec<Stopwatch> = [5, 1, 4, 2, 3, 0]
+        .iter()
+        .map(|n| Stopwatch::with_elapsed(DELAY * *n))
+        .collect();
+    sws.sort();
+    let elapsed: std::vec::Vec<Duration> = sws.iter().map(Stopwatch::elapsed).collect();
+    assert_eq!(elapsed, [0, 1, 2, 3, 4, 5].map(|n| DELAY * n));
+
+    // running stopwatches are sorted at a single anchor
+    let start = Instant::now();
+    let anchor = start.checked_add(DELAY * 3).unwrap();
+    sws[1] = Stopwatch::new_started_at(start);
+    sws[4] = Stopwatch::from_raw(Duration::ZERO, Some(anchor));
+    sws.sort_by(|a, b| a.cmp_elapsed_at(b, anchor));
+    let elapsed: std::vec::Vec<Duration> = sws.iter().map(|sw| sw.elapsed_at(anchor)).collect();
+    assert_eq!(elapsed, [0, 0, 2, 3, 3, 5].map(|n| DELAY * n));
+}
+
+#[test]
+fn ord_consistent_with_eq() {
+    use core::cmp::Ordering;
+
+    for [a, b, c] in mixed_stopwatches() {
+        for (lhs, rhs) in [(a, b), (b, c), (a, c)] {
+            assert_eq!(lhs == rhs, lhs.cmp(&rhs) == Ordering::Equal);
+            assert_eq!(lhs.cmp(&rhs), rhs.cmp(&lhs).reverse());
+        }
     }
-    assert_eq!(crafted_1, crafted_2);
+}
 
-    let started = Stopwatch::new_started();
-    let started_elapsed_1 = Stopwatch::with_elapsed_started(Duration::from_secs(1));
-    let started_elapsed_2 = Stopwatch::with_elapsed_started(Duration::from_secs(2));
+#[test]
+fn cmp_elapsed_at() {
+    use core::cmp::Ordering;
 
-    let overflowing_1;
-    let overflowing_2;
-    {
-        let start_1 = Instant::now();
-        let start_2 = <Instant as crate::Instant>::checked_sub(&start_1, DELAY).unwrap();
-        overflowing_1 = Stopwatch::from_raw(Duration::MAX, Some(start_1));
-        overflowing_2 = Stopwatch::from_raw(Duration::MAX, Some(start_2));
+    let start = Instant::now();
+    let later = start.checked_add(DELAY).unwrap();
+    let anchor = start.checked_add(DELAY * 3).unwrap();
+
+    // started at the same instant
+    let a = Stopwatch::new_started_at(start);
+    let b = Stopwatch::new_started_at(start);
+    assert_eq!(a.cmp_elapsed_at(&b, anchor), Ordering::Equal);
+
+    let c = Stopwatch::new_started_at(later);
+    assert_eq!(a.cmp_elapsed_at(&c, anchor), Ordering::Greater);
+    assert_eq!(c.cmp_elapsed_at(&a, anchor), Ordering::Less);
+
+    // running state isn't a tie-breaker
+    let stopped = Stopwatch::with_elapsed(DELAY * 3);
+    assert_eq!(a.cmp_elapsed_at(&stopped, anchor), Ordering::Equal);
+    assert_eq!(a.cmp_at(&stopped, anchor), Ordering::Greater);
+
+    // anchors before the start saturate
+    let c = Stopwatch::from_raw(DELAY, Some(later));
+    assert_eq!(c.cmp_elapsed_at(&a, start), Ordering::Greater);
+    assert_eq!(
+        c.cmp_elapsed_at(&Stopwatch::with_elapsed(DELAY), start),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn cmp_at() {
+    use core::cmp::Ordering;
+
+    let start = Instant::now();
+    let running = Stopwatch::new_started_at(start);
+    let stopped = Stopwatch::with_elapsed(DELAY);
+    let anchor = |n| start.checked_add(DELAY * n).unwrap();
+    assert_eq!(running.cmp_at(&stopped, start), Ordering::Less);
+    assert_eq!(running.cmp_at(&stopped, anchor(1)), Ordering::Greater);
+    assert_eq!(stopped.cmp_at(&running, anchor(1)), Ordering::Less);
+    assert_eq!(running.cmp_at(&stopped, anchor(2)), Ordering::Greater);
+    assert_eq!(running.cmp_at(&running, anchor(2)), Ordering::Equal);
+}
+
+#[test]
+fn replace_state_at() {
+    let start = Instant::now();
+    let anchor = start.checked_add(DELAY).unwrap();
+
+    for (was_running, run) in [(false, false), (false, true), (true, false), (true, true)] {
+        let mut sw = if was_running {
+            Stopwatch::from_raw(DELAY, Some(start))
+        } else {
+            Stopwatch::with_elapsed(DELAY)
+        };
+        let expected_elapsed = if was_running { DELAY * 2 } else { DELAY };
+
+        let previous = sw.replace_state_at(DELAY * 5, run, anchor);
+        assert_eq!(previous, (expected_elapsed, was_running));
+        assert_eq!(sw.is_running(), run);
+        assert_eq!(sw.elapsed_at(anchor), DELAY * 5);
+        let later = anchor.checked_add(DELAY).unwrap();
+        let expected_later = if run { DELAY * 6 } else { DELAY * 5 };
+        assert_eq!(sw.elapsed_at(later), expected_later);
     }
+}
 
-    [
-        [Stopwatch::new(), Stopwatch::new(), Stopwatch::new()],
-        [started, started, started],
-        [started, Stopwatch::new(), Stopwatch::new()],
-        [
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-        ],
-        [started_elapsed_1, started_elapsed_1, started_elapsed_1],
-        [started_elapsed_1, started_elapsed_2, started_elapsed_1],
-        [overflowing_1, overflowing_2, started],
-        [
-            started_elapsed_1,
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-        ],
-        [
-            started_elapsed_2,
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-        ],
-        [
-            Stopwatch::with_elapsed(Duration::from_secs(1)),
-            Stopwatch::with_elapsed(Duration::from_secs(2)),
-            Stopwatch::with_elapsed(Duration::from_secs(3)),
-        ],
+#[test]
+fn replace_state() {
+    let mut sw = Stopwatch::new();
+    assert_eq!(sw.replace_state(DELAY, true), (Duration::ZERO, false));
+    assert!(sw.is_running());
+    let (previous, was_running) = sw.replace_state(Duration::ZERO, false);
+    assert!(previous >= DELAY);
+    assert!(was_running);
+    assert_eq!(sw, Stopwatch::new());
+}
+
+#[test]
+fn abs_diff() {
+    let a = Stopwatch::with_elapsed(DELAY);
+    let b = Stopwatch::with_elapsed(DELAY * 3);
+    assert_eq!(a.abs_diff(&b), DELAY * 2);
+    assert_eq!(b.abs_diff(&a), DELAY * 2);
+    assert_eq!(a.abs_diff(&a), Duration::ZERO);
+}
+
+#[test]
+fn abs_diff_at() {
+    let start = Instant::now();
+    let a = Stopwatch::new_started_at(start);
+    let b = Stopwatch::from_raw(DELAY, Some(start));
+    let c = Stopwatch::with_elapsed(DELAY * 4);
+    let anchor = start.checked_add(DELAY * 2).unwrap();
+    assert_eq!(a.abs_diff_at(&b, anchor), DELAY);
+    assert_eq!(b.abs_diff_at(&a, anchor), DELAY);
+    assert_eq!(a.abs_diff_at(&c, anchor), DELAY * 2);
+    assert_eq!(c.abs_diff_at(&a, anchor), DELAY * 2);
+}
+
+#[cfg(feature = "std_systemtime")]
+#[test]
+fn elapsed_cmp_across_instants() {
+    use core::cmp::Ordering;
+
+    let sw = Stopwatch::with_elapsed(DELAY);
+    let system = crate::SystemSw::with_elapsed(DELAY);
+    assert_eq!(sw.elapsed_cmp(&system), Ordering::Equal);
+    assert_eq!(system.elapsed_cmp(&sw), Ordering::Equal);
+
+    let longer = crate::SystemSw::with_elapsed(DELAY * 2);
+    assert_eq!(sw.elapsed_cmp(&longer), Ordering::Less);
+    assert_eq!(longer.elapsed_cmp(&sw), Ordering::Greater);
+}
+
+#[test]
+fn auto_lap_if() {
+    let mut sw = Stopwatch::new();
+    assert_eq!(sw.auto_lap_if(Duration::ZERO), None);
+
+    sw.start().unwrap();
+    assert_eq!(sw.auto_lap_if(DELAY * 10), None);
+    thread::sleep(DELAY);
+    let lap = sw.auto_lap_if(DELAY / 2).unwrap();
+    assert!(lap >= DELAY);
+    assert!(sw.is_running());
+    assert!(sw.elapsed() >= lap);
+    assert_eq!(sw.auto_lap_if(DELAY * 10), None);
+}
+
+#[test]
+fn auto_lap_if_at() {
+    let start = Instant::now();
+    let mut sw = Stopwatch::from_raw(DELAY, Some(start));
+
+    let before = start.checked_add(DELAY).unwrap();
+    assert_eq!(sw.auto_lap_if_at(DELAY, before), None);
+    assert_eq!(sw.auto_lap_if_at(DELAY * 2, before), None);
+    assert_eq!(sw, Stopwatch::from_raw(DELAY, Some(start)));
+
+    let after = start.checked_add(DELAY * 3).unwrap();
+    assert_eq!(sw.auto_lap_if_at(DELAY * 2, after), Some(DELAY * 3));
+    assert_eq!(sw, Stopwatch::from_raw(DELAY * 4, Some(after)));
+    assert_eq!(sw.auto_lap_if_at(Duration::ZERO, start), None);
+}
+
+#[test]
+fn format_localized() {
+    let sw = Stopwatch::with_elapsed(Duration::from_millis(1500));
+    assert_eq!(sw.format_localized(',', " "), "1,500 s");
+    assert_eq!(sw.format_localized('.', ""), "1.500s");
+
+    let sw = Stopwatch::with_elapsed(Duration::new(62, 7_999_999));
+    assert_eq!(
+        sw.format_localized('\u{66b}', "\u{a0}"),
+        "62\u{66b}007\u{a0}s"
+    );
+    assert_eq!(Stopwatch::new().format_localized(',', " "), "0,000 s");
+}
+
+#[test]
+fn chrono_aliases() {
+    for elapsed in [
+        Duration::ZERO,
+        DELAY,
+        Duration::new(7, 999_999_999),
+        Duration::from_secs(86_400),
+    ] {
+        let sw = Stopwatch::with_elapsed(elapsed);
+        assert_eq!(
+            sw.num_milliseconds(),
+            i64::try_from(elapsed.as_millis()).unwrap()
+        );
+        assert_eq!(sw.num_seconds(), i64::try_from(elapsed.as_secs()).unwrap());
+    }
+
+    let max = Stopwatch::with_elapsed(Duration::MAX);
+    assert_eq!(max.num_milliseconds(), i64::MAX);
+    assert_eq!(max.num_seconds(), i64::MAX);
+}
+
+#[test]
+fn elapsed_millis_u32_saturating() {
+    let millis = |dur| Stopwatch::with_elapsed(dur).elapsed_millis_u32_saturating();
+    assert_eq!(millis(Duration::ZERO), 0);
+    assert_eq!(millis(Duration::from_micros(999)), 0);
+    assert_eq!(millis(DELAY), 100);
+    assert_eq!(millis(Duration::from_millis(u32::MAX.into())), u32::MAX);
+    assert_eq!(
+        millis(Duration::from_millis(u64::from(u32::MAX) + 1)),
+        u32::MAX
+    );
+    assert_eq!(millis(Duration::MAX), u32::MAX);
+}
+
+#[test]
+fn elapsed_clamped_range() {
+    let (min, max) = (DELAY, DELAY * 3);
+    let below = Stopwatch::with_elapsed(DELAY / 2);
+    let within = Stopwatch::with_elapsed(DELAY * 2);
+    let above = Stopwatch::with_elapsed(DELAY * 4);
+    assert_eq!(below.elapsed_clamped_range(min, max), min);
+    assert_eq!(within.elapsed_clamped_range(min, max), DELAY * 2);
+    assert_eq!(above.elapsed_clamped_range(min, max), max);
+}
+
+#[test]
+fn elapsed_clamped_range_at() {
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    let anchor = start.checked_add(DELAY * 2).unwrap();
+    assert_eq!(
+        sw.elapsed_clamped_range_at(DELAY, DELAY * 3, anchor),
+        DELAY * 2
+    );
+    assert_eq!(
+        sw.elapsed_clamped_range_at(DELAY * 3, DELAY * 4, anchor),
+        DELAY * 3
+    );
+    assert_eq!(
+        sw.elapsed_clamped_range_at(Duration::ZERO, DELAY, anchor),
+        DELAY
+    );
+}
+
+#[test]
+fn regressed_from() {
+    let baseline = DELAY;
+    let slack = DELAY / 10;
+    let within = Stopwatch::with_elapsed(DELAY + DELAY / 20);
+    let at = Stopwatch::with_elapsed(DELAY + slack);
+    let beyond = Stopwatch::with_elapsed(DELAY + slack + Duration::from_nanos(1));
+    assert!(!Stopwatch::new().regressed_from(baseline, slack));
+    assert!(!within.regressed_from(baseline, slack));
+    assert!(!at.regressed_from(baseline, slack));
+    assert!(beyond.regressed_from(baseline, slack));
+    assert!(!Stopwatch::with_elapsed(Duration::MAX).regressed_from(Duration::MAX, slack));
+}
+
+#[test]
+fn regressed_from_at() {
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    let at = start.checked_add(DELAY * 2).unwrap();
+    let beyond = start.checked_add(DELAY * 3).unwrap();
+    assert!(!sw.regressed_from_at(DELAY, DELAY, start));
+    assert!(!sw.regressed_from_at(DELAY, DELAY, at));
+    assert!(sw.regressed_from_at(DELAY, DELAY, beyond));
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn elapsed_clamped_range_inverted() {
+    let _ = Stopwatch::new().elapsed_clamped_range(DELAY * 2, DELAY);
+}
+
+#[test]
+fn display() {
+    let sw = Stopwatch::with_elapsed(Duration::from_millis(3_723_045));
+    assert_eq!(sw.to_string(), "01:02:03.045 (stopped)");
+    assert_eq!(Stopwatch::new().to_string(), "00:00:00.000 (stopped)");
+
+    let days = Stopwatch::with_elapsed(Duration::new(50 * 3600 + 59, 999_999_999));
+    assert_eq!(days.to_string(), "50:00:59.999 (stopped)");
+
+    let running = Stopwatch::with_elapsed_started(Duration::from_secs(61));
+    let display = running.to_string();
+    assert!(display.starts_with("00:01:01."));
+    assert!(display.ends_with(" (running)"));
+}
+
+#[test]
+fn format_progress() {
+    let budget = Duration::from_secs(60);
+    let progress =
+        |secs| Stopwatch::with_elapsed(Duration::from_secs(secs)).format_progress(budget);
+    assert_eq!(progress(0), "00:00 / 01:00 (0%)");
+    assert_eq!(progress(15), "00:15 / 01:00 (25%)");
+    assert_eq!(progress(45), "00:45 / 01:00 (75%)");
+    assert_eq!(progress(60), "01:00 / 01:00 (100%)");
+    assert_eq!(progress(90), "01:30 / 01:00 (150%)");
+    assert_eq!(progress(3723), "01:02:03 / 00:01:00 (6205%)");
+
+    let sw = Stopwatch::with_elapsed(Duration::from_millis(59_999));
+    assert_eq!(sw.format_progress(budget), "00:59 / 01:00 (99%)");
+    assert_eq!(sw.format_progress(Duration::ZERO), "00:59 / 00:00 (100%)");
+}
+
+#[test]
+fn debug_line() {
+    let stopped = Stopwatch::with_elapsed(Duration::from_nanos(123_456));
+    assert_eq!(stopped.debug_line(), "sw[running=false elapsed_ns=123456]");
+    assert_eq!(
+        Stopwatch::new().debug_line(),
+        "sw[running=false elapsed_ns=0]"
+    );
+
+    let running = Stopwatch::with_elapsed_started(DELAY);
+    let line = running.debug_line();
+    let elapsed_ns = line
+        .strip_prefix("sw[running=true elapsed_ns=")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap();
+    assert!(elapsed_ns.parse::<u128>().unwrap() >= DELAY.as_nanos());
+}
+
+#[test]
+fn write_debug_line() {
+    let sw = Stopwatch::with_elapsed(Duration::from_nanos(7));
+    let mut line = std::string::String::from("> ");
+    sw.write_debug_line(&mut line).unwrap();
+    assert_eq!(line, "> sw[running=false elapsed_ns=7]");
+}
+
+#[test]
+fn elapsed_full() {
+    let sw = Stopwatch::with_elapsed(DELAY);
+    let elapsed = sw.elapsed_full();
+    assert!(!elapsed.saturated);
+    assert_eq!(*elapsed, DELAY);
+    assert_eq!(elapsed.as_millis(), 100);
+
+    // genuine maximum is not saturated
+    let elapsed = Stopwatch::with_elapsed(Duration::MAX).elapsed_full();
+    assert!(!elapsed.saturated);
+    assert_eq!(*elapsed, Duration::MAX);
+}
+
+#[test]
+fn elapsed_full_saturated() {
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(Duration::MAX, Some(start));
+    let elapsed = sw.elapsed_full_at(start.checked_add(DELAY).unwrap());
+    assert!(elapsed.saturated);
+    assert_eq!(Duration::from(elapsed), Duration::MAX);
+    assert_eq!(<(Duration, bool)>::from(elapsed), (Duration::MAX, true));
+}
+
+#[test]
+fn elapsed_checked_flagged() {
+    let sw = Stopwatch::with_elapsed(Duration::MAX);
+    assert_eq!(sw.elapsed_checked_flagged(), (Duration::MAX, false));
+
+    let start = Instant::now();
+    let sw = Stopwatch::from_raw(Duration::MAX, Some(start));
+    assert_eq!(sw.elapsed_checked_flagged_at(start), (Duration::MAX, false));
+    assert_eq!(
+        sw.elapsed_checked_flagged_at(start.checked_add(DELAY).unwrap()),
+        (Duration::MAX, true)
+    );
+}
+
+#[test]
+fn elapsed_precise() {
+    let sw = Stopwatch::new_started();
+    thread::sleep(DELAY);
+    let (elapsed, jitter) = sw.elapsed_precise();
+    assert!(elapsed >= DELAY);
+    assert!(jitter < DELAY);
+
+    let sw = Stopwatch::with_elapsed(DELAY);
+    for samples in [0, 1, 2, 16] {
+        let (elapsed, jitter) = sw.elapsed_precise_sampled(samples);
+        assert_eq!(elapsed, DELAY);
+        assert!(jitter < DELAY);
+    }
+}
+
+#[test]
+fn elapsed_in_units() {
+    let frame = Duration::from_nanos(16_666_667);
+
+    assert_eq!(Stopwatch::new().elapsed_in_units(frame), 0);
+    assert_eq!(Stopwatch::with_elapsed(frame).elapsed_in_units(frame), 1);
+    assert_eq!(
+        Stopwatch::with_elapsed(Duration::from_nanos(16_666_667 * 60 - 1)).elapsed_in_units(frame),
+        59
+    );
+    assert_eq!(
+        Stopwatch::with_elapsed(frame * 60).elapsed_in_units(frame),
+        60
+    );
+    assert_eq!(
+        Stopwatch::with_elapsed(Duration::MAX).elapsed_in_units(Duration::from_nanos(1)),
+        u64::MAX
+    );
+}
+
+#[test]
+fn elapsed_in_units_at() {
+    let frame = Duration::from_nanos(16_666_667);
+    let start = Instant::now();
+    let sw = Stopwatch::new_started_at(start);
+    let anchor = start.checked_add(frame * 10).unwrap();
+    assert_eq!(sw.elapsed_in_units_at(frame, anchor), 10);
+}
+
+#[test]
+#[should_panic(expected = "unit of time must be non-zero")]
+fn elapsed_in_units_zero() {
+    _ = Stopwatch::new().elapsed_in_units(Duration::ZERO);
+}
+
+#[test]
+fn fixed_quantizes() {
+    let inner = Stopwatch::with_elapsed(Duration::new(1, 234_567_891));
+    assert_eq!(
+        crate::FixedStopwatch::<_, 0>::from_inner(inner).elapsed(),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        crate::FixedStopwatch::<_, 3>::from_inner(inner).elapsed(),
+        Duration::from_millis(1234)
+    );
+    assert_eq!(
+        crate::FixedStopwatch::<_, 9>::from_inner(inner).elapsed(),
+        inner.elapsed()
+    );
+    assert_eq!(
+        crate::FixedStopwatch::<_, 12>::from_inner(inner).elapsed(),
+        inner.elapsed()
+    );
+    assert_eq!(
+        crate::FixedStopwatch::<Instant, 3>::from_inner(Stopwatch::with_elapsed(Duration::MAX))
+            .elapsed(),
+        Duration::new(u64::MAX, 999_000_000)
+    );
+}
+
+#[test]
+fn fixed_same_millisecond() {
+    use mock::MockInstant;
+
+    let mut sw = crate::FixedStopwatch::<MockInstant, 3>::new();
+    mock::advance(Duration::from_millis(5));
+    sw.start().unwrap();
+    mock::advance(Duration::from_micros(1_200));
+    let first = sw.elapsed();
+    mock::advance(Duration::from_micros(700));
+    let second = sw.elapsed();
+    assert_eq!(first, second);
+    assert_eq!(first, Duration::from_millis(1));
+
+    mock::advance(Duration::from_micros(100));
+    assert_eq!(sw.elapsed(), Duration::from_millis(2));
+}
+
+#[test]
+fn fixed_at() -> crate::Result<()> {
+    let start = Instant::now();
+    let mut sw = crate::FixedStopwatch::<Instant, 1>::default();
+    sw.start_at(start)?;
+    sw.stop_at(start.checked_add(Duration::from_millis(1_250)).unwrap())?;
+    assert_eq!(sw.elapsed(), Duration::from_millis(1_200));
+    assert_eq!(sw.stop_at(start), Err(Error::SwStop));
+
+    sw.reset();
+    assert_eq!(sw, crate::FixedStopwatch::new());
+    Ok(())
+}
+
+#[test]
+fn signed_negative() {
+    let mut sw = crate::SignedStopwatch::<Instant>::new();
+    sw -= DELAY * 3;
+    assert_eq!(sw.elapsed_nanos(), -300_000_000);
+    assert!(sw.is_negative());
+
+    sw += DELAY;
+    assert_eq!(sw.elapsed_nanos(), -200_000_000);
+
+    assert_eq!((sw + DELAY * 3).elapsed_nanos(), 100_000_000);
+    sw += DELAY * 3;
+    assert!(!sw.is_negative());
+
+    sw.reset();
+    assert_eq!(sw.elapsed_nanos(), 0);
+    assert_eq!(sw, crate::SignedStopwatch::default());
+}
+
+#[test]
+fn signed_running() -> crate::Result<()> {
+    use mock::MockInstant;
+
+    let mut sw = crate::SignedStopwatch::<MockInstant>::with_elapsed_nanos(-150_000_000);
+    sw.start()?;
+    mock::advance(DELAY);
+    assert_eq!(sw.elapsed_nanos(), -50_000_000);
+    mock::advance(DELAY);
+    sw.stop()?;
+    assert_eq!(sw.elapsed_nanos(), 50_000_000);
+
+    assert_eq!((sw - DELAY).elapsed_nanos(), -50_000_000);
+    Ok(())
+}
+
+#[test]
+fn signed_eq_hash() {
+    use crate::SignedStopwatch;
+
+    fn hash_of(sw: &SignedStopwatch<Instant>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        sw.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // stopped stopwatches compare by total, however it was accumulated
+    let a = SignedStopwatch::<Instant>::with_elapsed_nanos(100_000_000);
+    let b = SignedStopwatch::from(Stopwatch::with_elapsed(DELAY));
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    assert_ne!(a, a - DELAY);
+
+    // running stopwatches compare by signed elapsed time at any instant
+    let start = Instant::now();
+    let later = start.checked_add(DELAY).unwrap();
+    let a = SignedStopwatch::from(Stopwatch::new_started_at(start)) - DELAY;
+    let b = SignedStopwatch::from(Stopwatch::new_started_at(later));
+    let c = SignedStopwatch::from(Stopwatch::from_raw(DELAY, Some(later))) - DELAY;
+    assert_eq!(a, b);
+    assert_eq!(a, c);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    assert_eq!(hash_of(&a), hash_of(&c));
+    assert_ne!(a, b + DELAY);
+
+    // running and stopped stopwatches are never equal
+    let stopped = SignedStopwatch::<Instant>::with_elapsed_nanos(a.elapsed_nanos_at(start));
+    assert_ne!(
+        SignedStopwatch::from(Stopwatch::new_started_at(start)),
+        stopped
+    );
+}
+
+#[test]
+fn windowed_rolls_over() -> crate::Result<()> {
+    use mock::MockInstant;
+    use std::cell::RefCell;
+    use std::vec::Vec;
+
+    thread_local! {
+        static COMPLETED: RefCell<Vec<Duration>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(total: Duration) {
+        COMPLETED.with(|completed| completed.borrow_mut().push(total));
+    }
+
+    let mut sw = crate::WindowedStopwatch::<MockInstant>::new(DELAY * 10).with_callback(record);
+    mock::advance(DELAY * 2);
+    sw.start()?;
+    mock::advance(DELAY * 3);
+    assert_eq!(sw.elapsed(), DELAY * 3);
+
+    // cross into the second window while running
+    mock::advance(DELAY * 7);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+    assert!(sw.is_running());
+
+    sw.stop()?;
+    // skip over the third window entirely
+    mock::advance(DELAY * 20);
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+
+    COMPLETED.with(|completed| {
+        assert_eq!(*completed.borrow(), [DELAY * 8, DELAY * 2, Duration::ZERO]);
+    });
+    Ok(())
+}
+
+#[test]
+fn windowed_boundary() -> crate::Result<()> {
+    let start = Instant::now();
+    let mut sw = crate::WindowedStopwatch::new_at(DELAY, start);
+    sw.start_at(start)?;
+
+    let boundary = start.checked_add(DELAY).unwrap();
+    assert_eq!(sw.elapsed_at(boundary), Duration::ZERO);
+    assert_eq!(sw.window_start(), boundary);
+    Ok(())
+}
+
+#[test]
+fn windowed_long_idle() -> crate::Result<()> {
+    use std::cell::RefCell;
+    use std::vec::Vec;
+
+    thread_local! {
+        static COMPLETED: RefCell<Vec<Duration>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(total: Duration) {
+        COMPLETED.with(|completed| completed.borrow_mut().push(total));
+    }
+
+    // billions of windows pass, which must not be rolled over one by one
+    let start = Instant::now();
+    let window = Duration::from_nanos(3);
+    let mut sw = crate::WindowedStopwatch::new_at(window, start).with_callback(record);
+    sw.start_at(start)?;
+
+    let anchor = start
+        .checked_add(Duration::from_nanos(10_000_000_001))
+        .unwrap();
+    assert_eq!(sw.elapsed_at(anchor), Duration::from_nanos(2));
+    assert_eq!(
+        sw.window_start(),
+        start
+            .checked_add(Duration::from_nanos(9_999_999_999))
+            .unwrap()
+    );
+
+    // the window in progress, then every skipped window at once
+    COMPLETED.with(|completed| {
+        assert_eq!(
+            *completed.borrow(),
+            [window, Duration::from_nanos(9_999_999_996)]
+        );
+    });
+    Ok(())
+}
+
+#[test]
+fn dto_round_trip_stopped() {
+    let sw = Stopwatch::with_elapsed(Duration::from_millis(1234));
+    let dto = StopwatchDto::from(sw);
+    assert_eq!(
+        dto,
+        StopwatchDto {
+            elapsed_ms: 1234,
+            running: false,
+        }
+    );
+    assert_eq!(Stopwatch::from(dto), sw);
+
+    // sub-millisecond precision is truncated
+    let sw = Stopwatch::with_elapsed(Duration::from_micros(1_234_567));
+    let dto = StopwatchDto::from(sw);
+    assert_eq!(dto.elapsed_ms, 1234);
+    assert_eq!(Stopwatch::from(dto).elapsed(), Duration::from_millis(1234));
+}
+
+#[test]
+fn dto_round_trip_running() {
+    let sw = Stopwatch::with_elapsed_started(DELAY);
+    let dto = StopwatchDto::from(sw);
+    assert!(dto.running);
+    assert!(dto.elapsed_ms >= 100);
+
+    let sw = Stopwatch::from(dto);
+    assert!(sw.is_running());
+    assert!(sw.elapsed() >= Duration::from_millis(dto.elapsed_ms));
+}
+
+#[test]
+fn dto_saturates() {
+    let dto = StopwatchDto::from(Stopwatch::with_elapsed(Duration::MAX));
+    assert_eq!(dto.elapsed_ms, u64::MAX);
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde_round_trip_stopped() {
+    let sw = Stopwatch::with_elapsed(Duration::new(3, 500));
+    let json = serde_json::to_value(sw).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "elapsed": { "secs": 3, "nanos": 500 }, "running": false }),
+    );
+    assert_eq!(serde_json::from_value::<Stopwatch>(json).unwrap(), sw);
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde_round_trip_running() {
+    let sw = Stopwatch::with_elapsed_started(DELAY);
+    let json = serde_json::to_string(&sw).unwrap();
+    thread::sleep(DELAY);
+
+    let loaded: Stopwatch = serde_json::from_str(&json).unwrap();
+    assert!(loaded.is_running());
+    assert!(loaded.elapsed() >= DELAY);
+    assert!(loaded.elapsed() < sw.elapsed());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde_round_trip_dto() {
+    let dto = StopwatchDto {
+        elapsed_ms: 1500,
+        running: true,
+    };
+    let json = serde_json::to_value(dto).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "elapsed_ms": 1500, "running": true }),
+    );
+    assert_eq!(serde_json::from_value::<StopwatchDto>(json).unwrap(), dto);
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde_round_trip_saturated() {
+    let sw = Stopwatch::with_elapsed_started(Duration::MAX);
+    thread::sleep(DELAY);
+    let json = serde_json::to_string(&sw).unwrap();
+    let loaded: Stopwatch = serde_json::from_str(&json).unwrap();
+    assert!(loaded.is_running());
+    assert_eq!(loaded.elapsed(), Duration::MAX);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn to_json_value() {
+    let stopped = Stopwatch::with_elapsed(Duration::from_millis(1500));
+    assert_eq!(
+        stopped.to_json_value(),
+        serde_json::json!({ "elapsed_ms": 1500, "running": false }),
+    );
+
+    let running = Stopwatch::with_elapsed_started(DELAY);
+    let json = running.to_json_value();
+    let object = json.as_object().unwrap();
+    assert_eq!(object.len(), 2);
+    assert!(object["elapsed_ms"].as_u64().unwrap() >= 100);
+    assert_eq!(object["running"], true);
+}
+
+#[cfg(feature = "std_systemtime")]
+#[test]
+fn utilization() {
+    let wall = crate::SystemSw::with_elapsed(DELAY * 4);
+    assert_approx_eq(
+        crate::utilization(&Stopwatch::with_elapsed(DELAY), &wall),
+        0.25,
+    );
+    assert_approx_eq(
+        crate::utilization(&Stopwatch::with_elapsed(DELAY * 4), &wall),
+        1.0,
+    );
+    assert_approx_eq(crate::utilization(&Stopwatch::new(), &wall), 0.0);
+    assert_approx_eq(
+        crate::utilization(&Stopwatch::with_elapsed(DELAY), &crate::SystemSw::new()),
+        0.0,
+    );
+}
+
+#[test]
+fn backoff_timer() {
+    use crate::BackoffTimer;
+    use mock::MockInstant;
+
+    let mut timer = BackoffTimer::<MockInstant>::new(DELAY, 3, DELAY * 20);
+    assert!(!timer.is_waiting());
+    assert!(timer.ready());
+    assert_eq!(timer.remaining(), Duration::ZERO);
+
+    for (attempt, delay) in [DELAY, DELAY * 3, DELAY * 9, DELAY * 20, DELAY * 20]
+        .into_iter()
+        .enumerate()
+    {
+        timer.retry();
+        assert_eq!(timer.attempt(), u32::try_from(attempt).unwrap());
+        assert_eq!(timer.next_delay(), delay);
+        assert!(!timer.ready());
+        assert_eq!(timer.remaining(), delay);
+
+        mock::advance(delay / 2);
+        assert!(!timer.ready());
+        assert_eq!(timer.elapsed(), delay / 2);
+        mock::advance(delay / 2);
+        assert!(timer.ready());
+        assert_eq!(timer.remaining(), Duration::ZERO);
+        mock::advance(DELAY);
+        assert!(timer.ready());
+    }
+
+    timer.reset();
+    assert_eq!(timer.attempt(), 0);
+    assert!(timer.ready());
+    timer.retry();
+    assert_eq!(timer.next_delay(), DELAY);
+
+    // overflowing delays are capped
+    let mut timer = BackoffTimer::<MockInstant>::new(Duration::MAX, 2, DELAY);
+    timer.retry();
+    assert_eq!(timer.next_delay(), DELAY);
+    for _ in 0..40 {
+        timer.retry();
+    }
+    assert_eq!(timer.next_delay(), DELAY);
+}
+
+#[test]
+fn speed_factor() -> crate::Result<()> {
+    use mock::MockInstant;
+
+    let real_start = Instant::now();
+    let mut sim = crate::StopwatchImpl::<MockInstant>::new_started();
+    for n in 1..=4 {
+        // simulation runs at 10x real time
+        mock::advance(DELAY * 10);
+        let real_now = real_start.checked_add(DELAY * n).unwrap();
+        assert_approx_eq(crate::speed_factor(&sim, real_start, real_now), 10.0);
+    }
+
+    sim.stop()?;
+    let real_now = real_start.checked_add(DELAY * 8).unwrap();
+    assert_approx_eq(crate::speed_factor(&sim, real_start, real_now), 5.0);
+    assert_approx_eq(crate::speed_factor(&sim, real_start, real_start), 0.0);
+    assert_approx_eq(crate::speed_factor(&sim, real_now, real_start), 0.0);
+    Ok(())
+}
+
+#[test]
+fn relative_progress() {
+    let t0 = Instant::now();
+    let t1 = t0.checked_add(DELAY * 4).unwrap();
+
+    // same rate
+    let a = Stopwatch::from_raw(DELAY * 7, Some(t0));
+    let b = Stopwatch::new_started_at(t0);
+    assert_approx_eq(crate::relative_progress(&a, &b, t0, t1), 1.0);
+
+    // `a` only runs for the second half
+    let a = Stopwatch::new_started_at(t0.checked_add(DELAY * 2).unwrap());
+    assert_approx_eq(crate::relative_progress(&a, &b, t0, t1), 0.5);
+    assert_approx_eq(crate::relative_progress(&b, &a, t0, t1), 2.0);
+
+    // `b` didn't advance
+    assert_approx_eq(crate::relative_progress(&b, &a, t1, t1), 0.0);
+    assert_approx_eq(crate::relative_progress(&a, &b, t1, t0), 0.0);
+    assert_approx_eq(crate::relative_progress(&b, &Stopwatch::new(), t0, t1), 0.0);
+}
+
+#[cfg(feature = "global_clock")]
+#[test]
+fn global_clock() -> crate::Result<()> {
+    use crate::GlobalSw;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    static TICKS: AtomicU64 = AtomicU64::new(0);
+
+    // every read advances the counter by one millisecond
+    fn counter() -> u64 {
+        TICKS.fetch_add(1_000_000, Ordering::SeqCst)
+    }
+
+    // this is the only test registering a global clock
+    assert!(crate::set_global_clock(&(counter as fn() -> u64)).is_ok());
+    assert!(crate::has_global_clock());
+    assert!(crate::set_global_clock(&(counter as fn() -> u64)).is_err());
+
+    let mut sw = GlobalSw::new_started();
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), Duration::from_millis(1));
+    Ok(())
+}
+
+#[cfg(feature = "global_clock")]
+#[test]
+fn global_instant_nanos() {
+    use crate::{GlobalInstant, Instant as _};
+
+    let instant = GlobalInstant::from(42_u64);
+    assert_eq!(instant, GlobalInstant::from_nanos(42));
+    assert_eq!(u64::from(instant), 42);
+
+    assert_eq!(
+        instant.checked_add(Duration::from_nanos(8)),
+        Some(GlobalInstant::from_nanos(50))
+    );
+    assert_eq!(instant.checked_sub(Duration::from_nanos(43)), None);
+    assert_eq!(
+        GlobalInstant::from_nanos(u64::MAX).checked_add(Duration::from_nanos(1)),
+        None
+    );
+    assert_eq!(instant.checked_add(Duration::MAX), None);
+    assert_eq!(
+        GlobalInstant::from_nanos(50).saturating_duration_since(instant),
+        Duration::from_nanos(8)
+    );
+    assert_eq!(
+        instant.saturating_duration_since(GlobalInstant::from_nanos(50)),
+        Duration::ZERO
+    );
+}
+
+#[cfg(feature = "cpu_time")]
+#[test]
+fn cpu_time_busy() -> crate::Result<()> {
+    let mut sw = crate::CpuSw::new_started();
+    let wall = Instant::now();
+    let mut acc: u64 = 0;
+    while wall.elapsed() < DELAY {
+        acc = core::hint::black_box(acc.wrapping_add(1));
+    }
+    sw.stop()?;
+    assert!(sw.elapsed() > Duration::ZERO);
+    Ok(())
+}
+
+#[cfg(feature = "boottime")]
+#[test]
+fn boottime_monotonic() -> crate::Result<()> {
+    use crate::{BoottimeInstant, Instant as _};
+
+    let mut prev = BoottimeInstant::now();
+    for _ in 0..1000 {
+        let now = BoottimeInstant::now();
+        assert!(now >= prev);
+        prev = now;
+    }
+
+    let mut sw = crate::BoottimeSw::new_started();
+    thread::sleep(DELAY);
+    sw.stop()?;
+    assert!(sw.elapsed() >= DELAY);
+    Ok(())
+}
+
+#[cfg(feature = "no_inline_elapsed")]
+#[test]
+fn no_inline_elapsed_compiles() -> crate::Result<()> {
+    let mut sw = Stopwatch::new();
+    sw.start()?;
+    sw.stop()?;
+    sw.reset();
+    let now = Instant::now();
+    sw.start_at(now)?;
+    sw.stop_at(now)?;
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    assert_eq!(sw.elapsed_at(now), Duration::ZERO);
+    assert_eq!(sw.checked_elapsed(), Some(Duration::ZERO));
+    assert_eq!(sw.checked_elapsed_at(now), Some(Duration::ZERO));
+    Ok(())
+}
+
+#[cfg(feature = "opentelemetry")]
+#[test]
+fn otel_guard_records_elapsed() -> crate::Result<()> {
+    use opentelemetry::trace::{Span, SpanContext, Status};
+    use opentelemetry::{KeyValue, Value};
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::SystemTime;
+    use std::vec::Vec;
+
+    #[derive(Default)]
+    struct Recorded {
+        attributes: Vec<KeyValue>,
+        ended: bool,
+    }
+
+    struct TestSpan(Rc<RefCell<Recorded>>);
+
+    impl Span for TestSpan {
+        fn add_event_with_timestamp<T>(&mut self, _: T, _: SystemTime, _: Vec<KeyValue>)
+        where
+            T: Into<Cow<'static, str>>,
+        {
+        }
+
+        fn span_context(&self) -> &SpanContext {
+            &SpanContext::NONE
+        }
+
+        fn is_recording(&self) -> bool {
+            !self.0.borrow().ended
+        }
+
+        fn set_attribute(&mut self, attribute: KeyValue) {
+            self.0.borrow_mut().attributes.push(attribute);
+        }
+
+        fn set_status(&mut self, _: Status) {}
+
+        fn update_name<T>(&mut self, _: T)
+        where
+            T: Into<Cow<'static, str>>,
+        {
+        }
+
+        fn add_link(&mut self, _: SpanContext, _: Vec<KeyValue>) {}
+
+        fn end_with_timestamp(&mut self, _: SystemTime) {
+            self.0.borrow_mut().ended = true;
+        }
+    }
+
+    let recorded = Rc::new(RefCell::new(Recorded::default()));
+    let mut sw = Stopwatch::new();
+    {
+        let _guard = sw.guard_otel(TestSpan(Rc::clone(&recorded)))?;
+        thread::sleep(DELAY);
+    }
+
+    let recorded = recorded.borrow();
+    assert!(recorded.ended);
+    assert!(sw.is_stopped());
+    let [attribute] = &recorded.attributes[..] else {
+        panic!("expected exactly one attribute");
+    };
+    assert_eq!(
+        attribute.key.as_str(),
+        crate::OtelGuard::<Instant, TestSpan>::ELAPSED_KEY
+    );
+    let Value::F64(secs) = attribute.value else {
+        panic!("expected elapsed seconds as f64");
+    };
+    assert!(secs >= DELAY.as_secs_f64());
+    assert_approx_eq(secs, sw.elapsed().as_secs_f64());
+
+    assert_eq!(
+        sw.guard_otel(TestSpan(Rc::default())).err(),
+        None,
+        "stopped stopwatch can be guarded"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "interpolate")]
+#[test]
+fn interpolate_smooths_coarse_clock() -> crate::Result<()> {
+    use crate::InterpolatedSw;
+    use mock::{CoarseMockInstant, MockInstant};
+
+    let mut sw = InterpolatedSw::<CoarseMockInstant, MockInstant>::new_started();
+    let mut readings = std::vec::Vec::new();
+    for _ in 0..25 {
+        readings.push(sw.elapsed());
+        mock::advance(Duration::from_millis(1));
+    }
+    readings.push(sw.elapsed());
+
+    // monotonic
+    assert!(readings.windows(2).all(|pair| pair[0] <= pair[1]));
+    // smoother than the coarse clock alone, which only takes 3 distinct values
+    let distinct = readings
+        .windows(2)
+        .filter(|pair| pair[0] != pair[1])
+        .count();
+    assert!(distinct > 3);
+    // coarse clock is authoritative once it catches up
+    assert!(readings.last().unwrap() >= &Duration::from_millis(20));
+
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), sw.inner().elapsed());
+    Ok(())
+}
+
+#[track_caller]
+fn assert_approx_eq(left: f64, right: f64) {
+    assert!(
+        (left - right).abs() < 1e-9,
+        "assertion failed: `left ~= right`\n  left: {left}\n right: {right}"
+    );
+}
+
+fn mixed_stopwatches() -> [[Stopwatch; 3]; 11] {
+    let crafted_1;
+    let crafted_2;
+    {
+        let mut elapsed = Duration::from_secs(10);
+        let mut start = Instant::now();
+        crafted_1 = Stopwatch::from_raw(elapsed, Some(start));
+
+        elapsed -= Duration::from_secs(1);
+        start = <Instant as crate::Instant>::checked_sub(&start, Duration::from_secs(1)).unwrap();
+        crafted_2 = Stopwatch::from_raw(elapsed, Some(start));
+    }
+    assert_eq!(crafted_1, crafted_2);
+
+    let started = Stopwatch::new_started();
+    let started_elapsed_1 = Stopwatch::with_elapsed_started(Duration::from_secs(1));
+    let started_elapsed_2 = Stopwatch::with_elapsed_started(Duration::from_secs(2));
+
+    let overflowing_1;
+    let overflowing_2;
+    {
+        let start_1 = Instant::now();
+        let start_2 = <Instant as crate::Instant>::checked_sub(&start_1, DELAY).unwrap();
+        overflowing_1 = Stopwatch::from_raw(Duration::MAX, Some(start_1));
+        overflowing_2 = Stopwatch::from_raw(Duration::MAX, Some(start_2));
+    }
+
+    [
+        [Stopwatch::new(), Stopwatch::new(), Stopwatch::new()],
+        [started, started, started],
+        [started, Stopwatch::new(), Stopwatch::new()],
+        [
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+        ],
+        [started_elapsed_1, started_elapsed_1, started_elapsed_1],
+        [started_elapsed_1, started_elapsed_2, started_elapsed_1],
+        [overflowing_1, overflowing_2, started],
+        [
+            started_elapsed_1,
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+        ],
+        [
+            started_elapsed_2,
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+        ],
+        [
+            Stopwatch::with_elapsed(Duration::from_secs(1)),
+            Stopwatch::with_elapsed(Duration::from_secs(2)),
+            Stopwatch::with_elapsed(Duration::from_secs(3)),
+        ],
         [crafted_1, crafted_2, Stopwatch::default()],
     ]
 }
+
+#[test]
+fn monotonic_clamps_backwards_clock() {
+    use mock::MockInstant;
+
+    let mut sw = crate::StopwatchImpl::<MockInstant>::new().into_monotonic();
+    sw.start().unwrap();
+    mock::advance(DELAY * 2);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+
+    mock::rewind(DELAY);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+    assert_eq!(sw.inner().elapsed(), DELAY);
+
+    mock::advance(DELAY / 2);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+    mock::advance(DELAY);
+    assert_eq!(sw.elapsed(), DELAY * 2 + DELAY / 2);
+
+    mock::rewind(DELAY);
+    sw.stop().unwrap();
+    assert_eq!(sw.elapsed(), DELAY * 2 + DELAY / 2);
+    assert_eq!(sw.inner().elapsed(), DELAY * 2 + DELAY / 2);
+}
+
+#[test]
+fn weighted_mean() {
+    let a = Stopwatch::with_elapsed(DELAY);
+    let b = Stopwatch::with_elapsed(DELAY * 5);
+    assert_eq!(crate::weighted_mean(&[(3, &a), (1, &b)]), DELAY * 2);
+    assert_eq!(crate::weighted_mean(&[(1, &a), (1, &b)]), DELAY * 3);
+    assert_eq!(crate::weighted_mean(&[(0, &a), (2, &b)]), DELAY * 5);
+    assert_eq!(crate::weighted_mean(&[(0, &a), (0, &b)]), Duration::ZERO);
+    assert_eq!(crate::weighted_mean::<Instant>(&[]), Duration::ZERO);
+
+    let max = Stopwatch::with_elapsed(Duration::MAX);
+    assert_eq!(crate::weighted_mean(&[(1, &max)]), Duration::MAX);
+    assert_eq!(
+        crate::weighted_mean(&[(u64::MAX, &max), (1, &a)]),
+        Duration::MAX
+    );
+
+    let running = Stopwatch::with_elapsed_started(DELAY);
+    assert!(crate::weighted_mean(&[(1, &running), (1, &a)]) >= DELAY);
+}
+
+#[test]
+fn rate_estimator() {
+    use crate::RateEstimator;
+    use mock::MockInstant;
+
+    let mut rate = RateEstimator::<MockInstant>::new();
+    assert_eq!(rate.rate(), None);
+
+    // stopwatch clock advances at half the speed of the wall clock
+    rate.record(Duration::ZERO, MockInstant(0));
+    assert_eq!(rate.rate(), None);
+    rate.record(DELAY / 2, MockInstant(100_000_000));
+    assert_approx_eq(rate.rate().unwrap(), 0.5);
+    rate.record(DELAY, MockInstant(300_000_000));
+    assert_approx_eq(rate.rate().unwrap(), 0.25);
+
+    // no wall time passed
+    rate.record(DELAY * 2, MockInstant(300_000_000));
+    assert_eq!(rate.rate(), None);
+
+    rate.reset();
+    let sw = crate::StopwatchImpl::<MockInstant>::new_started();
+    rate.observe(&sw);
+    mock::advance(DELAY);
+    rate.observe(&sw);
+    assert_approx_eq(rate.rate().unwrap(), 1.0);
+
+    let stopped = crate::StopwatchImpl::<MockInstant>::with_elapsed(DELAY);
+    rate.observe(&stopped);
+    mock::advance(DELAY);
+    rate.observe(&stopped);
+    assert_approx_eq(rate.rate().unwrap(), 0.0);
+}
+
+#[test]
+fn with_cumulative_elapsed() {
+    use crate::TimedIteratorExt;
+
+    let sw = Stopwatch::new_started();
+    let timed: std::vec::Vec<_> = (0..4)
+        .inspect(|_| thread::sleep(DELAY / 4))
+        .with_cumulative_elapsed(&sw)
+        .collect();
+    assert_eq!(
+        timed.iter().map(|(i, _)| *i).collect::<std::vec::Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+    assert!(timed.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    assert!(timed[3].1 >= DELAY);
+
+    let stopped = Stopwatch::with_elapsed(DELAY);
+    let mut iter = ['a', 'b'].into_iter().with_cumulative_elapsed(&stopped);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(('a', DELAY)));
+    assert_eq!(iter.next(), Some(('b', DELAY)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn group() -> crate::Result<()> {
+    use crate::StopwatchGroup;
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    let mut group = StopwatchGroup::<Instant>::new();
+    assert!(group.is_empty());
+    assert_eq!(group.stop_at("a", at(0)), Err(Error::SwStop));
+    assert_eq!(group.elapsed_at("a", at(0)), Duration::ZERO);
+    assert!(group.is_empty());
+
+    group.start_at("a", at(0))?;
+    group.start_at("b", at(1))?;
+    assert_eq!(group.start_at("a", at(1)), Err(Error::SwStart));
+    group.stop_at("a", at(3))?;
+    assert_eq!(group.elapsed_at("a", at(5)), DELAY * 3);
+    assert_eq!(group.elapsed_at("b", at(5)), DELAY * 4);
+    assert_eq!(group.len(), 2);
+    assert_eq!(group.iter().count(), 2);
+
+    assert_eq!(group.remove("a"), Some(Stopwatch::with_elapsed(DELAY * 3)));
+    assert_eq!(group.get("a"), None);
+    group.clear();
+    assert!(group.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn array_group() -> crate::Result<()> {
+    use crate::ArrayStopwatchGroup;
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    let mut group = ArrayStopwatchGroup::<Instant, 4>::new();
+    assert_eq!(group.capacity(), 4);
+    assert_eq!(group.stop_at(0, at(0)), Err(Error::SwStop));
+    assert_eq!(group.elapsed_at(0, at(0)), Duration::ZERO);
+
+    // fill to capacity
+    for id in 0..4 {
+        group.start_at(id, at(u32::from(id)))?;
+    }
+    assert_eq!(group.len(), 4);
+    assert_eq!(group.start_at(4, at(4)), Err(Error::GroupFull));
+    assert_eq!(group.get(4), None);
+    assert_eq!(group.len(), 4);
+
+    // existing stopwatches are still usable when full
+    assert_eq!(group.start_at(0, at(4)), Err(Error::SwStart));
+    group.stop_at(0, at(4))?;
+    group.start_at(0, at(6))?;
+    assert_eq!(group.elapsed_at(0, at(7)), DELAY * 5);
+    assert_eq!(group.elapsed_at(3, at(7)), DELAY * 4);
+    assert!(group.iter().map(|(id, _)| id).eq(0..4));
+
+    assert!(group.remove(1).unwrap().is_running());
+    group.start_at(4, at(7))?;
+    assert_eq!(group.start_at(5, at(7)), Err(Error::GroupFull));
+
+    group.clear();
+    assert!(group.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "std_instant")]
+#[test]
+fn thread_local_isolation() -> crate::Result<()> {
+    use crate::thread_local;
+
+    thread_local::start("phase")?;
+    thread::sleep(DELAY);
+    thread_local::stop("phase")?;
+    let elapsed = thread_local::elapsed("phase");
+    assert!(elapsed >= DELAY);
+    assert_eq!(thread_local::start("other"), Ok(()));
+    assert_eq!(thread_local::start("other"), Err(Error::SwStart));
+
+    thread::spawn(|| {
+        assert_eq!(thread_local::elapsed("phase"), Duration::ZERO);
+        assert_eq!(thread_local::stop("other"), Err(Error::SwStop));
+        thread_local::start("phase").unwrap();
+        thread_local::with_group(|group| assert_eq!(group.len(), 1));
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(thread_local::elapsed("phase"), elapsed);
+    thread_local::with_group(|group| assert_eq!(group.len(), 2));
+    assert!(thread_local::remove("other").unwrap().is_running());
+    Ok(())
+}
+
+#[test]
+fn lap() -> crate::Result<()> {
+    use crate::LapStopwatch;
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    let mut sw = LapStopwatch::<Instant>::new();
+    assert_eq!(sw.lap(), Err(Error::SwLap));
+
+    sw.start_at(at(1))?;
+    assert_eq!(sw.lap_at(at(3))?, DELAY * 2);
+    assert_eq!(sw.lap_at(at(4))?, DELAY);
+    assert_eq!(sw.lap_at(at(4))?, Duration::ZERO);
+    // earlier than the previous lap
+    assert_eq!(sw.lap_at(at(2))?, Duration::ZERO);
+    assert_eq!(sw.lap_at(at(5))?, DELAY);
+
+    // stopped time isn't counted
+    sw.stop_at(at(6))?;
+    assert_eq!(sw.lap_at(at(7)), Err(Error::SwLap));
+    sw.start_at(at(10))?;
+    assert_eq!(sw.lap_at(at(11))?, DELAY * 2);
+    assert_eq!(sw.elapsed_at(at(11)), DELAY * 6);
+
+    sw.reset();
+    assert_eq!(sw, LapStopwatch::new());
+    Ok(())
+}
+
+#[test]
+fn running_stats() {
+    use crate::RunningStats;
+
+    let mut stats = RunningStats::new();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.max(), None);
+    assert_eq!(stats.last(), None);
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.mean_secs_f64(), None);
+
+    stats.extend([4, 1, 7, 2, 6].map(|n| DELAY * n));
+    assert_eq!(stats.count(), 5);
+    assert_eq!(stats.min(), Some(DELAY));
+    assert_eq!(stats.max(), Some(DELAY * 7));
+    assert_eq!(stats.last(), Some(DELAY * 6));
+    assert_approx_eq(stats.mean_secs_f64().unwrap(), (DELAY * 4).as_secs_f64());
+    assert_eq!(stats.mean(), Some(DELAY * 4));
+
+    // single sample
+    stats.reset();
+    assert_eq!(stats, RunningStats::default());
+    stats.record(Duration::MAX);
+    assert_eq!(stats.mean(), Some(Duration::MAX));
+    stats.record(Duration::MAX);
+    assert_eq!(stats.mean(), Some(Duration::MAX));
+    assert_eq!(stats.min(), Some(Duration::MAX));
+
+    let mut stats = RunningStats::new();
+    stats.record(Duration::ZERO);
+    assert_eq!(stats.mean(), Some(Duration::ZERO));
+}
+
+#[test]
+fn lap_first_since_start() -> crate::Result<()> {
+    use crate::LapStopwatch;
+
+    let start = Instant::now();
+    let later = start.checked_add(DELAY).unwrap();
+    let mut sw = LapStopwatch::from_inner(Stopwatch::from_raw(DELAY * 3, Some(start)));
+    assert_eq!(sw.lap_at(later)?, DELAY);
+
+    let mut sw = LapStopwatch::from(Stopwatch::with_elapsed(DELAY * 3));
+    sw.start_at(start)?;
+    assert_eq!(sw.lap_at(later)?, DELAY);
+
+    let mut sw = LapStopwatch::<Instant>::new_started();
+    thread::sleep(DELAY);
+    assert!(sw.lap()? >= DELAY);
+    Ok(())
+}
+
+#[cfg(all(feature = "std_instant", target_has_atomic = "64"))]
+#[test]
+fn atomic_start_stop() -> crate::Result<()> {
+    use crate::AtomicStopwatch;
+
+    let sw = AtomicStopwatch::new();
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    assert_eq!(sw.stop(), Err(Error::SwStop));
+
+    sw.start()?;
+    assert!(sw.is_running());
+    assert_eq!(sw.start(), Err(Error::SwStart));
+    thread::sleep(DELAY);
+    sw.stop()?;
+    let elapsed = sw.elapsed();
+    assert!(elapsed >= DELAY);
+    thread::sleep(DELAY);
+    assert_eq!(sw.elapsed(), elapsed);
+    assert_eq!(sw.to_stopwatch(), Stopwatch::with_elapsed(elapsed));
+
+    // resuming keeps the previous elapsed time
+    sw.start()?;
+    thread::sleep(DELAY);
+    assert!(sw.elapsed() >= elapsed + DELAY);
+    assert!(sw.to_stopwatch().is_running());
+    assert!(sw.to_stopwatch().elapsed() >= elapsed + DELAY);
+
+    sw.reset();
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+
+    let sw = AtomicStopwatch::new_started();
+    thread::sleep(DELAY);
+    assert!(sw.elapsed() >= DELAY);
+    Ok(())
+}
+
+#[cfg(all(feature = "std_instant", target_has_atomic = "64"))]
+#[test]
+fn atomic_shared() {
+    use crate::AtomicStopwatch;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let sw = Arc::new(AtomicStopwatch::new());
+    let starts = Arc::new(AtomicUsize::new(0));
+    let stops = Arc::new(AtomicUsize::new(0));
+    let threads: std::vec::Vec<_> = (0..8)
+        .map(|_| {
+            let (sw, starts, stops) = (Arc::clone(&sw), Arc::clone(&starts), Arc::clone(&stops));
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    if sw.start().is_ok() {
+                        starts.fetch_add(1, Ordering::Relaxed);
+                    }
+                    if sw.stop().is_ok() {
+                        stops.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in threads {
+        handle.join().unwrap();
+    }
+
+    // every successful stop follows exactly one successful start
+    let (starts, stops) = (
+        starts.load(Ordering::Relaxed),
+        stops.load(Ordering::Relaxed),
+    );
+    assert!(starts >= 1000);
+    assert_eq!(starts - stops, usize::from(sw.is_running()));
+}
+
+#[cfg(all(feature = "std_instant", target_has_atomic = "64"))]
+#[test]
+fn atomic_contended_elapsed_never_decreases() {
+    use crate::AtomicStopwatch;
+    use std::sync::{Arc, Barrier};
+
+    let sw = Arc::new(AtomicStopwatch::new());
+    let barrier = Arc::new(Barrier::new(8));
+    let threads: std::vec::Vec<_> = (0..8)
+        .map(|_| {
+            let (sw, barrier) = (Arc::clone(&sw), Arc::clone(&barrier));
+            thread::spawn(move || {
+                // stopped states are committed totals, so each thread must see
+                // them in nondecreasing order
+                let mut last = Duration::ZERO;
+                barrier.wait();
+                for _ in 0..20_000 {
+                    _ = sw.start();
+                    _ = sw.stop();
+                    let snapshot = sw.to_stopwatch();
+                    if snapshot.is_stopped() {
+                        assert!(snapshot.elapsed() >= last);
+                        last = snapshot.elapsed();
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in threads {
+        handle.join().unwrap();
+    }
+}
+
+#[test]
+fn tracking_max_segment() -> crate::Result<()> {
+    use crate::TrackingStopwatch;
+
+    let mut sw = TrackingStopwatch::<Instant>::new();
+    assert_eq!(sw.max_segment(), Duration::ZERO);
+
+    let start = Instant::now();
+    for (segment, max) in [(2, 2), (5, 5), (1, 5), (3, 5)] {
+        sw.start_at(start)?;
+        sw.stop_at(start.checked_add(DELAY * segment).unwrap())?;
+        assert_eq!(sw.max_segment(), DELAY * max);
+    }
+    assert_eq!(sw.elapsed(), DELAY * 11);
+
+    // running segments aren't tracked until stopped
+    sw.start_at(start)?;
+    assert_eq!(sw.max_segment(), DELAY * 5);
+    sw.stop_at(start.checked_add(DELAY * 6).unwrap())?;
+    assert_eq!(sw.max_segment(), DELAY * 6);
+    assert_eq!(sw.stop(), Err(Error::SwStop));
+
+    sw.reset();
+    assert_eq!(sw.max_segment(), Duration::ZERO);
+    assert_eq!(sw, TrackingStopwatch::new());
+    Ok(())
+}
+
+#[test]
+fn scaled_stopwatch() -> crate::Result<()> {
+    use crate::ScaledStopwatch;
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+
+    let mut sw = ScaledStopwatch::<Instant>::new();
+    assert_approx_eq(sw.time_scale(), 1.0);
+    sw.start_at(at(0))?;
+    assert_eq!(sw.elapsed_at(at(1)), DELAY);
+
+    // only time after the change is scaled
+    sw.set_time_scale_at(2.0, at(1));
+    assert_eq!(sw.elapsed_at(at(2)), DELAY * 3);
+    sw.set_time_scale_at(0.5, at(2));
+    assert_eq!(sw.elapsed_at(at(4)), DELAY * 4);
+
+    // a zero scale freezes the total while running
+    sw.set_time_scale_at(0.0, at(4));
+    assert!(sw.is_running());
+    assert_eq!(sw.elapsed_at(at(10)), DELAY * 4);
+
+    sw.set_time_scale_at(1.0, at(10));
+    sw.stop_at(at(11))?;
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), DELAY * 5);
+    assert_eq!(sw.stop_at(at(12)), Err(Error::SwStop));
+
+    // scaling a stopped stopwatch affects the next segment
+    sw.set_time_scale(3.0);
+    sw.start_at(at(12))?;
+    assert_eq!(sw.elapsed_at(at(13)), DELAY * 8);
+    assert_eq!(sw.start_at(at(13)), Err(Error::SwStart));
+
+    // an anchor before the last start keeps the current segment
+    sw.set_time_scale_at(1.0, at(11));
+    assert_eq!(sw.elapsed_at(at(13)), DELAY * 6);
+
+    sw.set_time_scale_at(f64::MAX, at(13));
+    assert_eq!(sw.elapsed_at(at(14)), Duration::MAX);
+
+    sw.reset();
+    assert!(sw.is_stopped());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    assert_approx_eq(sw.time_scale(), f64::MAX);
+    Ok(())
+}
+
+#[test]
+#[should_panic = "time scale must be finite and non-negative"]
+fn scaled_stopwatch_negative() {
+    crate::ScaledStopwatch::<Instant>::new().set_time_scale(-1.0);
+}
+
+#[test]
+fn restart() {
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+
+    // a stopped stopwatch is started
+    let mut sw = Stopwatch::with_elapsed(DELAY);
+    sw.restart_at(at(1));
+    assert!(sw.is_running());
+    assert_eq!(sw.elapsed_at(at(2)), DELAY);
+
+    // a running stopwatch discards its elapsed time and keeps running
+    sw.restart_at(at(3));
+    assert!(sw.is_running());
+    assert_eq!(sw.start_time(), Some(at(3)));
+    assert_eq!(sw.elapsed_at(at(3)), Duration::ZERO);
+    assert_eq!(sw.elapsed_at(at(5)), DELAY * 2);
+
+    let mut sw = Stopwatch::with_elapsed_started(Duration::MAX);
+    sw.restart();
+    assert!(sw.is_running());
+    assert!(sw.elapsed() < DELAY);
+}
+
+#[cfg(feature = "std_instant")]
+#[test]
+fn clock_resolution() {
+    use crate::Resolution;
+
+    assert!(Stopwatch::clock_resolution() <= Duration::from_nanos(100));
+    assert_eq!(
+        Stopwatch::clock_resolution(),
+        <Instant as Resolution>::resolution()
+    );
+    #[cfg(feature = "coarsetime")]
+    assert_eq!(
+        crate::CoarseSw::clock_resolution(),
+        Duration::from_millis(1)
+    );
+    #[cfg(feature = "manual_clock")]
+    assert_eq!(crate::ManualSw::clock_resolution(), Duration::from_nanos(1));
+}
+
+#[test]
+fn start_stop_all() {
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+
+    let mut sws = vec![
+        Stopwatch::new(),
+        Stopwatch::with_elapsed(DELAY),
+        Stopwatch::from_raw(Duration::ZERO, Some(at(1))),
+    ];
+    assert_eq!(
+        Stopwatch::start_all_at(&mut sws, at(0)),
+        [Ok(()), Ok(()), Err(Error::SwStart)]
+    );
+    assert!(sws.iter().all(Stopwatch::is_running));
+    // already running, so left unchanged
+    assert_eq!(sws[2].start_time(), Some(at(1)));
+    assert_eq!(
+        Stopwatch::start_all_at(&mut sws, at(0)),
+        [Err(Error::SwStart); 3]
+    );
+
+    assert_eq!(Stopwatch::stop_all_at(&mut sws, at(3)), [Ok(()); 3]);
+    assert_eq!(sws[0].elapsed(), DELAY * 3);
+    assert_eq!(sws[1].elapsed(), DELAY * 4);
+    assert_eq!(sws[2].elapsed(), DELAY * 2);
+    assert_eq!(
+        Stopwatch::stop_all_at(&mut sws, at(4)),
+        [Err(Error::SwStop); 3]
+    );
+    assert_eq!(sws[0].elapsed(), DELAY * 3);
+
+    assert_eq!(Stopwatch::start_all(&mut []), []);
+    sws[2].start_at(at(4)).unwrap();
+    assert_eq!(
+        Stopwatch::stop_all(&mut sws),
+        [Err(Error::SwStop), Err(Error::SwStop), Ok(())]
+    );
+    assert!(sws.iter().all(Stopwatch::is_stopped));
+
+    // subslices share an anchor too
+    assert_eq!(
+        Stopwatch::start_all_at(&mut sws[1..], at(5)),
+        [Ok(()), Ok(())]
+    );
+    assert!(sws[0].is_stopped());
+    assert_eq!(Stopwatch::stop_all_at(&mut sws[1..], at(6)), [Ok(()); 2]);
+    assert_eq!(sws[1].elapsed(), DELAY * 5);
+    assert_eq!(sws[2].elapsed(), sws[2].elapsed_at(at(7)));
+}
+
+#[test]
+fn duration_add_stopwatch() {
+    let start = Instant::now();
+
+    let sw = Stopwatch::with_elapsed(DELAY);
+    assert_eq!(DELAY + sw, DELAY * 2);
+    let mut total = DELAY;
+    total += sw;
+    total += sw;
+    assert_eq!(total, DELAY * 3);
+
+    // running stopwatches are measured at the time of the call
+    let sw = Stopwatch::from_raw(DELAY, Some(start));
+    let mut total = Duration::ZERO;
+    total += sw;
+    assert!(total >= DELAY);
+    let snapshot = total;
+    thread::sleep(DELAY);
+    assert_eq!(total, snapshot);
+    assert!(Duration::ZERO + sw >= snapshot + DELAY);
+
+    // overflow saturates
+    let sw = Stopwatch::with_elapsed(Duration::MAX);
+    assert_eq!(DELAY + sw, Duration::MAX);
+    let mut total = Duration::MAX;
+    total += Stopwatch::with_elapsed(DELAY);
+    assert_eq!(total, Duration::MAX);
+    let mut total = DELAY;
+    total += Stopwatch::from_raw(Duration::MAX, Some(start));
+    assert_eq!(total, Duration::MAX);
+}
+
+#[test]
+fn measure_with_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut sw = Stopwatch::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        sw.measure_with(|| {
+            thread::sleep(DELAY);
+            panic!("measured closure panicked");
+        })
+    }));
+    assert!(result.is_err());
+    // the guard stopped the stopwatch while unwinding
+    assert!(sw.is_stopped());
+    assert!(sw.elapsed() >= DELAY);
+
+    // a running stopwatch keeps running
+    let mut sw = Stopwatch::new_started();
+    let ((), elapsed) = sw.measure_with(|| thread::sleep(DELAY));
+    assert!(elapsed >= DELAY);
+    assert!(sw.is_running());
+}
+
+#[test]
+fn elapsed_at_checked_clamp() {
+    let start = Instant::now();
+    let earlier = start.checked_sub(DELAY).unwrap();
+    let later = start.checked_add(DELAY).unwrap();
+
+    let sw = Stopwatch::from_raw(DELAY, Some(start));
+    assert_eq!(sw.elapsed_at_checked_clamp(later), (DELAY * 2, false));
+    assert_eq!(sw.elapsed_at_checked_clamp(start), (DELAY, false));
+    assert_eq!(sw.elapsed_at_checked_clamp(earlier), (DELAY, true));
+
+    // stopped stopwatches don't read the anchor, so never clamp it
+    let sw = Stopwatch::with_elapsed(DELAY);
+    assert_eq!(sw.elapsed_at_checked_clamp(earlier), (DELAY, false));
+}
+
+#[test]
+fn lap_recorder() -> crate::Result<()> {
+    use crate::{LapRecorder, LapStopwatch};
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+
+    let mut sw = LapRecorder::<Instant>::with_capacity(4);
+    assert!(sw.laps().is_empty());
+    assert_eq!(sw.lap_at(at(0)), Err(Error::SwLap));
+    assert!(sw.laps().is_empty());
+
+    sw.start_at(at(0))?;
+    assert_eq!(sw.lap_at(at(1))?, DELAY);
+    assert_eq!(sw.lap_at(at(3))?, DELAY * 2);
+    sw.stop_at(at(4))?;
+    sw.start_at(at(6))?;
+    assert_eq!(sw.lap_at(at(7))?, DELAY * 2);
+    assert_eq!(sw.laps(), [DELAY, DELAY * 2, DELAY * 2]);
+    assert_eq!(sw.elapsed_at(at(7)), DELAY * 5);
+
+    // clearing keeps the stopwatch and the current lap
+    sw.clear_laps();
+    assert!(sw.laps().is_empty());
+    assert!(sw.is_running());
+    assert_eq!(sw.lap_at(at(8))?, DELAY);
+    assert_eq!(sw.laps(), [DELAY]);
+
+    sw.reset();
+    assert!(sw.is_stopped());
+    assert!(sw.laps().is_empty());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    assert_eq!(sw.into_inner(), LapStopwatch::new());
+    Ok(())
+}
+
+#[cfg(all(feature = "std_instant", feature = "std_systemtime"))]
+#[test]
+fn monotonic_system_instant() {
+    use crate::{Instant, MonotonicSystemInstant, MonotonicSystemSw, Resolution};
+
+    let start = MonotonicSystemInstant::now();
+    let later = start.checked_add(DELAY).unwrap();
+    assert_eq!(later.saturating_duration_since(start), DELAY);
+    assert_eq!(later.system_time(), start.system_time() + DELAY);
+    assert_eq!(later.monotonic(), start.monotonic() + DELAY);
+    assert_eq!(later.checked_sub(DELAY), Some(start));
+    assert_eq!(start.saturating_duration_since(later), Duration::ZERO);
+    assert!(MonotonicSystemInstant::resolution() <= Duration::from_micros(1));
+
+    let mut sw = MonotonicSystemSw::new();
+    sw.start_at(start).unwrap();
+    sw.stop_at(later).unwrap();
+    assert_eq!(sw.elapsed(), DELAY);
+}
+
+#[test]
+fn add_assign_stopwatch() {
+    let start = Instant::now();
+    let mut parent = Stopwatch::new_started_at(start);
+    parent += Stopwatch::with_elapsed(DELAY);
+    assert!(parent.is_running());
+    assert_eq!(
+        parent.elapsed_at(start.checked_add(DELAY).unwrap()),
+        DELAY * 2
+    );
+
+    let mut stopped = Stopwatch::with_elapsed(DELAY);
+    stopped += Stopwatch::new_started();
+    assert!(stopped.is_stopped());
+    assert!(stopped >= DELAY);
+
+    let mut full = Stopwatch::with_elapsed(Duration::MAX);
+    full += Stopwatch::with_elapsed(DELAY);
+    assert_eq!(full.elapsed(), Duration::MAX);
+}
+
+#[test]
+fn tracking_session_count() -> crate::Result<()> {
+    use crate::TrackingStopwatch;
+
+    let start = Instant::now();
+    let mut sw = TrackingStopwatch::<Instant>::new();
+    assert_eq!(sw.session_count(), 0);
+    for n in 1..=3 {
+        sw.start_at(start)?;
+        assert_eq!(sw.session_count(), n);
+        sw.stop_at(start)?;
+    }
+
+    // failed starts aren't counted
+    sw.start_at(start)?;
+    assert_eq!(sw.start_at(start), Err(Error::SwStart));
+    assert_eq!(sw.session_count(), 4);
+
+    sw.reset();
+    assert_eq!(sw.session_count(), 0);
+    assert_eq!(
+        TrackingStopwatch::<Instant>::new_started().session_count(),
+        1
+    );
+    assert_eq!(
+        TrackingStopwatch::from(Stopwatch::new_started()).session_count(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn tracking_paused_total() -> crate::Result<()> {
+    use crate::TrackingStopwatch;
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    let mut sw = TrackingStopwatch::<Instant>::new();
+
+    // time before the first segment isn't paused
+    sw.start_at(at(1))?;
+    assert_eq!(sw.paused_total_at(at(2)), Duration::ZERO);
+    sw.stop_at(at(2))?;
+
+    // current pause is included
+    assert_eq!(sw.paused_total_at(at(2)), Duration::ZERO);
+    assert_eq!(sw.paused_total_at(at(5)), DELAY * 3);
+    assert_eq!(sw.paused_total_at(start), Duration::ZERO);
+
+    sw.start_at(at(4))?;
+    assert_eq!(sw.paused_total_at(at(10)), DELAY * 2);
+    sw.stop_at(at(5))?;
+    sw.start_at(at(8))?;
+    sw.stop_at(at(9))?;
+    assert_eq!(sw.paused_total_at(at(9)), DELAY * 5);
+    assert_eq!(sw.elapsed(), DELAY * 3);
+
+    // failed starts and stops don't affect paused time
+    assert_eq!(sw.stop_at(at(10)), Err(Error::SwStop));
+    assert_eq!(sw.paused_total_at(at(11)), DELAY * 7);
+
+    sw.reset();
+    assert_eq!(sw.paused_total(), Duration::ZERO);
+    Ok(())
+}
+
+#[test]
+fn configured_default() -> crate::Result<()> {
+    use crate::StopwatchConfig;
+
+    let mut sw = Stopwatch::with_elapsed(DELAY).with_config(StopwatchConfig::default());
+    assert_eq!(*sw.config(), StopwatchConfig::new());
+    assert_eq!(sw.elapsed(), DELAY);
+    sw.start()?;
+    assert_eq!(sw.start(), Err(Error::SwStart));
+    sw.stop()?;
+    assert_eq!(sw.stop(), Err(Error::SwStop));
+    assert!(sw.elapsed() >= DELAY);
+    sw.reset();
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    Ok(())
+}
+
+#[test]
+fn configured_offset_and_cap() {
+    use crate::StopwatchConfig;
+
+    let config = StopwatchConfig {
+        offset: DELAY,
+        ..StopwatchConfig::new()
+    };
+    let mut sw = Stopwatch::with_elapsed(DELAY * 2).with_config(config);
+    assert_eq!(sw.elapsed(), DELAY * 3);
+    assert_eq!(sw.inner().elapsed(), DELAY * 2);
+
+    sw.configure(StopwatchConfig {
+        cap: Some(DELAY * 2 + DELAY / 2),
+        ..config
+    });
+    assert_eq!(sw.elapsed(), DELAY * 2 + DELAY / 2);
+    sw.reset();
+    assert_eq!(sw.elapsed(), DELAY);
+}
+
+#[test]
+fn configured_overflow_policy() -> crate::Result<()> {
+    use crate::{OverflowPolicy, StopwatchConfig};
+
+    let mut saturate = Stopwatch::with_elapsed(Duration::MAX).with_config(StopwatchConfig::new());
+    saturate.start()?;
+    thread::sleep(DELAY);
+    saturate.stop()?;
+    assert_eq!(saturate.elapsed(), Duration::MAX);
+
+    let config = StopwatchConfig {
+        overflow: OverflowPolicy::Error,
+        ..StopwatchConfig::new()
+    };
+    let mut error = Stopwatch::with_elapsed(Duration::MAX).with_config(config);
+    error.start()?;
+    thread::sleep(DELAY);
+    assert_eq!(error.stop(), Err(Error::Overflow));
+    assert!(error.is_running());
+
+    let mut ok = Stopwatch::new().with_config(config);
+    ok.start()?;
+    ok.stop()?;
+    assert_eq!(ok.stop(), Err(Error::SwStop));
+    Ok(())
+}
+
+#[test]
+fn configured_monotonic() -> crate::Result<()> {
+    use crate::StopwatchConfig;
+    use mock::MockInstant;
+
+    let config = StopwatchConfig {
+        monotonic: true,
+        ..StopwatchConfig::new()
+    };
+    let mut sw = crate::StopwatchImpl::<MockInstant>::new().with_config(config);
+    sw.start()?;
+    mock::advance(DELAY * 2);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+    mock::rewind(DELAY);
+    assert_eq!(sw.elapsed(), DELAY * 2);
+
+    sw.configure(StopwatchConfig::new());
+    assert_eq!(sw.elapsed(), DELAY);
+    Ok(())
+}
+
+#[test]
+fn configured_max_segment() -> crate::Result<()> {
+    use crate::StopwatchConfig;
+    use mock::MockInstant;
+
+    let config = StopwatchConfig {
+        max_segment: Some(DELAY * 3),
+        ..StopwatchConfig::new()
+    };
+    let mut sw = crate::StopwatchImpl::<MockInstant>::with_elapsed(DELAY).with_config(config);
+    sw.start()?;
+    mock::advance(DELAY * 2);
+    assert_eq!(sw.elapsed(), DELAY * 3);
+
+    // the clock jumps forward
+    mock::advance(DELAY * 100);
+    assert_eq!(sw.elapsed(), DELAY * 4);
+    sw.stop()?;
+    assert_eq!(sw.inner().elapsed(), DELAY * 4);
+
+    // each segment is capped separately
+    sw.start()?;
+    mock::advance(DELAY);
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), DELAY * 5);
+
+    sw.configure(StopwatchConfig::new());
+    sw.start()?;
+    mock::advance(DELAY * 10);
+    assert_eq!(sw.elapsed(), DELAY * 15);
+    Ok(())
+}
+
+#[test]
+fn configured_at() -> crate::Result<()> {
+    use crate::{OverflowPolicy, StopwatchConfig};
+
+    let start = Instant::now();
+    let at = |n| start.checked_add(DELAY * n).unwrap();
+    let config = StopwatchConfig {
+        offset: DELAY,
+        cap: Some(DELAY * 4),
+        max_segment: Some(DELAY * 2),
+        ..StopwatchConfig::new()
+    };
+    let mut sw = Stopwatch::new().with_config(config);
+    sw.start_at(at(0))?;
+    assert_eq!(sw.start_at(at(0)), Err(Error::SwStart));
+    assert_eq!(sw.elapsed_at(at(1)), DELAY * 2);
+    // the segment is capped, then the offset is added
+    assert_eq!(sw.elapsed_at(at(5)), DELAY * 3);
+    sw.stop_at(at(5))?;
+    assert_eq!(sw.inner().elapsed(), DELAY * 2);
+    assert_eq!(sw.stop_at(at(6)), Err(Error::SwStop));
+
+    // the reported elapsed time is capped
+    sw.start_at(at(6))?;
+    assert_eq!(sw.elapsed_at(at(8)), DELAY * 4);
+
+    let mut sw = Stopwatch::with_elapsed(Duration::MAX).with_config(StopwatchConfig {
+        overflow: OverflowPolicy::Error,
+        ..StopwatchConfig::new()
+    });
+    sw.start_at(at(0))?;
+    assert_eq!(sw.stop_at(at(1)), Err(Error::Overflow));
+    assert!(sw.is_running());
+    Ok(())
+}
+
+#[cfg(feature = "manual_clock")]
+#[test]
+fn manual_clock() -> crate::Result<()> {
+    use crate::{Instant as _, ManualInstant, ManualSw};
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    let epoch = ManualInstant::now();
+    let mut sw = ManualSw::new_started();
+    for frame in 1..=10 {
+        ManualInstant::advance(FRAME);
+        assert_eq!(sw.elapsed(), FRAME * frame);
+    }
+    sw.stop()?;
+    ManualInstant::advance(FRAME);
+    assert_eq!(sw.elapsed(), FRAME * 10);
+    assert_eq!(
+        ManualInstant::now().saturating_duration_since(epoch),
+        FRAME * 11
+    );
+
+    // each thread has its own clock
+    thread::spawn(|| assert_eq!(ManualInstant::now(), ManualInstant::default()))
+        .join()
+        .unwrap();
+
+    ManualInstant::set(epoch);
+    assert_eq!(ManualInstant::now(), epoch);
+    ManualInstant::set(ManualInstant::from_duration(Duration::MAX));
+    ManualInstant::advance(FRAME);
+    assert_eq!(ManualInstant::now().as_duration(), Duration::MAX);
+    ManualInstant::set(epoch);
+    Ok(())
+}
+
+#[cfg(all(feature = "test_util", target_has_atomic = "64"))]
+#[test]
+fn test_util_mock_instant() -> crate::Result<()> {
+    use crate::{Instant as _, MockInstant, MockSw};
+    use std::sync::Arc;
+
+    // this is the only test using the global mock clock
+    MockInstant::set(MockInstant::default());
+    let sw = MockSw::new_started();
+    MockInstant::advance(DELAY);
+    assert_eq!(sw.elapsed(), DELAY);
+
+    // the clock is shared between threads
+    let sw = Arc::new(sw);
+    let handle = {
+        let sw = Arc::clone(&sw);
+        thread::spawn(move || {
+            MockInstant::advance(DELAY);
+            sw.elapsed()
+        })
+    };
+    assert_eq!(handle.join().unwrap(), DELAY * 2);
+
+    let mut sw = *sw;
+    MockInstant::rewind(DELAY * 2);
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    MockInstant::advance(DELAY * 3);
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), DELAY * 3);
+
+    MockInstant::rewind(Duration::MAX);
+    assert_eq!(MockInstant::now(), MockInstant::from_nanos(0));
+    MockInstant::advance(Duration::MAX);
+    assert_eq!(MockInstant::now().as_nanos(), u64::MAX);
+
+    MockInstant::set(MockInstant::default());
+    Ok(())
+}
+
+#[cfg(all(feature = "driven_clock", target_has_atomic = "64"))]
+#[test]
+fn driven_clock() -> crate::Result<()> {
+    use crate::{DrivenClock, DrivenInstant, DrivenSw, Instant as _};
+
+    // this is the only test using the global driven clock
+    let clock = DrivenClock::new();
+    clock.set(Duration::ZERO);
+    let mut a = DrivenSw::new_started();
+    clock.set(DELAY);
+    let mut b = DrivenSw::new_started();
+    clock.set(DELAY * 3);
+    a.stop()?;
+    b.stop()?;
+    assert_eq!(a.elapsed(), DELAY * 3);
+    assert_eq!(b.elapsed(), DELAY * 2);
+    assert_eq!(clock.now(), DrivenInstant::from_duration(DELAY * 3));
+    clock.set(Duration::MAX);
+    assert_eq!(DrivenInstant::now().as_duration(), Duration::MAX);
+
+    // readers never see a timestamp torn between two writes
+    clock.set(Duration::ZERO);
+    let reader = thread::spawn(|| {
+        for _ in 0..10_000 {
+            let now = DrivenInstant::now().as_duration();
+            assert_eq!(now.as_secs(), u64::from(now.subsec_nanos()));
+        }
+    });
+    for secs in 0..10_000 {
+        clock.set(Duration::new(secs, u32::try_from(secs).unwrap()));
+    }
+    reader.join().unwrap();
+
+    clock.set(Duration::ZERO);
+    Ok(())
+}
+
+#[cfg(feature = "web_time")]
+#[test]
+fn web_instant() -> crate::Result<()> {
+    let mut sw = crate::WebSw::new_started();
+    thread::sleep(DELAY);
+    sw.stop()?;
+    assert!(sw.elapsed() >= DELAY);
+
+    let now = web_time::Instant::now();
+    assert_eq!(web_time::Instant::from(crate::WebInstant::from(now)), now);
+    Ok(())
+}
+
+#[cfg(feature = "minstant")]
+#[test]
+fn minstant_instant() -> crate::Result<()> {
+    let mut sw = crate::MinstantSw::new_started();
+    thread::sleep(DELAY);
+    sw.stop()?;
+    assert!(sw.elapsed() >= DELAY);
+
+    let now = minstant::Instant::now();
+    assert_eq!(
+        minstant::Instant::from(crate::MinstantInstant::from(now)),
+        now
+    );
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_instant() -> crate::Result<()> {
+    use crate::{ChronoInstant, Instant as _};
+
+    let mut sw = crate::ChronoSw::new_started();
+    thread::sleep(DELAY);
+    sw.stop()?;
+    assert!(sw.elapsed() >= DELAY);
+
+    let now = chrono::Utc::now();
+    assert_eq!(chrono::DateTime::from(ChronoInstant::from(now)), now);
+
+    let start = ChronoInstant::from(now);
+    let later = start.checked_add(DELAY).unwrap();
+    assert_eq!(later.checked_sub(DELAY), Some(start));
+    assert_eq!(later.saturating_duration_since(start), DELAY);
+    // earlier is in the future
+    assert_eq!(start.saturating_duration_since(later), Duration::ZERO);
+    assert_eq!(start.checked_add(Duration::MAX), None);
+    Ok(())
+}
+
+#[test]
+fn try_instant() -> crate::Result<()> {
+    use mock::MockInstant;
+
+    let mut sw = crate::StopwatchImpl::<MockInstant>::new();
+    mock::set_broken(true);
+    assert_eq!(sw.try_start(), Err(Error::Clock));
+    assert!(sw.is_stopped());
+    assert_eq!(sw.try_elapsed(), Ok(Duration::ZERO));
+
+    mock::set_broken(false);
+    sw.try_start()?;
+    assert_eq!(sw.try_start(), Err(Error::SwStart));
+    mock::advance(DELAY);
+    assert_eq!(sw.try_elapsed(), Ok(DELAY));
+
+    mock::set_broken(true);
+    let before = sw;
+    assert_eq!(sw.try_elapsed(), Err(Error::Clock));
+    assert_eq!(sw.try_stop(), Err(Error::Clock));
+    assert_eq!(sw, before);
+    assert!(sw.is_running());
+
+    mock::set_broken(false);
+    mock::advance(DELAY);
+    sw.try_stop()?;
+    assert_eq!(sw.try_stop(), Err(Error::SwStop));
+    assert_eq!(sw.elapsed(), DELAY * 2);
+
+    assert_eq!(Error::from(crate::ClockError), Error::Clock);
+    assert_eq!(crate::ClockError.to_string(), Error::Clock.to_string());
+    assert!(!Error::Clock.expects_running());
+    assert!(!Error::Clock.expects_stopped());
+    Ok(())
+}
+
+#[cfg(feature = "embedded_time")]
+#[test]
+fn embedded_instant() -> crate::Result<()> {
+    use crate::{EmbeddedInstant, Instant as _, StopwatchImpl};
+    use embedded_time::{fraction::Fraction, Clock};
+
+    thread_local! {
+        static TICKS: core::cell::Cell<Option<u32>> = const { core::cell::Cell::new(Some(0)) };
+    }
+
+    /// Millisecond clock which fails to be read while `TICKS` is `None`.
+    #[derive(Debug, Default)]
+    struct MillisClock;
+
+    impl Clock for MillisClock {
+        type T = u32;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<embedded_time::Instant<Self>, embedded_time::clock::Error> {
+            TICKS
+                .with(core::cell::Cell::get)
+                .map(embedded_time::Instant::new)
+                .ok_or(embedded_time::clock::Error::Unspecified)
+        }
+    }
+
+    let mut sw = StopwatchImpl::<EmbeddedInstant<MillisClock>>::new();
+    sw.try_start()?;
+    TICKS.with(|ticks| ticks.set(Some(250)));
+    assert_eq!(sw.try_elapsed(), Ok(Duration::from_millis(250)));
+
+    TICKS.with(|ticks| ticks.set(None));
+    assert_eq!(sw.try_stop(), Err(Error::Clock));
+    TICKS.with(|ticks| ticks.set(Some(300)));
+    sw.try_stop()?;
+    assert_eq!(sw.elapsed(), Duration::from_millis(300));
+
+    let start = EmbeddedInstant::<MillisClock>::now();
+    let later = start.checked_add(Duration::from_millis(20)).unwrap();
+    assert_eq!(
+        later.saturating_duration_since(start),
+        Duration::from_millis(20)
+    );
+    assert_eq!(start.saturating_duration_since(later), Duration::ZERO);
+    assert_eq!(later.checked_sub(Duration::from_millis(20)), Some(start));
+    assert_eq!(
+        StopwatchImpl::<EmbeddedInstant<MillisClock>>::clock_resolution(),
+        Duration::from_millis(1)
+    );
+    Ok(())
+}
+
+#[cfg(feature = "quanta")]
+#[test]
+fn quanta_clock_instant() -> crate::Result<()> {
+    use crate::{Instant as _, QuantaClockInstant, StopwatchImpl};
+
+    let (clock, mock) = quanta::Clock::mock();
+    assert!(QuantaClockInstant::set_clock(Some(clock)).is_none());
+
+    let mut sw = StopwatchImpl::<QuantaClockInstant>::new_started();
+    mock.increment(DELAY);
+    assert_eq!(sw.elapsed(), DELAY);
+    mock.increment(DELAY);
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), DELAY * 2);
+
+    // other threads read the global clock
+    let mock_now = QuantaClockInstant::now();
+    let global_now = thread::spawn(QuantaClockInstant::now).join().unwrap();
+    assert_ne!(mock_now, global_now);
+
+    assert!(QuantaClockInstant::set_clock(None).is_some());
+    let start = QuantaClockInstant::now();
+    thread::sleep(DELAY);
+    assert!(QuantaClockInstant::now().saturating_duration_since(start) >= DELAY);
+    Ok(())
+}
+
+#[cfg(feature = "fugit")]
+#[test]
+fn fugit_instant() -> crate::Result<()> {
+    use crate::{FugitClock, FugitInstant, Instant as _, StopwatchImpl};
+
+    thread_local! {
+        static TICKS: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+    }
+
+    /// Millisecond clock reading `TICKS`.
+    struct MillisClock;
+
+    impl FugitClock<1, 1_000> for MillisClock {
+        fn now() -> fugit::Instant<u64, 1, 1_000> {
+            fugit::Instant::<u64, 1, 1_000>::from_ticks(TICKS.with(core::cell::Cell::get))
+        }
+    }
+
+    /// 32.768 kHz clock, whose tick isn't a whole number of nanoseconds.
+    struct RtcClock;
+
+    impl FugitClock<1, 32_768> for RtcClock {
+        fn now() -> fugit::Instant<u64, 1, 32_768> {
+            fugit::Instant::<u64, 1, 32_768>::from_ticks(0)
+        }
+    }
+
+    type Millis = FugitInstant<MillisClock, 1, 1_000>;
+
+    let mut sw = StopwatchImpl::<Millis>::new_started();
+    TICKS.with(|ticks| ticks.set(250));
+    sw.stop()?;
+    assert_eq!(sw.elapsed(), Duration::from_millis(250));
+
+    let start = Millis::now();
+    let later = start.checked_add(Duration::from_millis(20)).unwrap();
+    assert_eq!(later.into_inner().ticks(), 270);
+    assert_eq!(
+        later.saturating_duration_since(start),
+        Duration::from_millis(20)
+    );
+    assert_eq!(start.saturating_duration_since(later), Duration::ZERO);
+    assert_eq!(later.checked_sub(Duration::from_millis(20)), Some(start));
+
+    // durations are truncated to whole ticks
+    let later = start.checked_add(Duration::from_micros(1_999)).unwrap();
+    assert_eq!(
+        later.saturating_duration_since(start),
+        Duration::from_millis(1)
+    );
+    assert_eq!(start.checked_add(Duration::MAX), None);
+
+    let start = FugitInstant::<RtcClock, 1, 32_768>::now();
+    let later = start.checked_add(Duration::from_secs(2)).unwrap();
+    assert_eq!(later.into_inner().ticks(), 65_536);
+    assert_eq!(
+        later.saturating_duration_since(start),
+        Duration::from_secs(2)
+    );
+    let later =
+        FugitInstant::<RtcClock, 1, 32_768>::from(fugit::Instant::<u64, 1, 32_768>::from_ticks(1));
+    assert_eq!(
+        later.saturating_duration_since(start),
+        Duration::from_nanos(30_517)
+    );
+    assert_eq!(
+        StopwatchImpl::<Millis>::clock_resolution(),
+        Duration::from_millis(1)
+    );
+    assert_eq!(
+        StopwatchImpl::<FugitInstant<RtcClock, 1, 32_768>>::clock_resolution(),
+        Duration::from_nanos(30_517)
+    );
+    Ok(())
+}
+
+#[cfg(all(feature = "std_systemtime", feature = "chrono"))]
+#[test]
+fn rfc3339() -> crate::Result<()> {
+    use crate::{SystemSw, TrackingStopwatch};
+    use std::time::SystemTime;
+
+    let parse = |s: std::string::String| {
+        SystemTime::from(chrono::DateTime::parse_from_rfc3339(&s).unwrap())
+    };
+
+    let start = SystemTime::now();
+    let sw = SystemSw::new_started_at(start);
+    assert_eq!(parse(sw.start_rfc3339().unwrap()), start);
+    assert_eq!(SystemSw::new().start_rfc3339(), None);
+
+    let mut sw = TrackingStopwatch::<SystemTime>::new();
+    assert_eq!(sw.start_rfc3339(), None);
+    assert_eq!(sw.last_stop_rfc3339(), None);
+    sw.start_at(start)?;
+    assert_eq!(parse(sw.start_rfc3339().unwrap()), start);
+    assert_eq!(sw.last_stop_rfc3339(), None);
+
+    let stop = start.checked_add(DELAY).unwrap();
+    sw.stop_at(stop)?;
+    assert_eq!(sw.stopped_at(), Some(stop));
+    assert_eq!(sw.start_rfc3339(), None);
+    assert_eq!(parse(sw.last_stop_rfc3339().unwrap()), stop);
+
+    let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(
+        SystemSw::new_started_at(before_epoch)
+            .start_rfc3339()
+            .as_deref(),
+        Some("1969-12-31T23:59:58.500Z")
+    );
+    assert_eq!(
+        SystemSw::new_started_at(SystemTime::UNIX_EPOCH)
+            .start_rfc3339()
+            .as_deref(),
+        Some("1970-01-01T00:00:00Z")
+    );
+
+    // out of chrono's range
+    let far = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(1 << 60));
+    if let Some(far) = far {
+        assert_eq!(SystemSw::new_started_at(far).start_rfc3339(), None);
+    }
+    let far = SystemTime::UNIX_EPOCH.checked_sub(Duration::from_secs(1 << 60));
+    if let Some(far) = far {
+        assert_eq!(SystemSw::new_started_at(far).start_rfc3339(), None);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ufmt")]
+#[test]
+fn ufmt() {
+    use core::convert::Infallible;
+    use std::vec::Vec;
+
+    struct Sink(Vec<u8>);
+
+    impl ufmt::uWrite for Sink {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.extend_from_slice(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    fn display(value: &impl ufmt::uDisplay) -> Vec<u8> {
+        let mut sink = Sink(Vec::new());
+        ufmt::uwrite!(&mut sink, "{}", value).unwrap();
+        sink.0
+    }
+
+    fn debug(value: &impl ufmt::uDebug) -> Vec<u8> {
+        let mut sink = Sink(Vec::new());
+        ufmt::uwrite!(&mut sink, "{:?}", value).unwrap();
+        sink.0
+    }
+
+    let sw = Stopwatch::with_elapsed(Duration::new(3723, 45_000_000));
+    assert_eq!(display(&sw), b"01:02:03.045 (stopped)");
+    assert_eq!(display(&Stopwatch::new()), b"00:00:00.000 (stopped)");
+    let day = Stopwatch::with_elapsed(Duration::from_secs(100 * 3600 + 1));
+    assert_eq!(display(&day), b"100:00:01.000 (stopped)");
+    let running = Stopwatch::from_raw(DELAY, Some(Instant::now()));
+    assert!(display(&running).ends_with(b" (running)"));
+
+    // matches `Display`, so it can be parsed back
+    assert_eq!(display(&sw), sw.to_string().as_bytes());
+    let parsed: Stopwatch = core::str::from_utf8(&display(&sw))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(parsed, sw);
+    assert_eq!(
+        debug(&Stopwatch::with_elapsed(Duration::from_nanos(42))),
+        b"StopwatchImpl { elapsed_ns: 42, running: false }"
+    );
+
+    for error in [
+        Error::SwStart,
+        Error::SwStop,
+        Error::SwGuard,
+        Error::GuardNew,
+        Error::SwLap,
+        Error::Overflow,
+        Error::Clock,
+        Error::GroupFull,
+    ] {
+        assert_eq!(display(&error), error.to_string().as_bytes());
+        assert_eq!(debug(&error), std::format!("{error:?}").as_bytes());
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt() {
+    // formatting requires a global logger, so only check the impls exist
+    fn assert_format<T: defmt::Format>() {}
+
+    assert_format::<Error>();
+    assert_format::<crate::StopwatchImpl<mock::MockInstant>>();
+}
+
+/// Deterministic clocks for tests, advanced manually per thread.
+#[allow(dead_code)]
+mod mock {
+    use core::cell::Cell;
+    use core::time::Duration;
+
+    thread_local! {
+        static NOW: Cell<u64> = const { Cell::new(0) };
+        static BROKEN: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Sets whether the mock clock of the current thread fails to be read by
+    /// [`crate::TryInstant::try_now`].
+    pub fn set_broken(broken: bool) {
+        BROKEN.with(|cell| cell.set(broken));
+    }
+
+    /// Advances the mock clock of the current thread by `dur`.
+    pub fn advance(dur: Duration) {
+        let nanos = u64::try_from(dur.as_nanos()).unwrap();
+        NOW.with(|now| now.set(now.get() + nanos));
+    }
+
+    /// Moves the mock clock of the current thread back by `dur`, as a buggy
+    /// platform clock might.
+    pub fn rewind(dur: Duration) {
+        let nanos = u64::try_from(dur.as_nanos()).unwrap();
+        NOW.with(|now| now.set(now.get() - nanos));
+    }
+
+    /// Instant with nanosecond resolution.
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub struct MockInstant(pub u64);
+
+    /// Instant with 10ms resolution, reading the same clock as `MockInstant`.
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct CoarseMockInstant(pub u64);
+
+    const COARSE_RESOLUTION: u64 = 10_000_000;
+
+    fn checked_add(nanos: u64, dur: Duration) -> Option<u64> {
+        nanos.checked_add(u64::try_from(dur.as_nanos()).ok()?)
+    }
+
+    fn checked_sub(nanos: u64, dur: Duration) -> Option<u64> {
+        nanos.checked_sub(u64::try_from(dur.as_nanos()).ok()?)
+    }
+
+    impl crate::Instant for MockInstant {
+        fn now() -> Self {
+            Self(NOW.with(Cell::get))
+        }
+
+        fn checked_add(&self, dur: Duration) -> Option<Self> {
+            checked_add(self.0, dur).map(Self)
+        }
+
+        fn checked_sub(&self, dur: Duration) -> Option<Self> {
+            checked_sub(self.0, dur).map(Self)
+        }
+
+        fn saturating_duration_since(&self, earlier: Self) -> Duration {
+            Duration::from_nanos(self.0.saturating_sub(earlier.0))
+        }
+    }
+
+    impl crate::TryInstant for MockInstant {
+        fn try_now() -> Result<Self, crate::ClockError> {
+            if BROKEN.with(Cell::get) {
+                Err(crate::ClockError)
+            } else {
+                Ok(<Self as crate::Instant>::now())
+            }
+        }
+    }
+
+    impl crate::Instant for CoarseMockInstant {
+        fn now() -> Self {
+            Self(NOW.with(Cell::get) / COARSE_RESOLUTION * COARSE_RESOLUTION)
+        }
+
+        fn checked_add(&self, dur: Duration) -> Option<Self> {
+            checked_add(self.0, dur).map(Self)
+        }
+
+        fn checked_sub(&self, dur: Duration) -> Option<Self> {
+            checked_sub(self.0, dur).map(Self)
+        }
+
+        fn saturating_duration_since(&self, earlier: Self) -> Duration {
+            Duration::from_nanos(self.0.saturating_sub(earlier.0))
+        }
+    }
+}
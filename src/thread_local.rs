@@ -0,0 +1,85 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+//! Named stopwatches available anywhere on the current thread.
+//!
+//! Each thread has its own [`StopwatchGroup`], created on first use, which the
+//! free functions in this module operate on. This trades explicitness for
+//! convenience: deeply nested code can be timed without passing a stopwatch
+//! through every call.
+//!
+//! # Scoping
+//!
+//! Stopwatches are scoped to the thread which created them. A stopwatch
+//! started on one thread can't be read or stopped from another, and the same
+//! name refers to distinct stopwatches on different threads. A thread's
+//! stopwatches are dropped when it exits.
+//!
+//! # Examples
+//!
+//! ```
+//! use libsw::thread_local;
+//! # fn main() -> libsw::Result<()> {
+//!
+//! thread_local::start("phase")?;
+//! // do some work...
+//! thread_local::stop("phase")?;
+//! println!("phase took {:?}", thread_local::elapsed("phase"));
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::RefCell;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::{StopwatchGroup, StopwatchImpl};
+
+std::thread_local! {
+    static GROUP: RefCell<StopwatchGroup<Instant>> = RefCell::new(StopwatchGroup::new());
+}
+
+/// Calls `f` with a mutable reference to the current thread's group.
+///
+/// # Panics
+///
+/// Panics if called from within `f`, or during or after destruction of the
+/// thread's local storage.
+pub fn with_group<T>(f: impl FnOnce(&mut StopwatchGroup<Instant>) -> T) -> T {
+    GROUP.with(|group| f(&mut group.borrow_mut()))
+}
+
+/// Starts the stopwatch called `name` on the current thread, creating it if it
+/// doesn't exist.
+///
+/// # Errors
+///
+/// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+pub fn start(name: &str) -> crate::Result<()> {
+    with_group(|group| group.start(name))
+}
+
+/// Stops the stopwatch called `name` on the current thread.
+///
+/// # Errors
+///
+/// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+/// stopped or doesn't exist.
+pub fn stop(name: &str) -> crate::Result<()> {
+    with_group(|group| group.stop(name))
+}
+
+/// Returns the total time elapsed of the stopwatch called `name` on the current
+/// thread, or zero if it doesn't exist.
+#[must_use]
+pub fn elapsed(name: &str) -> Duration {
+    with_group(|group| group.elapsed(name))
+}
+
+/// Removes the stopwatch called `name` from the current thread, returning it if
+/// it existed.
+#[allow(clippy::must_use_candidate)]
+pub fn remove(name: &str) -> Option<StopwatchImpl<Instant>> {
+    with_group(|group| group.remove(name))
+}
@@ -0,0 +1,266 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) which tracks statistics about the segments it
+/// has measured.
+///
+/// A segment is the time elapsed between starting and stopping the stopwatch.
+/// `TrackingStopwatch` records:
+///
+/// - The longest completed segment, which is useful for detecting worst-case
+///   latency across many start/stop cycles without keeping the full history
+///   of segments.
+///
+/// - The total time spent paused, that is, stopped between two segments.
+///
+/// - The number of sessions, that is, how many times the stopwatch was
+///   started. This helps detect stopwatches toggled far more often than
+///   expected.
+///
+/// # Notes
+///
+/// - The longest segment is updated when a segment completes, that is, when
+///   the stopwatch is stopped. A segment which is still running isn't counted.
+///
+/// - Tracking paused time stores the instant the stopwatch was last stopped,
+///   so it relies on `I` having a meaningful [`now`](Instant::now). Time before
+///   the first segment isn't considered paused.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::TrackingStopwatch;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let mut sw = TrackingStopwatch::<Instant>::new();
+/// let start = Instant::now();
+/// for millis in [30, 50, 20] {
+///     sw.start_at(start)?;
+///     sw.stop_at(start + Duration::from_millis(millis))?;
+/// }
+/// assert_eq!(sw.max_segment(), Duration::from_millis(50));
+/// assert_eq!(sw.elapsed(), Duration::from_millis(100));
+/// assert_eq!(sw.session_count(), 3);
+///
+/// sw.start_at(start + Duration::from_millis(50))?;
+/// assert_eq!(sw.paused_total(), Duration::from_millis(30));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TrackingStopwatch<I: Instant> {
+    inner: StopwatchImpl<I>,
+    max_segment: Duration,
+    paused: Duration,
+    // when the last segment completed, if the stopwatch is stopped
+    stopped_at: Option<I>,
+    sessions: u64,
+}
+
+impl<I: Instant> TrackingStopwatch<I> {
+    /// Returns a stopped stopwatch with zero elapsed time and no segments.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_inner(StopwatchImpl::new())
+    }
+
+    /// Returns a running stopwatch initialized with zero elapsed time.
+    ///
+    /// Starting the stopwatch counts as its first session.
+    #[must_use]
+    pub fn new_started() -> Self {
+        Self {
+            sessions: 1,
+            ..Self::from_inner(StopwatchImpl::new_started())
+        }
+    }
+
+    /// Returns a tracking stopwatch wrapping `inner`, with no completed
+    /// segments.
+    ///
+    /// If `inner` is running, its current segment is tracked once it stops.
+    #[must_use]
+    pub const fn from_inner(inner: StopwatchImpl<I>) -> Self {
+        Self {
+            inner,
+            max_segment: Duration::ZERO,
+            paused: Duration::ZERO,
+            stopped_at: None,
+            sessions: 0,
+        }
+    }
+
+    /// Returns a reference to the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &StopwatchImpl<I> {
+        &self.inner
+    }
+
+    /// Returns the inner [`StopwatchImpl`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> StopwatchImpl<I> {
+        self.inner
+    }
+
+    /// Returns the longest completed segment, or [`Duration::ZERO`] if no
+    /// segment has completed since the last [reset](Self::reset).
+    #[inline]
+    #[must_use]
+    pub const fn max_segment(&self) -> Duration {
+        self.max_segment
+    }
+
+    /// Returns the instant at which the last segment completed, or [`None`]
+    /// if the stopwatch is running or no segment has completed since the last
+    /// [reset](Self::reset).
+    #[inline]
+    #[must_use]
+    pub const fn stopped_at(&self) -> Option<I> {
+        self.stopped_at
+    }
+
+    /// Returns the number of times the stopwatch has been started since it
+    /// was created or last [reset](Self::reset).
+    ///
+    /// # Notes
+    ///
+    /// If the tracking stopwatch was created [from](Self::from_inner) a
+    /// running stopwatch, its current segment isn't counted, since it wasn't
+    /// started through the tracking stopwatch. The count saturates to [`u64::MAX`].
+    #[inline]
+    #[must_use]
+    pub const fn session_count(&self) -> u64 {
+        self.sessions
+    }
+
+    /// Returns the total time spent paused between segments, including the
+    /// current pause if the stopwatch is stopped.
+    ///
+    /// # Notes
+    ///
+    /// Overflows of the paused time are saturated to [`Duration::MAX`].
+    #[must_use]
+    pub fn paused_total(&self) -> Duration {
+        self.paused_total_at(I::now())
+    }
+
+    /// Returns the total time spent paused between segments, measured as if
+    /// the current time were `anchor`.
+    ///
+    /// # Notes
+    ///
+    /// `anchor` saturates to the instant the stopwatch was last stopped.
+    #[must_use]
+    pub fn paused_total_at(&self, anchor: I) -> Duration {
+        match self.stopped_at {
+            Some(stopped_at) => self
+                .paused
+                .saturating_add(anchor.saturating_duration_since(stopped_at)),
+            None => self.paused,
+        }
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the total time elapsed.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    /// Returns the total time elapsed, measured as if the current time were
+    /// `anchor`.
+    #[must_use]
+    pub fn elapsed_at(&self, anchor: I) -> Duration {
+        self.inner.elapsed_at(anchor)
+    }
+
+    /// Starts measuring a new segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.start_at(I::now())
+    }
+
+    /// Starts measuring a new segment as if the current time were `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.inner.start_at(anchor)?;
+        self.paused = self.paused_total_at(anchor);
+        self.stopped_at = None;
+        self.sessions = self.sessions.saturating_add(1);
+        Ok(())
+    }
+
+    /// Stops measuring the current segment, and tracks it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.stop_at(I::now())
+    }
+
+    /// Stops measuring the current segment as if the current time were
+    /// `anchor`, and tracks it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        if let Some(start) = self.inner.inner.start {
+            let segment = anchor.saturating_duration_since(start);
+            self.max_segment = self.max_segment.max(segment);
+        }
+        self.inner.stop_at(anchor)?;
+        self.stopped_at = Some(anchor);
+        Ok(())
+    }
+
+    /// Stops and resets the elapsed time and all statistics, including the
+    /// session count, to zero.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<I: Instant> Default for TrackingStopwatch<I> {
+    /// Returns the default stopwatch. Same as calling
+    /// [`TrackingStopwatch::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Instant> From<StopwatchImpl<I>> for TrackingStopwatch<I> {
+    fn from(inner: StopwatchImpl<I>) -> Self {
+        Self::from_inner(inner)
+    }
+}
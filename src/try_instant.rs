@@ -0,0 +1,139 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::{Error, Instant, StopwatchImpl};
+
+/// Error returned when reading a fallible clock fails.
+///
+/// See [`TryInstant`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ClockError;
+
+impl fmt::Display for ClockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Error::Clock.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ClockError {}
+
+#[cfg(all(feature = "nightly", not(feature = "std")))]
+impl ::core::error::Error for ClockError {}
+
+impl From<ClockError> for Error {
+    fn from(_: ClockError) -> Self {
+        Self::Clock
+    }
+}
+
+/// An [`Instant`] whose clock may fail to be read.
+///
+/// Some clocks, such as hardware timers on microcontrollers, can fail when
+/// read. Their [`Instant::now`] implementation has no choice but to panic,
+/// which is undesirable on a device. Stopwatches using a `TryInstant` expose
+/// fallible methods, such as [`try_start`](StopwatchImpl::try_start), which
+/// return [`Error::Clock`] instead.
+///
+/// # Provided implementations
+///
+/// | Feature flag    | Type              |
+/// |-----------------|-------------------|
+/// | `embedded_time` | `EmbeddedInstant` |
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::{ClockError, Error, Instant, StopwatchImpl, TryInstant};
+/// # use core::time::Duration;
+/// #[derive(Clone, Copy, Debug)]
+/// struct Broken;
+///
+/// impl Instant for Broken {
+///     fn now() -> Self {
+///         Self::try_now().expect("clock is broken")
+///     }
+///     # fn checked_add(&self, _: Duration) -> Option<Self> { Some(*self) }
+///     # fn checked_sub(&self, _: Duration) -> Option<Self> { Some(*self) }
+///     # fn saturating_duration_since(&self, _: Self) -> Duration { Duration::ZERO }
+///     // ...
+/// }
+///
+/// impl TryInstant for Broken {
+///     fn try_now() -> Result<Self, ClockError> {
+///         Err(ClockError)
+///     }
+/// }
+///
+/// let mut sw = StopwatchImpl::<Broken>::new();
+/// assert_eq!(sw.try_start(), Err(Error::Clock));
+/// assert!(sw.is_stopped());
+/// ```
+pub trait TryInstant: Instant {
+    /// Returns the current instant in time, or [`ClockError`] if the clock
+    /// can't be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClockError`] if the clock can't be read.
+    fn try_now() -> Result<Self, ClockError>;
+}
+
+impl<I: TryInstant> StopwatchImpl<I> {
+    /// Returns the total time elapsed, reading the current time from the
+    /// fallible clock `I`.
+    ///
+    /// # Errors
+    ///
+    /// If the stopwatch is running and reading the clock fails, returns
+    /// [`Clock`](Error::Clock).
+    ///
+    /// # Notes
+    ///
+    /// The clock isn't read if the stopwatch is stopped.
+    pub fn try_elapsed(&self) -> crate::Result<Duration> {
+        if self.is_running() {
+            Ok(self.elapsed_at(I::try_now()?))
+        } else {
+            Ok(self.inner.elapsed)
+        }
+    }
+
+    /// Starts measuring the time elapsed, reading the current time from the
+    /// fallible clock `I`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](Error::SwStart) if the stopwatch is running, or
+    /// [`Clock`](Error::Clock) if reading the clock fails. In both cases, the
+    /// stopwatch is unchanged.
+    pub fn try_start(&mut self) -> crate::Result<()> {
+        if self.is_running() {
+            return Err(Error::SwStart);
+        }
+        self.start_at(I::try_now()?)
+    }
+
+    /// Stops measuring the time elapsed since the last start, reading the
+    /// current time from the fallible clock `I`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](Error::SwStop) if the stopwatch is already stopped,
+    /// or [`Clock`](Error::Clock) if reading the clock fails. In both cases,
+    /// the stopwatch is unchanged.
+    ///
+    /// # Notes
+    ///
+    /// See [`stop_at`](Self::stop_at) for what happens if overflow occurs.
+    pub fn try_stop(&mut self) -> crate::Result<()> {
+        if self.is_stopped() {
+            return Err(Error::SwStop);
+        }
+        self.stop_at(I::try_now()?)
+    }
+}
@@ -0,0 +1,83 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::error::state_to_str;
+use crate::{Error, Instant, StopwatchImpl};
+
+/// Writes `value`, padded with leading zeros to at least `width` digits.
+fn write_padded<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    value: u64,
+    width: u32,
+) -> Result<(), W::Error> {
+    let mut digits = 1;
+    let mut rest = value / 10;
+    while rest != 0 {
+        digits += 1;
+        rest /= 10;
+    }
+    for _ in digits..width {
+        f.write_str("0")?;
+    }
+    <u64 as uDisplay>::fmt(&value, f)
+}
+
+impl<I: Instant> uDisplay for StopwatchImpl<I> {
+    /// Formats the total time elapsed as `HH:MM:SS.mmm`, followed by whether
+    /// the stopwatch is running, using only integer arithmetic. Hours keep
+    /// counting past a day.
+    ///
+    /// This matches the [`Display`](core::fmt::Display) implementation.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let elapsed = self.elapsed();
+        let secs = elapsed.as_secs();
+        write_padded(f, secs / 3600, 2)?;
+        f.write_str(":")?;
+        write_padded(f, secs / 60 % 60, 2)?;
+        f.write_str(":")?;
+        write_padded(f, secs % 60, 2)?;
+        f.write_str(".")?;
+        write_padded(f, u64::from(elapsed.subsec_millis()), 3)?;
+        f.write_str(" (")?;
+        f.write_str(state_to_str(self.is_running()))?;
+        f.write_str(")")
+    }
+}
+
+impl<I: Instant> uDebug for StopwatchImpl<I> {
+    /// Formats the total time elapsed in nanoseconds and whether the
+    /// stopwatch is running, since neither [`Duration`](core::time::Duration)
+    /// nor `I` implement `uDebug`.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("StopwatchImpl")?
+            .field("elapsed_ns", &self.elapsed().as_nanos())?
+            .field("running", &self.is_running())?
+            .finish()
+    }
+}
+
+impl uDisplay for Error {
+    /// Formats the error the same way as its [`Display`](core::fmt::Display)
+    /// implementation.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.message())
+    }
+}
+
+impl uDebug for Error {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            Self::SwStart => "SwStart",
+            Self::SwStop => "SwStop",
+            Self::SwGuard => "SwGuard",
+            Self::GuardNew => "GuardNew",
+            Self::SwLap => "SwLap",
+            Self::Overflow => "Overflow",
+            Self::Clock => "Clock",
+            Self::GroupFull => "GroupFull",
+        })
+    }
+}
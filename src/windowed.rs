@@ -0,0 +1,238 @@
+// libsw: stopwatch library
+// copyright (C) 2022-2023 Ula Shipman <ula.hello@mailbox.org>
+// licensed under MIT OR Apache-2.0
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::{Instant, StopwatchImpl};
+
+/// A [stopwatch](StopwatchImpl) which resets its elapsed time at the end of
+/// every fixed window of time.
+///
+/// This is useful for rolling metrics, such as how much time was spent busy in
+/// the current second.
+///
+/// # Rollover
+///
+/// Windows are measured by the clock `I`, starting from when the
+/// `WindowedStopwatch` was created. Whenever the stopwatch is read or mutated
+/// at an instant past the end of the current window, it rolls over:
+///
+/// - The time accumulated up to the window boundary is passed to the rollover
+///   callback, if one is set.
+/// - The elapsed time is reset to zero, without affecting whether the
+///   stopwatch is running. If it's running, it continues measuring from the
+///   boundary.
+/// - The next window begins at the boundary.
+///
+/// If several windows have passed since the last read, the window in progress
+/// is rolled over first. All further windows which have ended are then rolled
+/// over at once, in constant time, and the callback is invoked a second time
+/// with the total time accumulated across them. That total is zero if the
+/// stopwatch was stopped throughout.
+///
+/// # Examples
+///
+/// ```
+/// # use libsw::WindowedStopwatch;
+/// # use core::time::Duration;
+/// # use std::time::Instant;
+/// # fn main() -> libsw::Result<()> {
+/// let start = Instant::now();
+/// let mut sw = WindowedStopwatch::new_at(Duration::from_secs(1), start);
+/// sw.start_at(start)?;
+///
+/// let later = start + Duration::from_millis(1500);
+/// assert_eq!(sw.elapsed_at(later), Duration::from_millis(500));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct WindowedStopwatch<I: Instant> {
+    inner: StopwatchImpl<I>,
+    window: Duration,
+    window_start: I,
+    on_rollover: Option<fn(Duration)>,
+}
+
+impl<I: Instant> WindowedStopwatch<I> {
+    /// Returns a stopped stopwatch whose first window begins now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is [`Duration::ZERO`].
+    #[must_use]
+    #[track_caller]
+    pub fn new(window: Duration) -> Self {
+        Self::new_at(window, I::now())
+    }
+
+    /// Returns a stopped stopwatch whose first window begins at `anchor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is [`Duration::ZERO`].
+    #[must_use]
+    #[track_caller]
+    pub fn new_at(window: Duration, anchor: I) -> Self {
+        assert!(window != Duration::ZERO, "window must be non-zero");
+        Self {
+            inner: StopwatchImpl::new(),
+            window,
+            window_start: anchor,
+            on_rollover: None,
+        }
+    }
+
+    /// Sets the callback invoked with the total time accumulated in each
+    /// completed window.
+    #[must_use]
+    pub const fn with_callback(mut self, on_rollover: fn(Duration)) -> Self {
+        self.on_rollover = Some(on_rollover);
+        self
+    }
+
+    /// Returns the length of each window.
+    #[inline]
+    #[must_use]
+    pub const fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Returns the instant at which the current window began, as of the last
+    /// rollover.
+    #[inline]
+    #[must_use]
+    pub const fn window_start(&self) -> I {
+        self.window_start
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    #[inline]
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Returns `true` if the stopwatch is stopped.
+    #[inline]
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Returns the time elapsed in the current window, rolling over if
+    /// necessary.
+    pub fn elapsed(&mut self) -> Duration {
+        self.elapsed_at(I::now())
+    }
+
+    /// Returns the time elapsed in the current window, measured as if the
+    /// current time were `anchor`, rolling over if necessary.
+    pub fn elapsed_at(&mut self, anchor: I) -> Duration {
+        self.roll_over(anchor);
+        self.inner.elapsed_at(anchor)
+    }
+
+    /// Starts measuring the time elapsed, rolling over if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start(&mut self) -> crate::Result<()> {
+        self.start_at(I::now())
+    }
+
+    /// Starts measuring the time elapsed as if the current time were `anchor`,
+    /// rolling over if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStart`](crate::Error::SwStart) if the stopwatch is running.
+    pub fn start_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.roll_over(anchor);
+        self.inner.start_at(anchor)
+    }
+
+    /// Stops measuring the time elapsed, rolling over if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop(&mut self) -> crate::Result<()> {
+        self.stop_at(I::now())
+    }
+
+    /// Stops measuring the time elapsed as if the current time were `anchor`,
+    /// rolling over if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SwStop`](crate::Error::SwStop) if the stopwatch is already
+    /// stopped.
+    pub fn stop_at(&mut self, anchor: I) -> crate::Result<()> {
+        self.roll_over(anchor);
+        self.inner.stop_at(anchor)
+    }
+
+    fn roll_over(&mut self, anchor: I) {
+        let boundary = match self.window_start.checked_add(self.window) {
+            Some(boundary) => boundary,
+            None => return,
+        };
+        if boundary.saturating_duration_since(anchor) != Duration::ZERO {
+            // anchor is before the boundary
+            return;
+        }
+        self.complete_window(boundary);
+
+        // skip every further window which has ended, without iterating over
+        // each of them
+        let behind = anchor.saturating_duration_since(self.window_start);
+        let skipped = behind.as_nanos() / self.window.as_nanos();
+        if skipped != 0 {
+            // never exceeds `behind`, so it fits in a duration
+            let nanos = self.window.as_nanos() * skipped;
+            let span = Duration::new(
+                u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX),
+                // less than one billion
+                (nanos % 1_000_000_000) as u32,
+            );
+            if let Some(boundary) = self.window_start.checked_add(span) {
+                self.complete_window(boundary);
+            }
+        }
+    }
+
+    /// Ends the current window at `boundary`, passing the time accumulated in
+    /// it to the callback, and begins the next window there.
+    fn complete_window(&mut self, boundary: I) {
+        let completed = self.inner.elapsed_at(boundary);
+        if let Some(on_rollover) = self.on_rollover {
+            on_rollover(completed);
+        }
+
+        let start = self.inner.inner.start.map(|start| {
+            if boundary.saturating_duration_since(start) == Duration::ZERO {
+                // started at or after the boundary
+                start
+            } else {
+                boundary
+            }
+        });
+        self.inner = StopwatchImpl::from_raw(Duration::ZERO, start);
+        self.window_start = boundary;
+    }
+}
+
+impl<I: Instant> fmt::Debug for WindowedStopwatch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WindowedStopwatch")
+            .field("inner", &self.inner)
+            .field("window", &self.window)
+            .field("window_start", &self.window_start)
+            .finish_non_exhaustive()
+    }
+}